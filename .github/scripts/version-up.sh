@@ -106,6 +106,9 @@ function bump_version() {
     ydb-grpc-helpers)
       version_dep_set "ydb-grpc-helpers" "$VERSION"
       ;;
+    ydb-derive)
+      version_dep_set "ydb-derive" "$VERSION"
+      ;;
     *)
       echo "Unexpected crate name '$CRATE_NAME'"
       exit 1
@@ -0,0 +1,201 @@
+//! Derive macros for ydb crate, use them by re-export from ydb: `ydb::YdbRow` and `ydb::YdbStruct`.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::ext::IdentExt;
+use syn::{
+    parse_macro_input, Data, DeriveInput, Fields, GenericArgument, LitStr, PathArguments, Type,
+};
+
+/// Implement `TryFrom<ydb::Row>` for struct with named fields
+///
+/// Every field read from column with same name, converted by `TryFrom<ydb::Value>`.
+///
+/// Field attributes:
+/// - `#[ydb(rename = "column_name")]` - read field from column with other name.
+///
+/// Fields with `Option<T>` type read as None if the row has no the column.
+#[proc_macro_derive(YdbRow, attributes(ydb))]
+pub fn derive_ydb_row(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_ydb_row(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Implement conversions between struct with named fields and `ydb::Value::Struct`
+///
+/// Derive implements `From<T> for ydb::Value`, `TryFrom<ydb::Value> for T` and `ydb::YdbStruct`.
+/// Fields converted by `Into<ydb::Value>` and `TryFrom<ydb::Value>`, so fields can be
+/// other structs with the derive.
+///
+/// Field attributes:
+/// - `#[ydb(rename = "field_name")]` - use other name for field of ydb struct.
+///
+/// Fields with `Option<T>` type read as None if the value has no the field.
+#[proc_macro_derive(YdbStruct, attributes(ydb))]
+pub fn derive_ydb_struct(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_ydb_struct(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+struct FieldInfo {
+    ident: syn::Ident,
+    name: String,
+    ty: Type,
+    // T for field with Option<T> type
+    option_inner: Option<Type>,
+}
+
+fn expand_ydb_row(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = named_fields(&input, "YdbRow")?;
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    // explicit types of fields in helpers calls need for type inference through `?`
+    let field_values = fields.iter().map(|field| {
+        let FieldInfo {
+            ident, name, ty, ..
+        } = field;
+        match &field.option_inner {
+            Some(inner) => quote! {
+                #ident: ::ydb::derive_helpers::row_optional_field::<#inner>(&mut row, #name)?
+            },
+            None => quote! { #ident: ::ydb::derive_helpers::row_field::<#ty>(&mut row, #name)? },
+        }
+    });
+
+    Ok(quote! {
+        impl #impl_generics ::core::convert::TryFrom<::ydb::Row> for #ident #ty_generics #where_clause {
+            type Error = ::ydb::YdbError;
+
+            fn try_from(mut row: ::ydb::Row) -> ::core::result::Result<Self, Self::Error> {
+                ::core::result::Result::Ok(Self {
+                    #(#field_values,)*
+                })
+            }
+        }
+    })
+}
+
+fn expand_ydb_struct(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = named_fields(&input, "YdbStruct")?;
+    let ident = &input.ident;
+    let struct_name = ident.to_string();
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let to_value_fields = fields.iter().map(|FieldInfo { ident, name, .. }| {
+        quote! {
+            (::std::string::String::from(#name), ::ydb::Value::from(value.#ident))
+        }
+    });
+
+    let from_value_fields = fields.iter().map(|field| {
+        let FieldInfo {
+            ident, name, ty, ..
+        } = field;
+        match &field.option_inner {
+            Some(inner) => quote! {
+                #ident: ::ydb::derive_helpers::struct_optional_field::<#inner>(&mut fields, #name)?
+            },
+            None => {
+                quote! { #ident: ::ydb::derive_helpers::struct_field::<#ty>(&mut fields, #name)? }
+            }
+        }
+    });
+
+    Ok(quote! {
+        impl #impl_generics ::core::convert::From<#ident #ty_generics> for ::ydb::Value #where_clause {
+            fn from(value: #ident #ty_generics) -> Self {
+                ::ydb::Value::struct_from_fields(::std::vec![
+                    #(#to_value_fields,)*
+                ])
+            }
+        }
+
+        impl #impl_generics ::core::convert::TryFrom<::ydb::Value> for #ident #ty_generics #where_clause {
+            type Error = ::ydb::YdbError;
+
+            fn try_from(value: ::ydb::Value) -> ::core::result::Result<Self, Self::Error> {
+                let mut fields = ::ydb::derive_helpers::struct_fields(value, #struct_name)?;
+                ::core::result::Result::Ok(Self {
+                    #(#from_value_fields,)*
+                })
+            }
+        }
+
+        impl #impl_generics ::ydb::YdbStruct for #ident #ty_generics #where_clause {}
+    })
+}
+
+fn named_fields(input: &DeriveInput, derive_name: &str) -> syn::Result<Vec<FieldInfo>> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &input.ident,
+                    format!("{derive_name} supports only structs with named fields"),
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                format!("{derive_name} supports only structs"),
+            ))
+        }
+    };
+
+    fields
+        .iter()
+        .map(|field| {
+            let ident = field.ident.clone().unwrap();
+            // raw identifier r#type read from column "type"
+            let mut name = ident.unraw().to_string();
+            for attr in field
+                .attrs
+                .iter()
+                .filter(|attr| attr.path().is_ident("ydb"))
+            {
+                attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("rename") {
+                        let value: LitStr = meta.value()?.parse()?;
+                        name = value.value();
+                        Ok(())
+                    } else {
+                        Err(meta.error("unsupported ydb attribute, expected `rename`"))
+                    }
+                })?;
+            }
+
+            Ok(FieldInfo {
+                ident,
+                name,
+                ty: field.ty.clone(),
+                option_inner: option_inner(&field.ty),
+            })
+        })
+        .collect()
+}
+
+// Option detected by last segment of type path, so it works for Option, std::option::Option, etc.
+fn option_inner(t: &Type) -> Option<Type> {
+    let Type::Path(type_path) = t else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.first() {
+        Some(GenericArgument::Type(inner)) if args.args.len() == 1 => Some(inner.clone()),
+        _ => None,
+    }
+}
@@ -30,9 +30,20 @@ pub mod ydb {
     pub mod issue {
         include!("ydb.issue.rs");
     }
+    pub mod operation {
+        pub mod v1 {
+            include!("ydb.operation.v1.rs");
+        }
+    }
     pub mod operations {
         include!("ydb.operations.rs");
     }
+    pub mod query {
+        include!("ydb.query.rs");
+        pub mod v1 {
+            include!("ydb.query.v1.rs");
+        }
+    }
     pub mod scheme {
         include!("ydb.scheme.rs");
         pub mod v1 {
@@ -1,12 +1,18 @@
 use crate::client_common::DBCredentials;
 use crate::client_coordination::client::CoordinationClient;
+use crate::client_query::client::QueryClient;
+use crate::client_query::session_pool::{QuerySessionPool, DEFAULT_SIZE};
 use crate::client_scheme::client::SchemeClient;
 use crate::client_table::TableClient;
 use crate::discovery::Discovery;
 use crate::errors::YdbResult;
 use crate::load_balancer::SharedLoadBalancer;
+use crate::retry::RetryBudget;
+use crate::session_pool::{SessionPool, SessionPoolOptions};
+use crate::shutdown::ClientShutdown;
 use crate::waiter::Waiter;
 
+use once_cell::sync::OnceCell;
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -22,6 +28,13 @@ pub struct Client {
     discovery: Arc<Box<dyn Discovery>>,
     timeouts: TimeoutSettings,
     connection_manager: GrpcConnectionManager,
+    retry_budget: RetryBudget,
+    session_pool_options: SessionPoolOptions,
+    // shared by all table clients, created on first use
+    table_session_pool: OnceCell<SessionPool>,
+    // shared by all query clients, created on first use
+    query_session_pool: OnceCell<QuerySessionPool>,
+    shutdown: ClientShutdown,
 }
 
 impl Client {
@@ -29,6 +42,9 @@ impl Client {
         credentials: DBCredentials,
         discovery: Arc<Box<dyn Discovery>>,
         connection_manager: GrpcConnectionManager,
+        retry_budget: RetryBudget,
+        session_pool_options: SessionPoolOptions,
+        shutdown: ClientShutdown,
     ) -> YdbResult<Self> {
         let discovery_ref = discovery.as_ref().as_ref();
 
@@ -38,6 +54,11 @@ impl Client {
             discovery,
             timeouts: TimeoutSettings::default(),
             connection_manager,
+            retry_budget,
+            session_pool_options,
+            table_session_pool: OnceCell::new(),
+            query_session_pool: OnceCell::new(),
+            shutdown,
         })
     }
 
@@ -46,8 +67,41 @@ impl Client {
     }
 
     /// Create instance of client for table service
+    ///
+    /// All table clients of the client share one session pool,
+    /// see [ClientBuilder::with_session_pool_options](crate::ClientBuilder::with_session_pool_options).
     pub fn table_client(&self) -> TableClient {
-        TableClient::new(self.connection_manager.clone(), self.timeouts)
+        let session_pool = self.table_session_pool.get_or_init(|| {
+            SessionPool::new(
+                Box::new(self.connection_manager.clone()),
+                self.timeouts,
+                self.session_pool_options.clone(),
+                self.shutdown.clone(),
+            )
+        });
+        TableClient::new(
+            session_pool.clone(),
+            self.timeouts,
+            self.retry_budget.clone(),
+        )
+    }
+
+    /// Create instance of client for query service
+    ///
+    /// All query clients of the client share one session pool.
+    pub fn query_client(&self) -> QueryClient {
+        let session_pool = self.query_session_pool.get_or_init(|| {
+            QuerySessionPool::new(
+                Box::new(self.connection_manager.clone()),
+                DEFAULT_SIZE,
+                self.shutdown.clone(),
+            )
+        });
+        QueryClient::new(
+            session_pool.clone(),
+            self.connection_manager.clone(),
+            self.retry_budget.clone(),
+        )
     }
 
     /// Create instance of client for directory service
@@ -61,16 +115,25 @@ impl Client {
             self.timeouts,
             self.connection_manager.clone(),
             self.credentials.token_cache.clone(),
+            self.retry_budget.clone(),
+            self.shutdown.clone(),
         )
     }
 
     /// Create instance of client for coordination service
     pub fn coordination_client(&self) -> CoordinationClient {
-        CoordinationClient::new(self.timeouts, self.connection_manager.clone())
+        CoordinationClient::new(
+            self.timeouts,
+            self.connection_manager.clone(),
+            self.retry_budget.clone(),
+            self.shutdown.clone(),
+        )
     }
 
     pub fn with_timeouts(mut self, timeouts: TimeoutSettings) -> Self {
         self.timeouts = timeouts;
+        // the pool create sessions with the timeouts
+        self.table_session_pool = OnceCell::new();
         self
     }
 
@@ -88,6 +151,34 @@ impl Client {
         self.load_balancer.wait().await?;
         Ok(())
     }
+
+    /// Gracefully close the client
+    ///
+    /// The client and all clients created from it stop accepting new operations,
+    /// wait in-flight operations and flush topic writers up to `timeout`,
+    /// close pooled sessions on server side, then stop discovery, token renewal,
+    /// topic readers/writers and coordination sessions.
+    ///
+    /// Return [YdbError::Timeout](crate::YdbError::Timeout) if the client was not fully drained
+    /// during `timeout`, background work is stopped anyway.
+    ///
+    /// Example:
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use ydb::{ClientBuilder, YdbResult};
+    /// # #[tokio::main]
+    /// # async fn main() -> YdbResult<()> {
+    /// let client = ClientBuilder::new_from_connection_string("grpc://localhost:2136/local")?.client()?;
+    /// client.wait().await?;
+    /// // ... work with the client
+    /// client.close(Duration::from_secs(10)).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn close(&self, timeout: Duration) -> YdbResult<()> {
+        trace!("close client");
+        self.shutdown.close(timeout).await
+    }
 }
 
 const DEFAULT_OPERATION_TIMEOUT: Duration = Duration::from_secs(600);
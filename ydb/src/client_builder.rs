@@ -9,6 +9,9 @@ use crate::grpc_connection_manager::GrpcConnectionManager;
 use crate::grpc_wrapper::auth::AuthGrpcInterceptor;
 use crate::grpc_wrapper::runtime_interceptors::MultiInterceptor;
 use crate::load_balancer::{SharedLoadBalancer, StaticLoadBalancer};
+use crate::retry::RetryBudget;
+use crate::session_pool::SessionPoolOptions;
+use crate::shutdown::ClientShutdown;
 use crate::{Client, Credentials};
 use http::Uri;
 use once_cell::sync::Lazy;
@@ -95,7 +98,7 @@ fn token_metadata(uri: &str, mut client_builder: ClientBuilder) -> YdbResult<Cli
                 client_builder.credentials = credencials_ref(GCEMetadata::new())
             }
             _ => {
-                return Err(YdbError::Custom(format!(
+                return Err(YdbError::InvalidConnectionString(format!(
                     "unknown metadata format: '{value}'"
                 )))
             }
@@ -122,12 +125,12 @@ fn token_static_password(uri: &str, mut client_builder: ClientBuilder) -> YdbRes
         }
     }
     if username.is_none() {
-        return Err(YdbError::Custom(
+        return Err(YdbError::InvalidConnectionString(
             "username was not provided for password authentication".to_string(),
         ));
     }
     if password.is_none() {
-        return Err(YdbError::Custom(
+        return Err(YdbError::InvalidConnectionString(
             "password was not provided for password authentication".to_string(),
         ));
     }
@@ -181,6 +184,8 @@ pub struct ClientBuilder {
     discovery_interval: Duration,
     pub(crate) endpoint: String,
     discovery: Option<Box<dyn Discovery>>,
+    retry_budget: RetryBudget,
+    session_pool_options: SessionPoolOptions,
     pub cert_path: Option<String>,
 }
 
@@ -202,8 +207,9 @@ impl ClientBuilder {
     }
 
     pub fn client(self) -> YdbResult<Client> {
+        let shutdown = ClientShutdown::default();
         let db_cred = DBCredentials {
-            token_cache: TokenCache::new(self.credentials.clone())?,
+            token_cache: TokenCache::new(self.credentials.clone(), shutdown.stop_token())?,
             database: self.database.clone(),
         };
 
@@ -227,6 +233,7 @@ impl ClientBuilder {
                 self.endpoint.as_str(),
                 self.discovery_interval,
                 Box::new(db_cred.token_cache.clone()),
+                shutdown.stop_token(),
             )?),
         };
 
@@ -243,7 +250,14 @@ impl ClientBuilder {
             self.cert_path,
         );
 
-        Client::new(db_cred, discovery, connection_manager)
+        Client::new(
+            db_cred,
+            discovery,
+            connection_manager,
+            self.retry_budget,
+            self.session_pool_options,
+            shutdown,
+        )
     }
 
     pub fn with_credentials<T: 'static + Credentials>(mut self, cred: T) -> Self {
@@ -278,6 +292,25 @@ impl ClientBuilder {
         self
     }
 
+    /// Set retry budget, shared by all clients created from the client, see [RetryBudget]
+    ///
+    /// The budget limits retries of all errors in addition to retry policies of the clients.
+    /// By default the budget allow 10% of extra requests with burst of 10 retries.
+    /// Use [RetryBudget::unlimited] for disable the budget.
+    pub fn with_retry_budget(mut self, budget: RetryBudget) -> Self {
+        self.retry_budget = budget;
+        self
+    }
+
+    /// Set options of table service session pool, see [SessionPoolOptions]
+    ///
+    /// The pool is created on first [Client::table_client](crate::Client::table_client)
+    /// call and shared by all table clients of the client.
+    pub fn with_session_pool_options(mut self, options: SessionPoolOptions) -> Self {
+        self.session_pool_options = options;
+        self
+    }
+
     fn new() -> Self {
         Self {
             credentials: credencials_ref(AccessTokenCredentials::from("")),
@@ -285,6 +318,8 @@ impl ClientBuilder {
             discovery_interval: Duration::from_secs(60),
             endpoint: "grpc://localhost:2135".to_string(),
             discovery: None,
+            retry_budget: RetryBudget::default(),
+            session_pool_options: SessionPoolOptions::default(),
             cert_path: None,
         }
     }
@@ -337,7 +372,7 @@ mod test {
         );
 
         match builder {
-            Err(YdbError::Custom(_)) => Ok(()),
+            Err(YdbError::InvalidConnectionString(_)) => Ok(()),
             _ => Err(YdbError::Custom(
                 "expected connection string parsing failure".to_string(),
             )),
@@ -6,6 +6,7 @@ use secrecy::SecretString;
 use std::sync::{Arc, Mutex, RwLock};
 use std::time::Instant;
 use tokio::sync::watch;
+use tokio_util::sync::CancellationToken;
 use tracing::trace;
 
 #[derive(Clone, Debug)]
@@ -21,13 +22,17 @@ struct TokenCacheState {
     token_renewing: Arc<Mutex<()>>,
     token_received: watch::Receiver<bool>,
     token_received_sender: watch::Sender<bool>,
+    stop_renew: CancellationToken,
 }
 
 #[derive(Clone, Debug)]
 pub(crate) struct TokenCache(Arc<RwLock<TokenCacheState>>);
 
 impl TokenCache {
-    pub(crate) fn new(credentials: CredentialsRef) -> YdbResult<Self> {
+    pub(crate) fn new(
+        credentials: CredentialsRef,
+        stop_renew: CancellationToken,
+    ) -> YdbResult<Self> {
         let (token_received_sender, token_received) = watch::channel(false);
         let token_cache = TokenCache(Arc::new(RwLock::new(TokenCacheState {
             credentials,
@@ -35,6 +40,7 @@ impl TokenCache {
             token_renewing: Arc::new(Mutex::new(())),
             token_received,
             token_received_sender,
+            stop_renew,
         })));
         let token_cache_clone = token_cache.clone();
         tokio::task::spawn_blocking(move || token_cache_clone.renew_token_blocking());
@@ -45,7 +51,7 @@ impl TokenCache {
         let now = Instant::now();
 
         let read = self.0.read().unwrap();
-        if now > read.token_info.next_renew {
+        if now > read.token_info.next_renew && !read.stop_renew.is_cancelled() {
             // if need renew and no renew background in process
             if read.token_renewing.try_lock().is_ok() {
                 let self_clone = self.clone();
@@ -56,7 +62,13 @@ impl TokenCache {
     }
 
     fn renew_token_blocking(self) {
-        let renew_arc = self.0.read().unwrap().token_renewing.clone();
+        let renew_arc = {
+            let read = self.0.read().unwrap();
+            if read.stop_renew.is_cancelled() {
+                return;
+            }
+            read.token_renewing.clone()
+        };
         let _renew_lock = if let Ok(lock) = renew_arc.try_lock() {
             lock
         } else {
@@ -5,7 +5,11 @@ use crate::grpc_wrapper::raw_coordination_service::config::RawCoordinationNodeCo
 use crate::grpc_wrapper::raw_coordination_service::create_node::RawCreateNodeRequest;
 use crate::grpc_wrapper::raw_coordination_service::describe_node::RawDescribeNodeRequest;
 use crate::grpc_wrapper::raw_coordination_service::drop_node::RawDropNodeRequest;
+use crate::retry::{retry_ydb_with_policy, BackoffRetrier, NoRetrier, Retry, RetryBudget};
+use crate::shutdown::ClientShutdown;
 use crate::{grpc_wrapper, CoordinationSession, SessionOptions, YdbResult};
+use std::future::Future;
+use std::sync::Arc;
 
 use super::list_types::{NodeConfig, NodeDescription};
 
@@ -16,29 +20,61 @@ pub struct CoordinationClient {
     session_seq_no: u64,
 
     connection_manager: GrpcConnectionManager,
+
+    retrier: Arc<Box<dyn Retry>>,
+
+    retry_budget: RetryBudget,
+
+    shutdown: ClientShutdown,
 }
 
 impl CoordinationClient {
     pub(crate) fn new(
         timeouts: TimeoutSettings,
         connection_manager: GrpcConnectionManager,
+        retry_budget: RetryBudget,
+        shutdown: ClientShutdown,
     ) -> Self {
         Self {
             timeouts,
             session_seq_no: 0,
             connection_manager,
+            retrier: Arc::new(Box::<BackoffRetrier>::default()),
+            retry_budget,
+            shutdown,
+        }
+    }
+
+    /// Clone the coordination client and set retry policy for node operations, see [Retry]
+    pub fn clone_with_retry_policy(&self, policy: impl Retry + 'static) -> Self {
+        Self {
+            retrier: Arc::new(Box::new(policy)),
+            ..self.clone()
         }
     }
 
+    /// Clone the coordination client and deny retries of node operations
+    pub fn clone_with_no_retry(&self) -> Self {
+        self.clone_with_retry_policy(NoRetrier {})
+    }
+
     pub async fn create_session(
         &mut self,
         path: String,
         options: SessionOptions,
     ) -> YdbResult<CoordinationSession> {
+        self.shutdown.check_not_closing()?;
         let seq_no = self.session_seq_no;
         self.session_seq_no += 1;
 
-        CoordinationSession::new(path, seq_no, options, self.connection_manager.clone()).await
+        CoordinationSession::new(
+            path,
+            seq_no,
+            options,
+            self.connection_manager.clone(),
+            self.shutdown.stop_token(),
+        )
+        .await
     }
 
     pub async fn create_node(&mut self, path: String, config: NodeConfig) -> YdbResult<()> {
@@ -48,10 +84,12 @@ impl CoordinationClient {
             path,
         };
 
-        let mut service = self.raw_client_connection().await?;
-        service.create_node(req).await?;
-
-        Ok(())
+        self.retry(false, || async {
+            let mut service = self.raw_client_connection().await?;
+            service.create_node(req.clone()).await?;
+            Ok(())
+        })
+        .await
     }
 
     pub async fn alter_node(&mut self, path: String, config: NodeConfig) -> YdbResult<()> {
@@ -61,10 +99,12 @@ impl CoordinationClient {
             path,
         };
 
-        let mut service = self.raw_client_connection().await?;
-        service.alter_node(req).await?;
-
-        Ok(())
+        self.retry(false, || async {
+            let mut service = self.raw_client_connection().await?;
+            service.alter_node(req.clone()).await?;
+            Ok(())
+        })
+        .await
     }
 
     pub async fn describe_node(&mut self, path: String) -> YdbResult<NodeDescription> {
@@ -73,8 +113,12 @@ impl CoordinationClient {
             path,
         };
 
-        let mut service = self.raw_client_connection().await?;
-        let result = service.describe_node(req).await?;
+        let result = self
+            .retry(true, || async {
+                let mut service = self.raw_client_connection().await?;
+                Ok(service.describe_node(req.clone()).await?)
+            })
+            .await?;
         let description = NodeDescription::from(result);
 
         Ok(description)
@@ -86,10 +130,29 @@ impl CoordinationClient {
             path,
         };
 
-        let mut service = self.raw_client_connection().await?;
-        service.drop_node(req).await?;
+        self.retry(false, || async {
+            let mut service = self.raw_client_connection().await?;
+            service.drop_node(req.clone()).await?;
+            Ok(())
+        })
+        .await
+    }
 
-        Ok(())
+    async fn retry<CallbackFuture, CallbackResult>(
+        &self,
+        idempotent_operation: bool,
+        callback: impl Fn() -> CallbackFuture,
+    ) -> YdbResult<CallbackResult>
+    where
+        CallbackFuture: Future<Output = YdbResult<CallbackResult>>,
+    {
+        retry_ydb_with_policy(
+            self.retrier.as_ref().as_ref(),
+            &self.retry_budget,
+            idempotent_operation,
+            callback,
+        )
+        .await
     }
 
     pub(crate) async fn raw_client_connection(
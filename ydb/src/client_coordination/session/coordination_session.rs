@@ -69,6 +69,7 @@ impl CoordinationSession {
         seq_no: u64,
         options: SessionOptions,
         connection_manager: GrpcConnectionManager,
+        cancellation_token: CancellationToken,
     ) -> YdbResult<Self> {
         let mut coordination_service = connection_manager
             .get_auth_service(
@@ -99,8 +100,6 @@ impl CoordinationSession {
         }
         trace!("session started! {:?}", session_response);
 
-        let cancellation_token = CancellationToken::new();
-
         let method_controllers = MethodControllers {
             create_semaphore: Arc::new(RequestController::new(stream.clone_sender())),
             update_semaphore: Arc::new(RequestController::new(stream.clone_sender())),
@@ -119,19 +118,18 @@ impl CoordinationSession {
         let receiver_loop = tokio::spawn(async move {
             let mut receiver = stream;
             loop {
-                match CoordinationSession::receive_messages_loop_iteration(
-                    &mut receiver,
-                    &loop_sender,
-                    &loop_controllers,
-                )
-                .await
-                {
-                    Ok(()) => {}
-                    Err(_iteration_error) => {
-                        loop_token.cancel();
-                        return;
-                    }
+                let iteration_result = tokio::select! {
+                    _ = loop_token.cancelled() => return,
+                    res = CoordinationSession::receive_messages_loop_iteration(
+                        &mut receiver,
+                        &loop_sender,
+                        &loop_controllers,
+                    ) => res,
                 };
+                if iteration_result.is_err() {
+                    loop_token.cancel();
+                    return;
+                }
             }
         });
 
@@ -0,0 +1,254 @@
+use crate::client_query::script::ScriptOperation;
+use crate::client_query::session::QuerySession;
+use crate::client_query::session_pool::QuerySessionPool;
+use crate::client_query::transaction::{QueryAutoCommit, QueryInteractiveTx};
+use crate::client_table::TransactionOptions;
+use crate::errors::*;
+use crate::grpc_connection_manager::GrpcConnectionManager;
+use crate::grpc_wrapper::raw_query_service::client::RawQueryClient;
+use crate::grpc_wrapper::raw_query_service::execute_script::RawExecuteScriptRequest;
+use crate::grpc_wrapper::raw_ydb_operation::RawOperationParams;
+use crate::query::Query;
+use crate::result::QueryResultStream;
+use crate::retry::{
+    retry_with_policy, retry_ydb_with_policy, BackoffRetrier, NoRetrier, Retry, RetryBudget,
+};
+use crate::transaction::Transaction;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::instrument;
+
+/// Client for YDB query service
+///
+/// Query service is successor of table service. It allow execute DDL and DML queries in
+/// one request and has no limits for result size.
+///
+/// See [QueryClient::retry_tx] for examples.
+#[derive(Clone)]
+pub struct QueryClient {
+    connection_manager: GrpcConnectionManager,
+    pub(crate) session_pool: QuerySessionPool,
+    retrier: Arc<Box<dyn Retry>>,
+    retry_budget: RetryBudget,
+    transaction_options: TransactionOptions,
+    idempotent_operation: bool,
+}
+
+impl QueryClient {
+    pub(crate) fn new(
+        session_pool: QuerySessionPool,
+        connection_manager: GrpcConnectionManager,
+        retry_budget: RetryBudget,
+    ) -> Self {
+        Self {
+            session_pool,
+            connection_manager,
+            retrier: Arc::new(Box::<BackoffRetrier>::default()),
+            retry_budget,
+            transaction_options: TransactionOptions::new(),
+            idempotent_operation: false,
+        }
+    }
+
+    /// Clone the query client and set new retry timeouts
+    pub fn clone_with_retry_timeout(&self, timeout: Duration) -> Self {
+        self.clone_with_retry_policy(BackoffRetrier::new().with_deadline(timeout))
+    }
+
+    /// Clone the query client and set retry policy, see [Retry]
+    pub fn clone_with_retry_policy(&self, policy: impl Retry + 'static) -> Self {
+        Self {
+            retrier: Arc::new(Box::new(policy)),
+            ..self.clone()
+        }
+    }
+
+    /// Clone the query client and deny retries
+    pub fn clone_with_no_retry(&self) -> Self {
+        self.clone_with_retry_policy(NoRetrier {})
+    }
+
+    /// Clone the query client and set feature operations as idempotent (can retry in more cases)
+    pub fn clone_with_idempotent_operations(&self, idempotent: bool) -> Self {
+        Self {
+            idempotent_operation: idempotent,
+            ..self.clone()
+        }
+    }
+
+    /// Clone the query client and set new transaction options for [QueryClient::retry_tx]
+    ///
+    /// Unlike table service, query service allow interactive transactions in any [crate::Mode].
+    pub fn clone_with_transaction_options(&self, opts: TransactionOptions) -> Self {
+        Self {
+            transaction_options: opts,
+            ..self.clone()
+        }
+    }
+
+    fn create_transaction(&self) -> Box<dyn Transaction> {
+        let mode = self.transaction_options.mode;
+        if self.transaction_options.autocommit {
+            Box::new(QueryAutoCommit::new(self.session_pool.clone(), mode))
+        } else {
+            Box::new(QueryInteractiveTx::new(self.session_pool.clone(), mode))
+        }
+    }
+
+    async fn retry<CallbackFuture, CallbackResult>(
+        &self,
+        callback: impl Fn() -> CallbackFuture,
+    ) -> YdbResultWithCustomerErr<CallbackResult>
+    where
+        CallbackFuture: Future<Output = YdbResultWithCustomerErr<CallbackResult>>,
+    {
+        retry_with_policy(
+            self.retrier.as_ref().as_ref(),
+            &self.retry_budget,
+            self.idempotent_operation,
+            callback,
+        )
+        .await
+    }
+
+    /// Retry callback with session
+    ///
+    /// Every call of callback will got a session from pool. Queries in the session executed
+    /// without explicit transaction, it is useful for DDL queries.
+    ///
+    /// ```no_run
+    /// # use ydb::YdbResult;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main()->YdbResult<()>{
+    /// #   use ydb::Query;
+    /// #   let query_client = ydb::ClientBuilder::new_from_connection_string("")?.client()?.query_client();
+    ///     query_client.retry_query(|mut session| async move {
+    ///         session.query(Query::new("CREATE TABLE test (id Int64, PRIMARY KEY (id))")).await?;
+    ///         Ok(())
+    ///     }).await?;
+    /// #     return Ok(());
+    /// # }
+    /// ```
+    #[instrument(level = "trace", skip_all, err)]
+    pub async fn retry_query<CallbackFuture, CallbackResult>(
+        &self,
+        callback: impl Fn(QuerySession) -> CallbackFuture,
+    ) -> YdbResultWithCustomerErr<CallbackResult>
+    where
+        CallbackFuture: Future<Output = YdbResultWithCustomerErr<CallbackResult>>,
+    {
+        self.retry(|| async {
+            let session = self.session_pool.session().await?;
+            callback(session).await
+        })
+        .await
+    }
+
+    /// Execute query and read result by parts
+    ///
+    /// The method will auto-retry errors while start query execution,
+    /// but no retries after server start streaming result.
+    /// Query executed without explicit transaction.
+    /// See [QueryResultStream] for example.
+    pub async fn retry_query_stream(&self, query: Query) -> YdbResult<QueryResultStream> {
+        retry_ydb_with_policy(
+            self.retrier.as_ref().as_ref(),
+            &self.retry_budget,
+            self.idempotent_operation,
+            || async {
+                let mut session = self.session_pool.session().await?;
+                let stream = session.start_query(query.clone(), None).await?;
+                Ok(QueryResultStream::new(session, stream))
+            },
+        )
+        .await
+    }
+
+    /// Start long-running script
+    ///
+    /// Script executed in background, it may contain many transactions and execute longer than any
+    /// request timeout. The method retry errors while start script only.
+    /// See [ScriptOperation] for example.
+    pub async fn execute_script(&self, query: Query) -> YdbResult<ScriptOperation> {
+        retry_ydb_with_policy(
+            self.retrier.as_ref().as_ref(),
+            &self.retry_budget,
+            self.idempotent_operation,
+            || async {
+                let req = RawExecuteScriptRequest {
+                    operation_params: RawOperationParams::new_async(),
+                    yql_text: query.text.clone(),
+                    params: query
+                        .parameters
+                        .iter()
+                        .map(|(k, v)| Ok((k.clone(), v.clone().try_into()?)))
+                        .collect::<YdbResult<_>>()?,
+                };
+                let mut client = self
+                    .connection_manager
+                    .get_auth_service(RawQueryClient::new)
+                    .await?;
+                let raw = client.execute_script(req).await.map_err(YdbError::from)?;
+                Ok(ScriptOperation::new(raw, self.connection_manager.clone()))
+            },
+        )
+        .await
+    }
+
+    /// Get script operation by id, for example started by other process
+    ///
+    /// State of the operation refreshed from server. The method retry errors of the refresh.
+    pub async fn script_operation(&self, id: impl Into<String>) -> YdbResult<ScriptOperation> {
+        let id = id.into();
+        retry_ydb_with_policy(
+            self.retrier.as_ref().as_ref(),
+            &self.retry_budget,
+            true,
+            || async {
+                let mut operation =
+                    ScriptOperation::from_id(id.clone(), self.connection_manager.clone());
+                operation.refresh().await?;
+                Ok(operation)
+            },
+        )
+        .await
+    }
+
+    /// Retry callback in transaction
+    ///
+    /// Same as [TableClient::retry_transaction], but queries executed by query service.
+    /// Every call of callback will within new transaction.
+    /// Transaction without commit will rollback after callback finished.
+    ///
+    /// ```no_run
+    /// # use ydb::YdbResult;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main()->YdbResult<()>{
+    /// #   use ydb::{Query, Value};
+    /// #   let query_client = ydb::ClientBuilder::new_from_connection_string("")?.client()?.query_client();
+    ///     let res: Option<i32> = query_client.retry_tx(|mut t| async move {
+    ///         let value: Value = t.query(Query::new("SELECT 1 + 1 as sum")).await?
+    ///             .into_only_row()?
+    ///             .remove_field_by_name("sum")?;
+    ///         let res: Option<i32> = value.try_into()?;
+    ///         t.commit().await?;
+    ///         return Ok(res);
+    ///     }).await?;
+    ///     assert_eq!(Some(2), res);
+    /// #     return Ok(());
+    /// # }
+    /// ```
+    #[instrument(level = "trace", skip_all, err)]
+    pub async fn retry_tx<CallbackFuture, CallbackResult>(
+        &self,
+        callback: impl Fn(Box<dyn Transaction>) -> CallbackFuture,
+    ) -> YdbResultWithCustomerErr<CallbackResult>
+    where
+        CallbackFuture: Future<Output = YdbResultWithCustomerErr<CallbackResult>>,
+    {
+        self.retry(|| callback(self.create_transaction())).await
+    }
+}
@@ -0,0 +1,5 @@
+pub(crate) mod client;
+pub(crate) mod script;
+pub(crate) mod session;
+pub(crate) mod session_pool;
+pub(crate) mod transaction;
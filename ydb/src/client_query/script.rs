@@ -0,0 +1,278 @@
+use crate::errors::{YdbError, YdbResult};
+use crate::grpc_connection_manager::GrpcConnectionManager;
+use crate::grpc_wrapper::raw_operation_service::cancel_operation::RawCancelOperationRequest;
+use crate::grpc_wrapper::raw_operation_service::client::RawOperationClient;
+use crate::grpc_wrapper::raw_operation_service::forget_operation::RawForgetOperationRequest;
+use crate::grpc_wrapper::raw_operation_service::get_operation::RawGetOperationRequest;
+use crate::grpc_wrapper::raw_query_service::client::RawQueryClient;
+use crate::grpc_wrapper::raw_query_service::execute_script::RawScriptOperation;
+use crate::grpc_wrapper::raw_query_service::fetch_script_results::RawFetchScriptResultsRequest;
+use crate::result::ResultSet;
+use derive_builder::Builder;
+use std::time::Duration;
+use tracing::trace;
+
+const INITIAL_POLL_INTERVAL: Duration = Duration::from_millis(100);
+const MAX_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Execution status of script
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(not(feature = "force-exhaustive-all"), non_exhaustive)]
+pub enum ScriptExecStatus {
+    Unspecified,
+    Starting,
+    Aborted,
+    Cancelled,
+    Completed,
+    Failed,
+
+    // no use Unknown for own logic (use for debug/log only) - for prevent broke your code when new status will be defined.
+    Unknown(i32),
+}
+
+impl From<i32> for ScriptExecStatus {
+    fn from(value: i32) -> Self {
+        use ydb_grpc::ydb_proto::query::ExecStatus;
+
+        let Ok(status) = ExecStatus::try_from(value) else {
+            return ScriptExecStatus::Unknown(value);
+        };
+
+        match status {
+            ExecStatus::Unspecified => ScriptExecStatus::Unspecified,
+            ExecStatus::Starting => ScriptExecStatus::Starting,
+            ExecStatus::Aborted => ScriptExecStatus::Aborted,
+            ExecStatus::Cancelled => ScriptExecStatus::Cancelled,
+            ExecStatus::Completed => ScriptExecStatus::Completed,
+            ExecStatus::Failed => ScriptExecStatus::Failed,
+        }
+    }
+}
+
+#[derive(Builder, Clone, Default)]
+#[builder(build_fn(error = "crate::errors::YdbError"))]
+pub struct FetchScriptResultsOptions {
+    // Use FetchScriptResultsOptionsBuilder
+    /// Token from previous page, start from first row if absent
+    #[builder(setter(strip_option), default)]
+    pub fetch_token: Option<String>,
+
+    /// Max rows in page, server default if absent
+    #[builder(setter(strip_option), default)]
+    pub rows_limit: Option<u64>,
+}
+
+/// Page of script result set
+#[derive(Debug)]
+#[cfg_attr(not(feature = "force-exhaustive-all"), non_exhaustive)]
+pub struct ScriptResultSetPage {
+    pub result_set_index: usize,
+    pub result_set: ResultSet,
+
+    /// Token for fetch next page, None for last page
+    pub next_fetch_token: Option<String>,
+}
+
+/// Script, executed by query service in background
+///
+/// Results of the script saved on server side and can be read by pages after the script completed.
+///
+/// Example:
+/// ```no_run
+/// # use ydb::YdbResult;
+/// #
+/// # #[tokio::main]
+/// # async fn main()->YdbResult<()>{
+/// #   use ydb::{FetchScriptResultsOptionsBuilder, Query};
+/// #   let query_client = ydb::ClientBuilder::new_from_connection_string("")?.client()?.query_client();
+///     let mut operation = query_client
+///         .execute_script(Query::new("SELECT * FROM big_table"))
+///         .await?;
+///     operation.wait().await?;
+///
+///     let mut fetch_token = None;
+///     loop {
+///         let mut opts = FetchScriptResultsOptionsBuilder::default();
+///         if let Some(token) = fetch_token {
+///             opts.fetch_token(token);
+///         }
+///         let page = operation.fetch_results(0, opts.build()?).await?;
+///         for row in page.result_set.rows() {
+///             println!("{row:?}");
+///         }
+///         fetch_token = page.next_fetch_token;
+///         if fetch_token.is_none() {
+///             break;
+///         }
+///     }
+/// #     return Ok(());
+/// # }
+/// ```
+pub struct ScriptOperation {
+    id: String,
+    ready: bool,
+    execution_id: String,
+    exec_status: ScriptExecStatus,
+    result_sets_count: usize,
+
+    connection_manager: GrpcConnectionManager,
+}
+
+impl ScriptOperation {
+    pub(crate) fn new(raw: RawScriptOperation, connection_manager: GrpcConnectionManager) -> Self {
+        let mut operation = Self {
+            id: String::new(),
+            ready: false,
+            execution_id: String::new(),
+            exec_status: ScriptExecStatus::Unspecified,
+            result_sets_count: 0,
+            connection_manager,
+        };
+        operation.update(raw);
+        operation
+    }
+
+    // Operation with known id only, state is unknown until refresh
+    pub(crate) fn from_id(id: String, connection_manager: GrpcConnectionManager) -> Self {
+        Self {
+            id,
+            ready: false,
+            execution_id: String::new(),
+            exec_status: ScriptExecStatus::Unspecified,
+            result_sets_count: 0,
+            connection_manager,
+        }
+    }
+
+    fn update(&mut self, raw: RawScriptOperation) {
+        self.id = raw.id;
+        self.ready = raw.ready;
+        self.execution_id = raw.execution_id;
+        self.exec_status = raw.exec_status.into();
+        self.result_sets_count = raw.result_sets_count;
+    }
+
+    /// Operation id, it can be used for get operation state from other processes
+    /// by [QueryClient::script_operation](crate::QueryClient::script_operation)
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn execution_id(&self) -> &str {
+        &self.execution_id
+    }
+
+    /// Operation completed, state from last refresh
+    pub fn ready(&self) -> bool {
+        self.ready
+    }
+
+    /// Script execution status, state from last refresh
+    pub fn exec_status(&self) -> ScriptExecStatus {
+        self.exec_status
+    }
+
+    /// Count of result sets, known after the script completed
+    pub fn result_sets_count(&self) -> usize {
+        self.result_sets_count
+    }
+
+    /// Get current operation state from server
+    ///
+    /// Return error if the script failed.
+    pub async fn refresh(&mut self) -> YdbResult<()> {
+        let mut client = self
+            .connection_manager
+            .get_auth_service(RawOperationClient::new)
+            .await?;
+        let raw: RawScriptOperation = client
+            .get_operation(RawGetOperationRequest {
+                id: self.id.clone(),
+            })
+            .await?;
+        self.update(raw);
+        Ok(())
+    }
+
+    /// Wait until script completed
+    ///
+    /// Refresh operation state periodically, return error if the script failed.
+    pub async fn wait(&mut self) -> YdbResult<()> {
+        let mut interval = INITIAL_POLL_INTERVAL;
+        while !self.ready {
+            tokio::time::sleep(interval).await;
+            self.refresh().await?;
+            trace!(
+                "script operation '{}' status: {:?}",
+                self.id,
+                self.exec_status
+            );
+            interval = (interval * 2).min(MAX_POLL_INTERVAL);
+        }
+        Ok(())
+    }
+
+    /// Start cancel of the script
+    ///
+    /// Script may be completed in spite of cancel, use [ScriptOperation::refresh] for check.
+    pub async fn cancel(&self) -> YdbResult<()> {
+        let mut client = self
+            .connection_manager
+            .get_auth_service(RawOperationClient::new)
+            .await?;
+        client
+            .cancel_operation(RawCancelOperationRequest {
+                id: self.id.clone(),
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Forget completed operation, results of the script will be removed.
+    pub async fn forget(&self) -> YdbResult<()> {
+        let mut client = self
+            .connection_manager
+            .get_auth_service(RawOperationClient::new)
+            .await?;
+        client
+            .forget_operation(RawForgetOperationRequest {
+                id: self.id.clone(),
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Fetch page of result set with index result_set_index
+    pub async fn fetch_results(
+        &self,
+        result_set_index: usize,
+        opts: FetchScriptResultsOptions,
+    ) -> YdbResult<ScriptResultSetPage> {
+        let req = RawFetchScriptResultsRequest {
+            operation_id: self.id.clone(),
+            result_set_index: i64::try_from(result_set_index)?,
+            fetch_token: opts.fetch_token.unwrap_or_default(),
+            rows_limit: i64::try_from(opts.rows_limit.unwrap_or_default())?,
+        };
+
+        let mut client = self
+            .connection_manager
+            .get_auth_service(RawQueryClient::new)
+            .await?;
+        let res = client.fetch_script_results(req).await?;
+
+        let raw_result_set = res.result_set.ok_or_else(|| {
+            YdbError::InternalError("no result set in fetch script results".into())
+        })?;
+
+        Ok(ScriptResultSetPage {
+            result_set_index: res.result_set_index,
+            result_set: ResultSet::try_from(raw_result_set)?,
+            next_fetch_token: if res.next_fetch_token.is_empty() {
+                None
+            } else {
+                Some(res.next_fetch_token)
+            },
+        })
+    }
+}
@@ -0,0 +1,283 @@
+#[cfg(test)]
+#[path = "session_test.rs"]
+mod session_test;
+
+use crate::errors::{YdbError, YdbResult};
+use crate::grpc_connection_manager::GrpcConnectionManager;
+use crate::grpc_wrapper::raw_errors::RawResult;
+use crate::grpc_wrapper::raw_query_service::attach_session::RawAttachSessionStream;
+use crate::grpc_wrapper::raw_query_service::begin_transaction::RawBeginTransactionRequest;
+use crate::grpc_wrapper::raw_query_service::client::RawQueryClient;
+use crate::grpc_wrapper::raw_query_service::commit_transaction::RawCommitTransactionRequest;
+use crate::grpc_wrapper::raw_query_service::execute_query::{
+    RawExecuteQueryRequest, RawExecuteQueryStream,
+};
+use crate::grpc_wrapper::raw_query_service::rollback_transaction::RawRollbackTransactionRequest;
+use crate::grpc_wrapper::raw_table_service::transaction_control::{
+    RawTransactionControl, RawTxSettings,
+};
+use crate::grpc_wrapper::raw_table_service::value::RawResultSet;
+use crate::query::Query;
+use crate::result::{QueryResult, ResultSet};
+use crate::transaction::Mode;
+use derivative::Derivative;
+use futures_util::StreamExt;
+use itertools::Itertools;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use tokio_util::sync::CancellationToken;
+use tracing::trace;
+
+type DropSessionCallback = dyn FnOnce(&mut QuerySession) + Send + Sync;
+
+/// Session of query service
+///
+/// Server keep the session alive while it attached to the client.
+/// The session returns to pool after drop.
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct QuerySession {
+    pub(crate) id: String,
+
+    pub(crate) can_pooled: bool,
+
+    #[derivative(Debug = "ignore")]
+    attach_state: Arc<AttachState>,
+
+    #[derivative(Debug = "ignore")]
+    on_drop_callbacks: Vec<Box<DropSessionCallback>>,
+
+    #[derivative(Debug = "ignore")]
+    channel_pool: Box<dyn CreateQueryClient>,
+}
+
+impl QuerySession {
+    pub(crate) fn new<CQ: CreateQueryClient + 'static>(id: String, channel_pool: CQ) -> Self {
+        Self {
+            id,
+            can_pooled: true,
+            attach_state: Arc::new(AttachState::default()),
+            on_drop_callbacks: Vec::new(),
+            channel_pool: Box::new(channel_pool),
+        }
+    }
+
+    // Listen session state stream in background, until last copy of the session will be dropped.
+    // Server close the session after the stream will be closed.
+    pub(crate) fn keep_attached(&self, mut stream: RawAttachSessionStream) {
+        let broken = self.attach_state.broken.clone();
+        let stop = self.attach_state.stop.clone();
+        let session_id = self.id.clone();
+        tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    _ = stop.cancelled() => return,
+                    res = stream.next_state() => {
+                        if let Err(err) = res {
+                            trace!("session '{}' detached: {}", session_id, err);
+                            broken.store(true, Ordering::Relaxed);
+                            return;
+                        }
+                    }
+                }
+            }
+        });
+    }
+
+    pub(crate) fn is_alive(&self) -> bool {
+        self.can_pooled && !self.attach_state.broken.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn handle_error(&mut self, err: &YdbError) {
+        if let YdbError::YdbStatusError(err) = err {
+            use ydb_grpc::ydb_proto::status_ids::StatusCode;
+            if let Ok(status) = StatusCode::try_from(err.operation_status) {
+                if status == StatusCode::BadSession || status == StatusCode::SessionExpired {
+                    self.can_pooled = false;
+                }
+            }
+        }
+    }
+
+    fn handle_raw_result<T>(&mut self, res: RawResult<T>) -> YdbResult<T> {
+        let res = res.map_err(YdbError::from);
+        if let Err(err) = &res {
+            self.handle_error(err);
+        }
+        res
+    }
+
+    /// Execute query without explicit transaction
+    ///
+    /// Use it for DDL queries or for data queries, which can be executed within implicit transaction.
+    pub async fn query(&mut self, query: Query) -> YdbResult<QueryResult> {
+        self.execute_query(query, None).await
+    }
+
+    pub(crate) async fn execute_query(
+        &mut self,
+        query: Query,
+        tx_control: Option<RawTransactionControl>,
+    ) -> YdbResult<QueryResult> {
+        let mut stream = self.start_query(query, tx_control).await?;
+
+        let mut result_sets: Vec<RawResultSet> = Vec::new();
+        let mut tx_id = String::new();
+        let mut stats = None;
+        loop {
+            let part = if let Some(part) = stream.next().await {
+                self.handle_raw_result(part)?
+            } else {
+                break;
+            };
+
+            if let Some(id) = part.tx_id {
+                tx_id = id;
+            }
+            if let Some(exec_stats) = part.exec_stats {
+                stats = Some(exec_stats.into());
+            }
+
+            let Some(result_set) = part.result_set else {
+                continue;
+            };
+
+            // result set can be split to many parts, columns sent in first part only
+            if part.result_set_index == result_sets.len() {
+                result_sets.push(result_set);
+            } else if let Some(current) = result_sets.get_mut(part.result_set_index) {
+                current.rows.extend(result_set.rows);
+                current.truncated = result_set.truncated;
+            } else {
+                return Err(YdbError::InternalError(format!(
+                    "unexpected result set index: {}, received result sets: {}",
+                    part.result_set_index,
+                    result_sets.len()
+                )));
+            }
+        }
+
+        Ok(QueryResult {
+            results: result_sets
+                .into_iter()
+                .map(ResultSet::try_from)
+                .try_collect()?,
+            tx_id,
+            stats,
+        })
+    }
+
+    pub(crate) async fn start_query(
+        &mut self,
+        query: Query,
+        tx_control: Option<RawTransactionControl>,
+    ) -> YdbResult<RawExecuteQueryStream> {
+        let req = RawExecuteQueryRequest {
+            session_id: self.id.clone(),
+            tx_control,
+            yql_text: query.text,
+            params: query
+                .parameters
+                .into_iter()
+                .map(|(k, v)| match v.try_into() {
+                    Ok(converted) => Ok((k, converted)),
+                    Err(err) => Err(err),
+                })
+                .try_collect()?,
+            stats_mode: query.stats_mode.into(),
+        };
+
+        let mut client = self.get_query_client().await?;
+        let stream = client.execute_query(req).await;
+        self.handle_raw_result(stream)
+    }
+
+    pub(crate) async fn begin_transaction(&mut self, mode: Mode) -> YdbResult<String> {
+        let mut client = self.get_query_client().await?;
+        let res = client
+            .begin_transaction(RawBeginTransactionRequest {
+                session_id: self.id.clone(),
+                tx_settings: RawTxSettings { mode: mode.into() },
+            })
+            .await;
+        Ok(self.handle_raw_result(res)?.tx_id)
+    }
+
+    pub(crate) async fn commit_transaction(&mut self, tx_id: String) -> YdbResult<()> {
+        let mut client = self.get_query_client().await?;
+        let res = client
+            .commit_transaction(RawCommitTransactionRequest {
+                session_id: self.id.clone(),
+                tx_id,
+            })
+            .await;
+        self.handle_raw_result(res)
+    }
+
+    pub(crate) async fn rollback_transaction(&mut self, tx_id: String) -> YdbResult<()> {
+        let mut client = self.get_query_client().await?;
+        let res = client
+            .rollback_transaction(RawRollbackTransactionRequest {
+                session_id: self.id.clone(),
+                tx_id,
+            })
+            .await;
+        self.handle_raw_result(res)
+    }
+
+    async fn get_query_client(&self) -> YdbResult<RawQueryClient> {
+        self.channel_pool.create_query_client().await
+    }
+
+    pub(crate) fn on_drop(&mut self, f: Box<DropSessionCallback>) {
+        self.on_drop_callbacks.push(f)
+    }
+
+    pub(crate) fn clone_without_ondrop(&self) -> Self {
+        Self {
+            id: self.id.clone(),
+            can_pooled: self.can_pooled,
+            attach_state: self.attach_state.clone(),
+            on_drop_callbacks: Vec::new(),
+            channel_pool: self.channel_pool.clone_box(),
+        }
+    }
+}
+
+impl Drop for QuerySession {
+    fn drop(&mut self) {
+        trace!("drop query session: {}", &self.id);
+        while let Some(on_drop) = self.on_drop_callbacks.pop() {
+            on_drop(self)
+        }
+    }
+}
+
+#[derive(Default)]
+struct AttachState {
+    broken: Arc<AtomicBool>,
+    stop: CancellationToken,
+}
+
+impl Drop for AttachState {
+    fn drop(&mut self) {
+        self.stop.cancel();
+    }
+}
+
+#[async_trait::async_trait]
+pub(crate) trait CreateQueryClient: Send + Sync {
+    async fn create_query_client(&self) -> YdbResult<RawQueryClient>;
+    fn clone_box(&self) -> Box<dyn CreateQueryClient>;
+}
+
+#[async_trait::async_trait]
+impl CreateQueryClient for GrpcConnectionManager {
+    async fn create_query_client(&self) -> YdbResult<RawQueryClient> {
+        self.get_auth_service(RawQueryClient::new).await
+    }
+
+    fn clone_box(&self) -> Box<dyn CreateQueryClient> {
+        Box::new(self.clone())
+    }
+}
@@ -0,0 +1,252 @@
+use crate::client_query::session::QuerySession;
+use crate::errors::*;
+use crate::grpc_connection_manager::GrpcConnectionManager;
+use crate::grpc_wrapper::raw_query_service::attach_session::RawAttachSessionRequest;
+use crate::grpc_wrapper::raw_query_service::client::RawQueryClient;
+use crate::shutdown::{ClientShutdown, ClosableSessionPool};
+use async_trait::async_trait;
+use std::collections::vec_deque::VecDeque;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, Weak};
+use tokio::sync::Semaphore;
+use tracing::trace;
+
+pub(crate) const DEFAULT_SIZE: usize = 1000;
+
+#[async_trait]
+pub(crate) trait QuerySessionFabric: Send + Sync {
+    async fn create_session(&self) -> YdbResult<QuerySession>;
+}
+
+#[async_trait]
+impl QuerySessionFabric for GrpcConnectionManager {
+    async fn create_session(&self) -> YdbResult<QuerySession> {
+        let mut client = self.get_auth_service(RawQueryClient::new).await?;
+        let session_res = client.create_session().await?;
+
+        let mut attach_stream = client
+            .attach_session(RawAttachSessionRequest {
+                session_id: session_res.id.clone(),
+            })
+            .await?;
+
+        // first message confirm successfully attach
+        attach_stream.next_state().await?;
+
+        let session = QuerySession::new(session_res.id, self.clone());
+        session.keep_attached(attach_stream);
+        Ok(session)
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct QuerySessionPool {
+    active_sessions: Arc<Semaphore>,
+    create_session: Arc<Box<dyn QuerySessionFabric>>,
+    idle_sessions: Arc<Mutex<VecDeque<QuerySession>>>,
+    in_use: Arc<AtomicUsize>,
+    shutdown: ClientShutdown,
+}
+
+impl QuerySessionPool {
+    pub(crate) fn new(
+        session_client: Box<dyn QuerySessionFabric>,
+        max_active_sessions: usize,
+        shutdown: ClientShutdown,
+    ) -> Self {
+        let pool = Self {
+            active_sessions: Arc::new(Semaphore::new(max_active_sessions)),
+            create_session: Arc::new(session_client),
+            idle_sessions: Arc::new(Mutex::new(VecDeque::new())),
+            in_use: Arc::new(AtomicUsize::new(0)),
+            shutdown,
+        };
+        pool.shutdown.register_session_pool(WeakQuerySessionPool {
+            idle_sessions: Arc::downgrade(&pool.idle_sessions),
+            in_use: Arc::downgrade(&pool.in_use),
+        });
+        pool
+    }
+
+    #[cfg(test)]
+    pub(crate) fn is_same(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.idle_sessions, &other.idle_sessions)
+    }
+
+    pub(crate) async fn session(&self) -> YdbResult<QuerySession> {
+        self.shutdown.check_not_closing()?;
+        let active_session_permit = self.active_sessions.clone().acquire_owned().await?;
+        let idle_sessions = self.idle_sessions.clone();
+        let in_use = self.in_use.clone();
+        let shutdown = self.shutdown.clone();
+
+        let mut session = loop {
+            let idle_session = {
+                // brackets need for drop mutex guard right after pop element: before start async await
+                idle_sessions.lock()?.pop_front()
+            };
+            match idle_session {
+                // session may be broken on server side while it was in pool
+                Some(session) if session.is_alive() => {
+                    trace!("got query session from pool: {}", &session.id);
+                    break session;
+                }
+                Some(session) => {
+                    trace!("skip broken query session from pool: {}", &session.id);
+                }
+                None => {
+                    let session = self.create_session.create_session().await?;
+                    trace!("create query session: {}", &session.id);
+                    break session;
+                }
+            }
+        };
+
+        in_use.fetch_add(1, Ordering::Relaxed);
+        session.on_drop(Box::new(move |s: &mut QuerySession| {
+            // server close the session after last copy of it will be dropped
+            if s.is_alive() && !shutdown.is_closing() {
+                trace!("moved to pool: {}", s.id);
+                idle_sessions
+                    .lock()
+                    .unwrap()
+                    .push_back(s.clone_without_ondrop());
+            }
+            in_use.fetch_sub(1, Ordering::Relaxed);
+            drop(active_session_permit);
+        }));
+        Ok(session)
+    }
+}
+
+struct WeakQuerySessionPool {
+    idle_sessions: Weak<Mutex<VecDeque<QuerySession>>>,
+    in_use: Weak<AtomicUsize>,
+}
+
+#[async_trait]
+impl ClosableSessionPool for WeakQuerySessionPool {
+    fn is_alive(&self) -> bool {
+        self.idle_sessions.strong_count() > 0
+    }
+
+    fn in_use(&self) -> usize {
+        self.in_use
+            .upgrade()
+            .map_or(0, |in_use| in_use.load(Ordering::Relaxed))
+    }
+
+    async fn close_idle_sessions(&self) {
+        if let Some(idle_sessions) = self.idle_sessions.upgrade() {
+            let sessions = std::mem::take(&mut *idle_sessions.lock().unwrap());
+            trace!("close idle query sessions: {}", sessions.len());
+            // server delete the sessions after attach streams will be closed
+            drop(sessions);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{QuerySessionFabric, QuerySessionPool, DEFAULT_SIZE};
+    use crate::client_query::session::{CreateQueryClient, QuerySession};
+    use crate::errors::{YdbError, YdbResult};
+    use crate::grpc_wrapper::raw_query_service::client::RawQueryClient;
+    use crate::shutdown::ClientShutdown;
+    use crate::ClientBuilder;
+    use async_trait::async_trait;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+    use tokio::sync::oneshot;
+
+    #[derive(Default)]
+    struct SessionClientMock {
+        created: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl QuerySessionFabric for SessionClientMock {
+        async fn create_session(&self) -> YdbResult<QuerySession> {
+            let num = self.created.fetch_add(1, Ordering::Relaxed);
+            Ok(QuerySession::new(
+                format!("session-{num}"),
+                QueryChannelPoolMock {},
+            ))
+        }
+    }
+
+    struct QueryChannelPoolMock {}
+
+    #[async_trait]
+    impl CreateQueryClient for QueryChannelPoolMock {
+        async fn create_query_client(&self) -> YdbResult<RawQueryClient> {
+            Err(YdbError::Custom("test".into()))
+        }
+
+        fn clone_box(&self) -> Box<dyn CreateQueryClient> {
+            Box::new(QueryChannelPoolMock {})
+        }
+    }
+
+    #[tokio::test]
+    async fn max_active_session() -> YdbResult<()> {
+        let pool = QuerySessionPool::new(
+            Box::<SessionClientMock>::default(),
+            1,
+            ClientShutdown::default(),
+        );
+        let first_session = pool.session().await?;
+
+        let (second_session_got_sender, mut second_session_got_receiver) = oneshot::channel();
+        let cloned_pool = pool.clone();
+
+        tokio::spawn(async move {
+            cloned_pool.session().await.unwrap();
+            second_session_got_sender.send(true).unwrap();
+        });
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        assert!(second_session_got_receiver.try_recv().is_err());
+
+        drop(first_session);
+
+        second_session_got_receiver.await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn reuse_alive_sessions_only() -> YdbResult<()> {
+        let fabric = SessionClientMock::default();
+        let created = fabric.created.clone();
+        let pool = QuerySessionPool::new(Box::new(fabric), DEFAULT_SIZE, ClientShutdown::default());
+
+        let session = pool.session().await?;
+        assert_eq!("session-0", session.id);
+        drop(session);
+
+        let mut session = pool.session().await?;
+        assert_eq!("session-0", session.id);
+        session.can_pooled = false;
+        drop(session);
+
+        let session = pool.session().await?;
+        assert_eq!("session-1", session.id);
+        assert_eq!(2, created.load(Ordering::Relaxed));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn query_clients_share_session_pool() -> YdbResult<()> {
+        let client =
+            ClientBuilder::new_from_connection_string("grpc://localhost:2136?database=/local")?
+                .client()?;
+        let first = client.query_client();
+        let second = client.query_client();
+        assert!(first.session_pool.is_same(&second.session_pool));
+        Ok(())
+    }
+}
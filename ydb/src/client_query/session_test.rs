@@ -0,0 +1,50 @@
+use crate::client_query::session::QuerySession;
+use crate::errors::YdbResult;
+use crate::query::Query;
+use crate::query_stats::StatsMode;
+use crate::test_helpers::{fake_grpc_channel, FakeQueryService};
+use prost::Message;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use ydb_grpc::ydb_proto::query::{ExecuteQueryRequest, ExecuteQueryResponsePart};
+use ydb_grpc::ydb_proto::status_ids::StatusCode;
+use ydb_grpc::ydb_proto::table_stats::QueryStats;
+
+#[tokio::test]
+async fn query_with_stats() -> YdbResult<()> {
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let channel = fake_grpc_channel({
+        let requests = requests.clone();
+        move |_method, request| {
+            requests
+                .lock()
+                .unwrap()
+                .push(ExecuteQueryRequest::decode(request).unwrap());
+            ExecuteQueryResponsePart {
+                status: StatusCode::Success as i32,
+                exec_stats: Some(QueryStats {
+                    total_duration_us: 10,
+                    ..QueryStats::default()
+                }),
+                ..ExecuteQueryResponsePart::default()
+            }
+            .encode_to_vec()
+        }
+    });
+    let mut session = QuerySession::new("session".to_string(), FakeQueryService { channel });
+
+    let res = session
+        .query(Query::new("SELECT 1").with_stats(StatsMode::Full))
+        .await?;
+    assert_eq!(
+        Some(Duration::from_micros(10)),
+        res.stats().map(|stats| stats.total_duration)
+    );
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(
+        ydb_grpc::ydb_proto::query::StatsMode::Full as i32,
+        requests[0].stats_mode
+    );
+    Ok(())
+}
@@ -0,0 +1,198 @@
+use crate::client_query::session::QuerySession;
+use crate::client_query::session_pool::QuerySessionPool;
+use crate::errors::{YdbError, YdbResult};
+use crate::grpc_wrapper::raw_table_service::transaction_control::{
+    RawTransactionControl, RawTxSelector, RawTxSettings,
+};
+use crate::query::Query;
+use crate::result::QueryResult;
+use crate::transaction::{Mode, Transaction, TransactionInfo};
+use async_trait::async_trait;
+use tracing::trace;
+
+// Every query execute in own transaction, which commit with the query
+pub(crate) struct QueryAutoCommit {
+    mode: Mode,
+    session_pool: QuerySessionPool,
+}
+
+impl QueryAutoCommit {
+    pub(crate) fn new(session_pool: QuerySessionPool, mode: Mode) -> Self {
+        Self { mode, session_pool }
+    }
+}
+
+#[async_trait]
+impl Transaction for QueryAutoCommit {
+    async fn query(&mut self, query: Query) -> YdbResult<QueryResult> {
+        let tx_control = RawTransactionControl {
+            commit_tx: true,
+            tx_selector: RawTxSelector::Begin(RawTxSettings {
+                mode: self.mode.into(),
+            }),
+        };
+
+        let mut session = self.session_pool.session().await?;
+        session.execute_query(query, Some(tx_control)).await
+    }
+
+    async fn commit(&mut self) -> YdbResult<()> {
+        Ok(())
+    }
+
+    async fn rollback(&mut self) -> YdbResult<()> {
+        Err(YdbError::from(
+            "impossible to rollback autocommit transaction",
+        ))
+    }
+}
+
+// Transaction begin with first query and hold session until finish
+pub(crate) struct QueryInteractiveTx {
+    mode: Mode,
+    session_pool: QuerySessionPool,
+
+    id: Option<String>,
+    session: Option<QuerySession>,
+    comitted: bool,
+    rollbacked: bool,
+    finished: bool,
+}
+
+impl QueryInteractiveTx {
+    pub(crate) fn new(session_pool: QuerySessionPool, mode: Mode) -> Self {
+        Self {
+            mode,
+            session_pool,
+
+            id: None,
+            session: None,
+            comitted: false,
+            rollbacked: false,
+            finished: false,
+        }
+    }
+
+    async fn session(&mut self) -> YdbResult<&mut QuerySession> {
+        if self.session.is_none() {
+            self.session = Some(self.session_pool.session().await?);
+            trace!("create query session from transaction");
+        }
+        Ok(self.session.as_mut().unwrap())
+    }
+}
+
+impl Drop for QueryInteractiveTx {
+    // rollback if unfinished
+    fn drop(&mut self) {
+        if !self.finished {
+            if let (Some(tx_id), Some(mut session)) = (self.id.take(), self.session.take()) {
+                tokio::spawn(async move {
+                    let _ = session.rollback_transaction(tx_id).await;
+                });
+            };
+        };
+    }
+}
+
+#[async_trait]
+impl Transaction for QueryInteractiveTx {
+    async fn query(&mut self, query: Query) -> YdbResult<QueryResult> {
+        if self.finished {
+            return Err(YdbError::Custom(format!(
+                "query in finished transaction: {:?}",
+                &self.id
+            )));
+        }
+
+        let tx_selector = if let Some(tx_id) = &self.id {
+            trace!("tx_id: {}", tx_id);
+            RawTxSelector::Id(tx_id.clone())
+        } else {
+            trace!("start new transaction");
+            RawTxSelector::Begin(RawTxSettings {
+                mode: self.mode.into(),
+            })
+        };
+        let tx_control = RawTransactionControl {
+            commit_tx: false,
+            tx_selector,
+        };
+
+        let query_result = self
+            .session()
+            .await?
+            .execute_query(query, Some(tx_control))
+            .await?;
+        if self.id.is_none() && !query_result.tx_id.is_empty() {
+            self.id = Some(query_result.tx_id.clone());
+        };
+
+        Ok(query_result)
+    }
+
+    async fn commit(&mut self) -> YdbResult<()> {
+        if self.comitted {
+            // commit many times - ok
+            return Ok(());
+        }
+
+        if self.finished {
+            return Err(YdbError::Custom(format!(
+                "commit finished non comitted transaction: {:?}",
+                &self.id
+            )));
+        }
+        self.finished = true;
+
+        let tx_id = if let Some(id) = &self.id {
+            id.clone()
+        } else {
+            // commit non started transaction - ok
+            self.comitted = true;
+            return Ok(());
+        };
+
+        self.session().await?.commit_transaction(tx_id).await?;
+        self.comitted = true;
+        Ok(())
+    }
+
+    async fn rollback(&mut self) -> YdbResult<()> {
+        // double rollback is ok
+        if self.rollbacked {
+            return Ok(());
+        }
+
+        if self.finished {
+            return Err(YdbError::Custom(format!(
+                "rollback finished non rollbacked transaction: {:?}",
+                &self.id
+            )));
+        }
+        self.finished = true;
+        self.rollbacked = true;
+
+        let tx_id = if let Some(id) = &self.id {
+            id.clone()
+        } else {
+            // rollback non started transaction - ok
+            return Ok(());
+        };
+
+        self.session().await?.rollback_transaction(tx_id).await
+    }
+
+    async fn transaction_info(&mut self) -> YdbResult<TransactionInfo> {
+        if self.id.is_none() {
+            let mode = self.mode;
+            let tx_id = self.session().await?.begin_transaction(mode).await?;
+            self.id = Some(tx_id);
+        }
+
+        Ok(TransactionInfo {
+            transaction_id: self.id.clone().unwrap(),
+            session_id: self.session().await?.id.clone(),
+        })
+    }
+}
@@ -0,0 +1,204 @@
+use futures_util::StreamExt;
+use tracing::trace;
+use tracing_test::traced_test;
+
+use crate::client_query::script::{FetchScriptResultsOptionsBuilder, ScriptExecStatus};
+use crate::client_table::TransactionOptions;
+use crate::errors::YdbResult;
+use crate::query::Query;
+use crate::test_integration_helper::create_client;
+use crate::transaction::Mode;
+use crate::types::Value;
+use crate::ydb_params;
+
+#[tokio::test]
+#[traced_test]
+#[ignore] // need YDB access
+async fn retry_tx_select() -> YdbResult<()> {
+    let client = create_client().await?;
+    let res: i32 = client
+        .query_client()
+        .retry_tx(|mut t| async move {
+            let res = t
+                .query(Query::new("SELECT $val + 1 AS sum").with_params(ydb_params!("$val" => 1)))
+                .await?;
+            t.commit().await?;
+            Ok(res
+                .into_only_row()?
+                .remove_field_by_name("sum")?
+                .try_into()?)
+        })
+        .await?;
+    assert_eq!(2, res);
+    Ok(())
+}
+
+#[tokio::test]
+#[traced_test]
+#[ignore] // need YDB access
+async fn retry_tx_many_result_sets() -> YdbResult<()> {
+    let client = create_client().await?;
+    let res = client
+        .query_client()
+        .clone_with_transaction_options(
+            TransactionOptions::new()
+                .with_mode(Mode::OnlineReadonly)
+                .with_autocommit(true),
+        )
+        .retry_tx(|mut t| async move { Ok(t.query(Query::new("SELECT 1; SELECT 2, 3")).await?) })
+        .await?;
+    trace!("result: {:?}", &res);
+    assert_eq!(2, res.results.len());
+    Ok(())
+}
+
+#[tokio::test]
+#[traced_test]
+#[ignore] // need YDB access
+async fn retry_query_ddl_with_dml() -> YdbResult<()> {
+    let client = create_client().await?;
+    let table_name = "test_query_service_ddl_with_dml";
+    let query_client = client.query_client();
+    let res: Option<i64> = query_client
+        .retry_query(|mut session| async move {
+            session
+                .query(Query::new(format!(
+                    "
+                DROP TABLE IF EXISTS {table_name};
+                CREATE TABLE {table_name} (id Int64 NOT NULL, PRIMARY KEY (id));
+                "
+                )))
+                .await?;
+            session
+                .query(Query::new(format!(
+                    "UPSERT INTO {table_name} (id) VALUES (1);
+                    SELECT id FROM {table_name};"
+                )))
+                .await?
+                .into_only_row()?
+                .remove_field(0)?
+                .try_into()
+                .map_err(Into::into)
+        })
+        .await?;
+    assert_eq!(Some(1), res);
+
+    query_client
+        .retry_query(|mut session| async move {
+            session
+                .query(Query::new(format!("DROP TABLE {table_name}")))
+                .await?;
+            Ok(())
+        })
+        .await?;
+    Ok(())
+}
+
+#[tokio::test]
+#[traced_test]
+#[ignore] // need YDB access
+async fn interactive_transaction_info() -> YdbResult<()> {
+    let client = create_client().await?;
+    client
+        .query_client()
+        .retry_tx(|mut t| async move {
+            let info = t.transaction_info().await?;
+            assert!(!info.transaction_id.is_empty());
+            let value = t
+                .query(Query::new("SELECT 1 AS one"))
+                .await?
+                .into_only_row()?
+                .remove_field_by_name("one")?;
+            assert_eq!(Value::Int32(1), value);
+            t.rollback().await?;
+            Ok(())
+        })
+        .await?;
+    Ok(())
+}
+
+#[tokio::test]
+#[traced_test]
+#[ignore] // need YDB access
+async fn retry_query_stream() -> YdbResult<()> {
+    let client = create_client().await?;
+    let mut stream = client
+        .query_client()
+        .retry_query_stream(Query::new(
+            "
+            SELECT * FROM AS_TABLE(ListMap(ListFromRange(0, 100000), ($x) -> (<|id: $x|>)));
+            SELECT 1 AS one;
+            ",
+        ))
+        .await?;
+
+    let mut rows_by_result_set = [0usize; 2];
+    while let Some(part) = stream.next().await {
+        let (index, result_set) = part?;
+        assert!(!result_set.columns().is_empty());
+        rows_by_result_set[index] += result_set.rows().count();
+    }
+    assert_eq!([100000, 1], rows_by_result_set);
+    Ok(())
+}
+
+#[tokio::test]
+#[traced_test]
+#[ignore] // need YDB access
+async fn execute_script() -> YdbResult<()> {
+    let client = create_client().await?;
+    let mut operation = client
+        .query_client()
+        .execute_script(Query::new(
+            "SELECT * FROM AS_TABLE(ListMap(ListFromRange(0, 250), ($x) -> (<|id: $x|>)));",
+        ))
+        .await?;
+    assert!(!operation.id().is_empty());
+
+    operation.wait().await?;
+    assert_eq!(ScriptExecStatus::Completed, operation.exec_status());
+    assert_eq!(1, operation.result_sets_count());
+
+    let mut rows = 0;
+    let mut fetch_token = None;
+    loop {
+        let mut opts = FetchScriptResultsOptionsBuilder::default();
+        opts.rows_limit(100);
+        if let Some(token) = fetch_token {
+            opts.fetch_token(token);
+        }
+        let page = operation.fetch_results(0, opts.build()?).await?;
+        assert_eq!(0, page.result_set_index);
+        rows += page.result_set.rows().count();
+        fetch_token = page.next_fetch_token;
+        if fetch_token.is_none() {
+            break;
+        }
+    }
+    assert_eq!(250, rows);
+
+    operation.forget().await?;
+    Ok(())
+}
+
+#[tokio::test]
+#[traced_test]
+#[ignore] // need YDB access
+async fn script_operation_by_id() -> YdbResult<()> {
+    let client = create_client().await?;
+    let id = client
+        .query_client()
+        .execute_script(Query::new("SELECT 1 AS id;"))
+        .await?
+        .id()
+        .to_string();
+
+    let mut operation = client.query_client().script_operation(id.clone()).await?;
+    assert_eq!(id, operation.id());
+    operation.wait().await?;
+    assert_eq!(ScriptExecStatus::Completed, operation.exec_status());
+    assert_eq!(1, operation.result_sets_count());
+
+    operation.forget().await?;
+    Ok(())
+}
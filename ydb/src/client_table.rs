@@ -1,27 +1,32 @@
+#[cfg(test)]
+#[path = "client_table_test.rs"]
+mod client_table_test;
+
 use crate::client::TimeoutSettings;
 
 use crate::errors::*;
 use crate::session::Session;
-use crate::session_pool::SessionPool;
-use crate::transaction::{AutoCommit, Mode, SerializableReadWriteTx, Transaction};
+use crate::session_pool::{SessionPool, SessionPoolOptions, SessionPoolStats};
+use crate::transaction::{AutoCommit, InteractiveTx, Mode, Transaction};
 use crate::types::Value;
 
-use crate::grpc_connection_manager::GrpcConnectionManager;
-
 use crate::grpc_wrapper::runtime_interceptors::InterceptedChannel;
-use crate::table_service_types::CopyTableItem;
-use crate::{Query, StreamResult};
-use num::pow;
+use crate::query_plan::QueryPlan;
+use crate::result::ReadTableStream;
+use crate::retry::{
+    retry_with_policy, retry_ydb_with_policy, BackoffRetrier, NoRetrier, Retry, RetryBudget,
+};
+use crate::table_service_types::{
+    AlterTableOptions, CopyTableItem, DescribeTableOptions, ReadTableOptions, RenameTableItem,
+    TableDescription,
+};
+use crate::{Query, ResultSet, StreamResult};
 use std::future::Future;
 use std::sync::Arc;
-use std::time::{Duration, Instant};
-use tokio::time::sleep;
-use tracing::{instrument, trace};
+use std::time::Duration;
+use tracing::instrument;
 use ydb_grpc::ydb_proto::table::v1::table_service_client::TableServiceClient;
 
-const DEFAULT_RETRY_TIMEOUT: Duration = Duration::from_secs(5);
-const INITIAL_RETRY_BACKOFF_MILLISECONDS: u64 = 1;
-
 pub(crate) type TableServiceClientType = TableServiceClient<InterceptedChannel>;
 
 type TransactionArgType = Box<dyn Transaction>; // real type may be changed
@@ -29,8 +34,8 @@ type TransactionArgType = Box<dyn Transaction>; // real type may be changed
 /// Options for create transaction
 #[derive(Clone)]
 pub struct TransactionOptions {
-    mode: Mode,
-    autocommit: bool, // Commit transaction after every query. From DB side it visible as many small transactions
+    pub(crate) mode: Mode,
+    pub(crate) autocommit: bool, // Commit transaction after every query. From DB side it visible as many small transactions
 }
 
 impl TransactionOptions {
@@ -45,6 +50,9 @@ impl TransactionOptions {
     }
 
     /// Set transaction [Mode]
+    ///
+    /// Interactive (non autocommit) transactions allowed with [Mode::SerializableReadWrite] and
+    /// [Mode::SnapshotReadonly] only.
     pub fn with_mode(mut self, mode: Mode) -> Self {
         self.mode = mode;
         self
@@ -82,16 +90,22 @@ impl RetryOptions {
     }
 
     /// Operations under the options is safe for complete few times instead of one.
-    #[allow(dead_code)]
-    pub(crate) fn with_idempotent(mut self, idempotent: bool) -> Self {
+    pub fn with_idempotent(mut self, idempotent: bool) -> Self {
         self.idempotent_operation = idempotent;
         self
     }
 
-    /// Set retry timeout
-    #[allow(dead_code)]
-    pub(crate) fn with_timeout(mut self, timeout: Duration) -> Self {
-        self.retrier = Some(Arc::new(Box::new(TimeoutRetrier { timeout })));
+    /// Set retry timeout for default retry policy
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.retrier = Some(Arc::new(Box::new(
+            BackoffRetrier::new().with_deadline(timeout),
+        )));
+        self
+    }
+
+    /// Set retry policy, see [Retry]
+    pub fn with_retry_policy(mut self, policy: impl Retry + 'static) -> Self {
+        self.retrier = Some(Arc::new(Box::new(policy)));
         self
     }
 }
@@ -114,6 +128,7 @@ pub struct TableClient {
     error_on_truncate: bool,
     session_pool: SessionPool,
     retrier: Arc<Box<dyn Retry>>,
+    retry_budget: RetryBudget,
     transaction_options: TransactionOptions,
     idempotent_operation: bool,
     timeouts: TimeoutSettings,
@@ -121,23 +136,37 @@ pub struct TableClient {
 
 impl TableClient {
     pub(crate) fn new(
-        connection_manager: GrpcConnectionManager,
+        session_pool: SessionPool,
         timeouts: TimeoutSettings,
+        retry_budget: RetryBudget,
     ) -> Self {
         Self {
             error_on_truncate: false,
-            session_pool: SessionPool::new(Box::new(connection_manager), timeouts),
-            retrier: Arc::new(Box::<TimeoutRetrier>::default()),
+            session_pool,
+            retrier: Arc::new(Box::<BackoffRetrier>::default()),
+            retry_budget,
             transaction_options: TransactionOptions::new(),
             idempotent_operation: false,
             timeouts,
         }
     }
 
-    #[allow(dead_code)]
-    pub(crate) fn with_max_active_sessions(mut self, size: usize) -> Self {
-        self.session_pool = self.session_pool.with_max_active_sessions(size);
-        self
+    /// Clone the table client with new empty session pool with the options
+    ///
+    /// Sessions of the pool doesn't share with other table clients.
+    pub fn clone_with_session_pool_options(&self, options: SessionPoolOptions) -> Self {
+        Self {
+            session_pool: self.session_pool.clone_with_options(options),
+            ..self.clone()
+        }
+    }
+
+    /// Snapshot of session pool state: count of sessions in use, idle, etc.
+    ///
+    /// The pool shared by all table clients of the [Client](crate::Client),
+    /// except clones with own pool by [TableClient::clone_with_session_pool_options].
+    pub fn session_pool_stats(&self) -> SessionPoolStats {
+        self.session_pool.stats()
     }
 
     // Clone the table client and set new timeouts settings
@@ -149,25 +178,24 @@ impl TableClient {
     }
 
     /// Clone the table client and set new retry timeouts
-    #[allow(dead_code)]
     pub fn clone_with_retry_timeout(&self, timeout: Duration) -> Self {
+        self.clone_with_retry_policy(BackoffRetrier::new().with_deadline(timeout))
+    }
+
+    /// Clone the table client and set retry policy, see [Retry]
+    pub fn clone_with_retry_policy(&self, policy: impl Retry + 'static) -> Self {
         Self {
-            retrier: Arc::new(Box::new(TimeoutRetrier { timeout })),
+            retrier: Arc::new(Box::new(policy)),
             ..self.clone()
         }
     }
 
     /// Clone the table client and deny retries
-    #[allow(dead_code)]
     pub fn clone_with_no_retry(&self) -> Self {
-        Self {
-            retrier: Arc::new(Box::new(NoRetrier {})),
-            ..self.clone()
-        }
+        self.clone_with_retry_policy(NoRetrier {})
     }
 
     /// Clone the table client and set feature operations as idempotent (can retry in more cases)
-    #[allow(dead_code)]
     pub fn clone_with_idempotent_operations(&self, idempotent: bool) -> Self {
         Self {
             idempotent_operation: idempotent,
@@ -187,13 +215,16 @@ impl TableClient {
             .with_error_on_truncate(self.error_on_truncate)
     }
 
-    pub(crate) fn create_interactive_transaction(&self) -> impl Transaction {
-        SerializableReadWriteTx::new(self.session_pool.clone(), self.timeouts)
+    pub(crate) fn create_interactive_transaction(&self, mode: Mode) -> impl Transaction {
+        InteractiveTx::new(self.session_pool.clone(), mode, self.timeouts)
             .with_error_on_truncate(self.error_on_truncate)
     }
 
-    #[allow(dead_code)]
-    pub(crate) async fn create_session(&self) -> YdbResult<Session> {
+    /// Get session from the session pool
+    ///
+    /// The session returned to the pool on drop.
+    /// The method doesn't retry errors. See [Session] for example.
+    pub async fn session(&self) -> YdbResult<Session> {
         Ok(self
             .session_pool
             .session()
@@ -208,36 +239,20 @@ impl TableClient {
     where
         CallbackFuture: Future<Output = YdbResult<CallbackResult>>,
     {
-        let mut attempt: usize = 0;
-        let start = Instant::now();
-        loop {
-            attempt += 1;
-            let last_err = match callback().await {
-                Ok(res) => return Ok(res),
-                Err(err) => match (err.need_retry(), self.idempotent_operation) {
-                    (NeedRetry::True, _) => err,
-                    (NeedRetry::IdempotentOnly, true) => err,
-                    _ => return Err(err),
-                },
-            };
-
-            let now = std::time::Instant::now();
-            let retry_decision = self.retrier.wait_duration(RetryParams {
-                attempt,
-                time_from_start: now.duration_since(start),
-            });
-            if !retry_decision.allow_retry {
-                return Err(last_err);
-            }
-            tokio::time::sleep(retry_decision.wait_timeout).await;
-        }
+        retry_ydb_with_policy(
+            self.retrier.as_ref().as_ref(),
+            &self.retry_budget,
+            self.idempotent_operation,
+            callback,
+        )
+        .await
     }
 
     /// Execute scan query. The method will auto-retry errors while start query execution,
     /// but no retries after server start streaming result.
     pub async fn retry_execute_scan_query(&self, query: Query) -> YdbResult<StreamResult> {
         self.retry(|| async {
-            let mut session = self.create_session().await?;
+            let mut session = self.session().await?;
             session.execute_scan_query(query.clone()).await
         })
         .await
@@ -247,7 +262,7 @@ impl TableClient {
     pub async fn retry_execute_scheme_query<T: Into<String>>(&self, query: T) -> YdbResult<()> {
         let query = Arc::new(query.into());
         self.retry(|| async {
-            let mut session = self.create_session().await?;
+            let mut session = self.session().await?;
             session.execute_schema_query(query.to_string()).await
         })
         .await
@@ -273,7 +288,7 @@ impl TableClient {
         let value = Value::list_from(examle_value, rows)?;
 
         self.retry(|| async {
-            let mut session = self.create_session().await?;
+            let mut session = self.session().await?;
             session
                 .execute_bulk_upsert(table_path.clone(), value.clone())
                 .await
@@ -281,6 +296,115 @@ impl TableClient {
         .await
     }
 
+    /// Execute bulk upsert of Arrow record batch with retry policy, available with `arrow` feature
+    ///
+    /// Names of the batch columns must be same as table columns and arrow types must be compatible
+    /// with types of the columns. The batch sent to server in Arrow IPC format without conversion to Value.
+    #[cfg(feature = "arrow")]
+    pub async fn bulk_upsert_arrow(
+        &self,
+        table_path: String,
+        batch: arrow_array::RecordBatch,
+    ) -> YdbResult<()> {
+        if batch.num_rows() == 0 {
+            return Ok(());
+        }
+
+        let (schema, data) = crate::types_arrow::encode_record_batch(&batch)?;
+
+        self.retry(|| async {
+            let mut session = self.session().await?;
+            session
+                .execute_bulk_upsert_arrow(table_path.clone(), schema.clone(), data.clone())
+                .await
+        })
+        .await
+    }
+
+    /// Read rows by primary keys with retry policy
+    ///
+    /// keys - `Value::List` of structs with all primary key columns.
+    /// columns - names of columns for read, empty for read all columns.
+    ///
+    /// The request doesn't compile query and doesn't start transaction, it is faster than
+    /// select for point lookups. The operation is idempotent and retried on all retriable errors.
+    ///
+    /// Example:
+    /// ```no_run
+    /// # use ydb::YdbResult;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main()->YdbResult<()>{
+    /// #   use ydb::{ydb_struct, Value};
+    /// #   let table_client = ydb::ClientBuilder::new_from_connection_string("")?.client()?.table_client();
+    ///     let keys = Value::list_from(
+    ///         ydb_struct!("id" => 0_u64),
+    ///         vec![ydb_struct!("id" => 1_u64), ydb_struct!("id" => 2_u64)],
+    ///     )?;
+    ///     let result_set = table_client
+    ///         .read_rows("my_table".to_string(), keys, vec!["id".to_string(), "name".to_string()])
+    ///         .await?;
+    ///     for mut row in result_set.rows() {
+    ///         let name: Option<String> = row.remove_field_by_name("name")?.try_into()?;
+    ///     }
+    /// #     return Ok(());
+    /// # }
+    /// ```
+    pub async fn read_rows(
+        &self,
+        table_path: String,
+        keys: Value,
+        columns: Vec<String>,
+    ) -> YdbResult<ResultSet> {
+        match &keys {
+            Value::List(list) if matches!(&list.t, Value::Struct(_)) => {}
+            _ => {
+                return Err(YdbError::Custom(
+                    "expected list of ValueStruct for keys".to_string(),
+                ))
+            }
+        }
+
+        self.retry_with_session(RetryOptions::new().with_idempotent(true), |session| async {
+            let mut session = session; // force borrow for lifetime of t inside closure
+            Ok(session
+                .read_rows(table_path.clone(), keys.clone(), columns.clone())
+                .await?)
+        })
+        .await
+        .map_err(YdbOrCustomerError::to_ydb_error)
+    }
+
+    /// Get plan of data query without execute it, see [QueryPlan] for example
+    pub async fn explain(&self, query: Query) -> YdbResult<QueryPlan> {
+        self.retry_with_session(RetryOptions::new().with_idempotent(true), |session| async {
+            let mut session = session; // force borrow for lifetime of t inside closure
+            Ok(session.explain(query.clone()).await?)
+        })
+        .await
+        .map_err(YdbOrCustomerError::to_ydb_error)
+    }
+
+    /// Read rows of table by parts, see [ReadTableStream] for example
+    ///
+    /// Unlike scan query the read may be ordered by primary key and consistent by snapshot.
+    /// The method retry errors while start read only, errors while read parts returned from the stream.
+    pub async fn read_table(
+        &self,
+        table_path: String,
+        options: ReadTableOptions,
+    ) -> YdbResult<ReadTableStream> {
+        self.retry_with_session(RetryOptions::new().with_idempotent(true), |session| async {
+            let mut session = session; // force borrow for lifetime of t inside closure
+            let stream = session
+                .start_read_table(table_path.clone(), options.clone())
+                .await?;
+            Ok(ReadTableStream::new(session, stream))
+        })
+        .await
+        .map_err(YdbOrCustomerError::to_ydb_error)
+    }
+
     /// Retry callback in transaction
     ///
     /// retries callback as retry policy.
@@ -341,62 +465,36 @@ impl TableClient {
     where
         CallbackFuture: Future<Output = YdbResultWithCustomerErr<CallbackResult>>,
     {
-        let mut attempts: usize = 0;
-        let start = Instant::now();
-        loop {
-            attempts += 1;
-            trace!("attempt: {}", attempts);
-            let transaction: Box<dyn Transaction> = if self.transaction_options.autocommit {
-                Box::new(self.create_autocommit_transaction(self.transaction_options.mode))
-            } else {
-                if self.transaction_options.mode != Mode::SerializableReadWrite {
-                    return Err(YdbOrCustomerError::YDB(YdbError::Custom(
-                        "only serializable rw transactions allow to interactive mode".into(),
-                    )));
-                }
-                Box::new(self.create_interactive_transaction())
-            };
-
-            let res = callback(transaction).await;
-
-            let err = if let Err(err) = res {
-                err
-            } else {
-                match &res {
-                    Ok(_) => trace!("return successfully after '{}' attempts", attempts),
-                    Err(err) => trace!(
-                        "return with customer error after '{}' attempts: {:?}",
-                        attempts,
-                        err
-                    ),
-                };
-                return res;
-            };
-
-            if !Self::check_retry_error(self.idempotent_operation, &err) {
-                return Err(err);
-            }
-
-            let now = Instant::now();
-            let loop_decision = self.retrier.wait_duration(RetryParams {
-                attempt: attempts,
-                time_from_start: now.duration_since(start),
-            });
-            if loop_decision.allow_retry {
-                sleep(loop_decision.wait_timeout).await;
-            } else {
-                trace!(
-                    "return with ydb error after '{}' attempts by retry decision: {}",
-                    attempts,
-                    err
-                );
-                return Err(err);
-            };
+        if !self.transaction_options.autocommit
+            && !self.transaction_options.mode.allow_interactive()
+        {
+            return Err(YdbOrCustomerError::YDB(YdbError::Custom(
+                "only serializable rw and snapshot ro transactions allow to interactive mode"
+                    .into(),
+            )));
         }
+
+        retry_with_policy(
+            self.retrier.as_ref().as_ref(),
+            &self.retry_budget,
+            self.idempotent_operation,
+            || {
+                let transaction: TransactionArgType = if self.transaction_options.autocommit {
+                    Box::new(self.create_autocommit_transaction(self.transaction_options.mode))
+                } else {
+                    Box::new(self.create_interactive_transaction(self.transaction_options.mode))
+                };
+                callback(transaction)
+            },
+        )
+        .await
     }
 
-    #[allow(dead_code)]
-    pub(crate) async fn retry_with_session<CallbackFuture, CallbackResult>(
+    /// Retry callback with session from the pool by retry options
+    ///
+    /// Unlike [TableClient::retry_transaction] the callback works with session directly,
+    /// options override retry policy and idempotent flag of the client.
+    pub async fn retry_with_session<CallbackFuture, CallbackResult>(
         &self,
         opts: RetryOptions,
         callback: impl Fn(Session) -> CallbackFuture,
@@ -405,34 +503,13 @@ impl TableClient {
         CallbackFuture: Future<Output = YdbResultWithCustomerErr<CallbackResult>>,
     {
         let retrier = opts.retrier.unwrap_or_else(|| self.retrier.clone());
-        let mut attempts: usize = 0;
-        let start = Instant::now();
-        loop {
-            let session = self.create_session().await?;
-            let res = callback(session).await;
-
-            let err = if let Err(err) = res {
-                err
-            } else {
-                return res;
-            };
-
-            if !Self::check_retry_error(opts.idempotent_operation, &err) {
-                return Err(err);
-            }
-
-            let now = Instant::now();
-            attempts += 1;
-            let loop_decision = retrier.wait_duration(RetryParams {
-                attempt: attempts,
-                time_from_start: now.duration_since(start),
-            });
-            if loop_decision.allow_retry {
-                sleep(loop_decision.wait_timeout).await;
-            } else {
-                return Err(err);
-            };
-        }
+        retry_with_policy(
+            retrier.as_ref().as_ref(),
+            &self.retry_budget,
+            opts.idempotent_operation,
+            || async { callback(self.session().await?).await },
+        )
+        .await
     }
 
     pub fn with_error_on_truncate(mut self, error_on_truncate: bool) -> Self {
@@ -440,20 +517,6 @@ impl TableClient {
         self
     }
 
-    #[instrument(level = "trace", ret)]
-    fn check_retry_error(is_idempotent_operation: bool, err: &YdbOrCustomerError) -> bool {
-        let ydb_err = match &err {
-            YdbOrCustomerError::Customer(_) => return false,
-            YdbOrCustomerError::YDB(err) => err,
-        };
-
-        match ydb_err.need_retry() {
-            NeedRetry::True => true,
-            NeedRetry::IdempotentOnly => is_idempotent_operation,
-            NeedRetry::False => false,
-        }
-    }
-
     pub async fn copy_table(&self, source_path: String, destination_path: String) -> YdbResult<()> {
         self.retry_with_session(RetryOptions::new(), |session| async {
             let mut session = session; // force borrow for lifetime of t inside closure
@@ -477,62 +540,87 @@ impl TableClient {
         .await
         .map_err(YdbOrCustomerError::to_ydb_error)
     }
-}
 
-#[derive(Debug)]
-struct RetryParams {
-    pub(crate) attempt: usize,
-    pub(crate) time_from_start: Duration,
-}
-
-// May be extend in feature
-#[derive(Default, Debug)]
-struct RetryDecision {
-    pub(crate) allow_retry: bool,
-    pub(crate) wait_timeout: Duration,
-}
+    /// Create table by description. See [TableDescription] for example.
+    ///
+    /// Changefeeds from the description added by alter table request after create table.
+    /// Create and alter retried separately: retry of create table after failed alter
+    /// would fail with "already exists".
+    pub async fn create_table(&self, path: String, description: TableDescription) -> YdbResult<()> {
+        let mut description = description;
+        let changefeeds = std::mem::take(&mut description.changefeeds);
 
-trait Retry: Send + Sync {
-    fn wait_duration(&self, params: RetryParams) -> RetryDecision;
-}
+        self.retry_with_session(RetryOptions::new(), |session| async {
+            let mut session = session; // force borrow for lifetime of t inside closure
+            session
+                .create_table(path.clone(), description.clone())
+                .await?;
 
-#[derive(Debug)]
-struct TimeoutRetrier {
-    timeout: Duration,
-}
+            Ok(())
+        })
+        .await
+        .map_err(YdbOrCustomerError::to_ydb_error)?;
 
-impl Default for TimeoutRetrier {
-    fn default() -> Self {
-        Self {
-            timeout: DEFAULT_RETRY_TIMEOUT,
+        if changefeeds.is_empty() {
+            return Ok(());
         }
+        self.alter_table(
+            path,
+            AlterTableOptions {
+                add_changefeeds: changefeeds,
+                ..AlterTableOptions::default()
+            },
+        )
+        .await
     }
-}
 
-impl Retry for TimeoutRetrier {
-    #[instrument(ret)]
-    fn wait_duration(&self, params: RetryParams) -> RetryDecision {
-        let mut res = RetryDecision::default();
-        if params.time_from_start < self.timeout {
-            if params.attempt > 0 {
-                res.wait_timeout =
-                    Duration::from_millis(pow(INITIAL_RETRY_BACKOFF_MILLISECONDS, params.attempt));
-            }
-            res.allow_retry = (params.time_from_start + res.wait_timeout) < self.timeout;
-        };
+    pub async fn alter_table(&self, path: String, options: AlterTableOptions) -> YdbResult<()> {
+        self.retry_with_session(RetryOptions::new(), |session| async {
+            let mut session = session; // force borrow for lifetime of t inside closure
+            session.alter_table(path.clone(), options.clone()).await?;
 
-        res
+            Ok(())
+        })
+        .await
+        .map_err(YdbOrCustomerError::to_ydb_error)
+    }
+
+    /// Describe table, the operation is idempotent and retried on all retriable errors
+    pub async fn describe_table(
+        &self,
+        path: String,
+        options: DescribeTableOptions,
+    ) -> YdbResult<TableDescription> {
+        self.retry_with_session(RetryOptions::new().with_idempotent(true), |session| async {
+            let mut session = session; // force borrow for lifetime of t inside closure
+            Ok(session
+                .describe_table(path.clone(), options.clone())
+                .await?)
+        })
+        .await
+        .map_err(YdbOrCustomerError::to_ydb_error)
     }
-}
 
-struct NoRetrier {}
+    pub async fn drop_table(&self, path: String) -> YdbResult<()> {
+        self.retry_with_session(RetryOptions::new(), |session| async {
+            let mut session = session; // force borrow for lifetime of t inside closure
+            session.drop_table(path.clone()).await?;
 
-impl Retry for NoRetrier {
-    #[instrument(skip_all)]
-    fn wait_duration(&self, _: RetryParams) -> RetryDecision {
-        RetryDecision {
-            allow_retry: false,
-            wait_timeout: Duration::default(),
-        }
+            Ok(())
+        })
+        .await
+        .map_err(YdbOrCustomerError::to_ydb_error)
+    }
+
+    /// Rename (move) tables in one transaction
+    pub async fn rename_tables(&self, tables: Vec<RenameTableItem>) -> YdbResult<()> {
+        self.retry_with_session(RetryOptions::new(), |session| async {
+            let mut session = session; // force borrow for lifetime of t inside closure
+            session.rename_tables(tables.to_vec()).await?;
+
+            Ok(())
+        })
+        .await
+        .map_err(YdbOrCustomerError::to_ydb_error)
     }
 }
@@ -0,0 +1,156 @@
+use crate::client::TimeoutSettings;
+use crate::client_table::{TableClient, TransactionOptions};
+use crate::errors::{YdbError, YdbOrCustomerError, YdbResult};
+use crate::retry::{BackoffRetrier, RetryBudget};
+use crate::session_pool::{SessionPool, SessionPoolOptions};
+use crate::shutdown::ClientShutdown;
+use crate::table_service_types::{
+    ChangefeedBuilder, ChangefeedMode, TableColumnBuilder, TableDescriptionBuilder,
+};
+use crate::test_helpers::{fake_grpc_channel, operation_response, FakeTableService};
+use crate::{ClientBuilder, Query, Value};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use ydb_grpc::ydb_proto::status_ids::StatusCode;
+
+fn fake_table_client(service: FakeTableService) -> TableClient {
+    TableClient {
+        error_on_truncate: false,
+        session_pool: SessionPool::new(
+            Box::new(service),
+            TimeoutSettings::default(),
+            SessionPoolOptions::default(),
+            ClientShutdown::default(),
+        ),
+        retrier: Arc::new(Box::<BackoffRetrier>::default()),
+        retry_budget: RetryBudget::unlimited(),
+        transaction_options: TransactionOptions::new(),
+        idempotent_operation: false,
+        timeouts: TimeoutSettings::default(),
+    }
+}
+
+#[tokio::test]
+async fn table_clients_share_session_pool() -> YdbResult<()> {
+    let client =
+        ClientBuilder::new_from_connection_string("grpc://localhost:2136?database=/local")?
+            .client()?;
+    let first = client.table_client();
+    let second = client.table_client();
+    assert!(first.session_pool.is_same(&second.session_pool));
+
+    let own_pool = first.clone_with_session_pool_options(SessionPoolOptions::default());
+    assert!(!own_pool.session_pool.is_same(&first.session_pool));
+    Ok(())
+}
+
+#[tokio::test]
+async fn create_table_retries_changefeeds_separately() -> YdbResult<()> {
+    let calls = Arc::new(Mutex::new(HashMap::<String, usize>::new()));
+    let channel = fake_grpc_channel({
+        let calls = calls.clone();
+        move |method, _request| {
+            let mut calls = calls.lock().unwrap();
+            let count = calls.entry(method.to_string()).or_default();
+            *count += 1;
+            if method.ends_with("/AlterTable") && *count == 1 {
+                operation_response(StatusCode::Overloaded)
+            } else {
+                operation_response(StatusCode::Success)
+            }
+        }
+    });
+    let table_client = fake_table_client(FakeTableService { channel });
+
+    let description = TableDescriptionBuilder::default()
+        .column(
+            TableColumnBuilder::default()
+                .name("id")
+                .type_example(Value::Int64(0))
+                .build()?,
+        )
+        .primary_key_column("id".to_string())
+        .changefeed(
+            ChangefeedBuilder::default()
+                .name("feed")
+                .mode(ChangefeedMode::Updates)
+                .build()?,
+        )
+        .build()?;
+    table_client
+        .create_table("table".to_string(), description)
+        .await?;
+
+    let calls = calls.lock().unwrap();
+    assert_eq!(
+        Some(&1),
+        calls.get("/Ydb.Table.V1.TableService/CreateTable")
+    );
+    assert_eq!(Some(&2), calls.get("/Ydb.Table.V1.TableService/AlterTable"));
+    Ok(())
+}
+
+#[tokio::test(start_paused = true)]
+async fn retry_transaction_fails_on_exhausted_pool() -> YdbResult<()> {
+    let channel = fake_grpc_channel(|_method, _request| operation_response(StatusCode::Success));
+    let table_client = fake_table_client(FakeTableService { channel })
+        .clone_with_session_pool_options(
+            SessionPoolOptions::new()
+                .with_max_size(1)
+                .with_acquire_timeout(Duration::from_millis(100)),
+        );
+    let _busy = table_client.session_pool.session().await?;
+
+    let start = tokio::time::Instant::now();
+    let res = table_client
+        .retry_transaction(|mut t| async move {
+            t.query(Query::new("SELECT 1")).await?;
+            Ok(())
+        })
+        .await;
+    assert!(matches!(
+        res,
+        Err(YdbOrCustomerError::YDB(YdbError::SessionPoolExhausted(_)))
+    ));
+    assert!(start.elapsed() < Duration::from_millis(200));
+    Ok(())
+}
+
+#[tokio::test]
+async fn session_create_table_rejects_changefeeds() -> YdbResult<()> {
+    let calls = Arc::new(Mutex::new(Vec::<String>::new()));
+    let channel = fake_grpc_channel({
+        let calls = calls.clone();
+        move |method, _request| {
+            calls.lock().unwrap().push(method.to_string());
+            operation_response(StatusCode::Success)
+        }
+    });
+    let table_client = fake_table_client(FakeTableService { channel });
+
+    let description = TableDescriptionBuilder::default()
+        .column(
+            TableColumnBuilder::default()
+                .name("id")
+                .type_example(Value::Int64(0))
+                .build()?,
+        )
+        .primary_key_column("id".to_string())
+        .changefeed(
+            ChangefeedBuilder::default()
+                .name("feed")
+                .mode(ChangefeedMode::Updates)
+                .build()?,
+        )
+        .build()?;
+    let mut session = table_client.session_pool.session().await?;
+    let res = session.create_table("table".to_string(), description).await;
+    assert!(matches!(res, Err(YdbError::Custom(_))));
+    assert!(!calls
+        .lock()
+        .unwrap()
+        .iter()
+        .any(|method| method.ends_with("/CreateTable")));
+    Ok(())
+}
@@ -5,34 +5,64 @@ use std::time;
 use std::time::UNIX_EPOCH;
 use tokio::sync::Mutex as AsyncMutex;
 
+use futures_util::StreamExt;
 use rand::distributions::{Alphanumeric, DistString};
 use tonic::{Code, Status};
 use tracing::trace;
 use tracing_test::traced_test;
 
-use crate::client_table::RetryOptions;
+use crate::client_table::{RetryOptions, TransactionOptions};
 use crate::errors::{YdbError, YdbOrCustomerError, YdbResult};
 use crate::query::Query;
-use crate::table_service_types::CopyTableItem;
+use crate::table_service_types::{
+    AlterTableOptionsBuilder, ChangefeedBuilder, ChangefeedMode, CopyTableItem,
+    DescribeTableOptionsBuilder, KeyBound, KeyRangeBuilder, ReadTableOptionsBuilder,
+    RenameTableItem, TableColumnBuilder, TableDescriptionBuilder, TableIndexBuilder,
+    TableIndexType, TablePartitioningSettingsBuilder, TtlSettingsBuilder,
+};
 use crate::test_integration_helper::create_client;
-use crate::transaction::Mode;
 use crate::transaction::Transaction;
+use crate::transaction::{Mode, TxSettings};
 use crate::types::{Value, ValueList, ValueStruct};
-use crate::{ydb_params, ydb_struct, Bytes, TableClient};
+use crate::{ydb_params, ydb_struct, Bytes, StatsMode, TableClient, YdbRow, YdbStruct};
 
 #[tokio::test]
 #[traced_test]
 #[ignore] // need YDB access
 async fn create_session() -> YdbResult<()> {
-    let res = create_client()
-        .await?
-        .table_client()
-        .create_session()
-        .await?;
+    let res = create_client().await?.table_client().session().await?;
     trace!("session: {:?}", res);
     Ok(())
 }
 
+#[tokio::test]
+#[traced_test]
+#[ignore] // need YDB access
+async fn session_explicit_transaction() -> YdbResult<()> {
+    let client = create_client().await?;
+    let mut session = client.table_client().session().await?;
+    session.keep_alive().await?;
+
+    session
+        .begin_transaction(TxSettings::new(Mode::SerializableReadWrite))
+        .await?;
+    let info = session.transaction_info().unwrap();
+    assert!(!info.transaction_id.is_empty());
+    assert_eq!(session.id(), info.session_id);
+
+    let res = session
+        .execute_data_query(Query::new("SELECT 1+1 AS sum"))
+        .await?;
+    assert_eq!(res.tx_id, info.transaction_id);
+    session.commit().await?;
+    assert!(session.transaction_info().is_none());
+
+    session.begin_transaction(TxSettings::default()).await?;
+    session.rollback().await?;
+    assert!(session.rollback().await.is_err());
+    Ok(())
+}
+
 #[tokio::test]
 #[traced_test]
 #[ignore] // need YDB access
@@ -114,6 +144,50 @@ async fn execute_data_query_params() -> YdbResult<()> {
     Ok(())
 }
 
+#[tokio::test]
+#[traced_test]
+#[ignore] // need YDB access
+async fn retry_transaction_snapshot_readonly_interactive() -> YdbResult<()> {
+    let client = create_client().await?;
+    let table_client = client.table_client().clone_with_transaction_options(
+        TransactionOptions::new().with_mode(Mode::SnapshotReadonly),
+    );
+
+    let res: i32 = table_client
+        .retry_transaction(|mut t| async move {
+            let first: i32 = t
+                .query(Query::new("SELECT 1 AS val"))
+                .await?
+                .into_only_row()?
+                .remove_field_by_name("val")?
+                .try_into()?;
+            let second: i32 = t
+                .query(Query::new("SELECT 2 AS val"))
+                .await?
+                .into_only_row()?
+                .remove_field_by_name("val")?
+                .try_into()?;
+            t.commit().await?;
+            Ok(first + second)
+        })
+        .await?;
+    assert_eq!(3, res);
+
+    let stale_client = client
+        .table_client()
+        .clone_with_transaction_options(TransactionOptions::new().with_mode(Mode::StaleReadonly));
+    assert!(stale_client
+        .retry_transaction(|_t| async move { Ok(()) })
+        .await
+        .is_err());
+
+    for mode in [Mode::StaleReadonly, Mode::OnlineReadonlyInconsistent] {
+        let mut tx = client.table_client().create_autocommit_transaction(mode);
+        tx.query(Query::new("SELECT 1")).await?;
+    }
+    Ok(())
+}
+
 #[tokio::test]
 #[traced_test]
 #[ignore]
@@ -155,7 +229,7 @@ async fn interactive_transaction() -> YdbResult<()> {
 
     client
         .table_client()
-        .create_session()
+        .session()
         .await?
         .execute_schema_query(
             "CREATE TABLE test_values (id Int64, vInt64 Int64, PRIMARY KEY (id))".to_string(),
@@ -166,11 +240,15 @@ async fn interactive_transaction() -> YdbResult<()> {
         .table_client()
         .create_autocommit_transaction(Mode::SerializableReadWrite);
 
-    let mut tx = client.table_client().create_interactive_transaction();
+    let mut tx = client
+        .table_client()
+        .create_interactive_transaction(Mode::SerializableReadWrite);
     tx.query(Query::new("DELETE FROM test_values")).await?;
     tx.commit().await?;
 
-    let mut tx = client.table_client().create_interactive_transaction();
+    let mut tx = client
+        .table_client()
+        .create_interactive_transaction(Mode::SerializableReadWrite);
     tx.query(Query::new(
         "UPSERT INTO test_values (id, vInt64) VALUES (1, 2)",
     ))
@@ -217,7 +295,7 @@ async fn interactive_transaction() -> YdbResult<()> {
 
     client
         .table_client()
-        .create_session()
+        .session()
         .await?
         .execute_schema_query("DROP TABLE test_values".to_string())
         .await?;
@@ -252,7 +330,8 @@ async fn copy_table() -> YdbResult<()> {
 
     let mut transaction = table_client.create_autocommit_transaction(Mode::SerializableReadWrite);
 
-    let mut interactive_tx = table_client.create_interactive_transaction();
+    let mut interactive_tx =
+        table_client.create_interactive_transaction(Mode::SerializableReadWrite);
 
     interactive_tx
         .query(format!("UPSERT INTO {table_name} (id, vInt64) VALUES (1, 2)").into())
@@ -328,7 +407,8 @@ async fn copy_tables() -> YdbResult<()> {
 
     let mut transaction = table_client.create_autocommit_transaction(Mode::SerializableReadWrite);
 
-    let mut interactive_tx = table_client.create_interactive_transaction();
+    let mut interactive_tx =
+        table_client.create_interactive_transaction(Mode::SerializableReadWrite);
 
     interactive_tx
         .query(format!("UPSERT INTO {table_name} (id, vInt64) VALUES (1, 2)").into())
@@ -749,7 +829,7 @@ SELECT NULL
 #[ignore] // need YDB access
 async fn stream_query() -> YdbResult<()> {
     let client = create_client().await?.table_client();
-    let mut session = client.create_session().await?;
+    let mut session = client.session().await?;
 
     let _ = session
         .execute_schema_query("DROP TABLE stream_query".to_string())
@@ -959,3 +1039,538 @@ async fn bulk_upsert() -> YdbResult<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+#[traced_test]
+#[ignore] // need YDB access
+async fn bulk_upsert_derived_structs() -> YdbResult<()> {
+    #[derive(Debug, Default, PartialEq, YdbRow, YdbStruct)]
+    struct Item {
+        id: i64,
+        #[ydb(rename = "val")]
+        value: Option<String>,
+    }
+
+    let client = create_client().await?;
+    let table_client = client.table_client();
+    let table_name = "bulk_upsert_derived_structs";
+
+    table_client
+        .retry_execute_scheme_query(format!(
+            "
+                CREATE TABLE {table_name} (
+                    id Int64 NOT NULL,
+                    val Utf8,
+                    PRIMARY KEY (id)
+                );
+            "
+        ))
+        .await?;
+
+    let items = vec![
+        Item {
+            id: 1,
+            value: Some("test".to_string()),
+        },
+        Item { id: 2, value: None },
+    ];
+    table_client
+        .retry_execute_bulk_upsert(
+            format!("/local/{table_name}"),
+            items.into_iter().map(Value::from).collect(),
+        )
+        .await?;
+
+    let read: Vec<Item> = table_client
+        .retry_transaction(|mut t| async move {
+            let res = t
+                .query(
+                    Query::new(format!(
+                        "
+                        DECLARE $item AS Struct<id: Int64, val: Utf8?>;
+                        SELECT * FROM {table_name} WHERE id <= $item.id ORDER BY id
+                        "
+                    ))
+                    .with_params(ydb_params!("$item" => Item { id: 2, value: None })),
+                )
+                .await?;
+            Ok(res
+                .into_only_result()?
+                .into_typed()
+                .collect::<YdbResult<_>>()?)
+        })
+        .await?;
+
+    assert_eq!(
+        vec![
+            Item {
+                id: 1,
+                value: Some("test".to_string())
+            },
+            Item { id: 2, value: None },
+        ],
+        read
+    );
+
+    table_client
+        .retry_execute_scheme_query(format!("DROP TABLE {table_name}"))
+        .await?;
+    Ok(())
+}
+
+#[cfg(feature = "arrow")]
+#[tokio::test]
+#[traced_test]
+#[ignore] // need YDB access
+async fn bulk_upsert_arrow() -> YdbResult<()> {
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Int64Type;
+    use arrow_array::{Int64Array, RecordBatch, StringArray};
+    use arrow_schema::{DataType, Field, Schema};
+
+    let client = create_client().await?;
+    let table_client = client.table_client();
+    let table_name = "bulk_upsert_arrow";
+
+    table_client
+        .retry_execute_scheme_query(format!(
+            "
+                CREATE TABLE {table_name} (
+                    id Int64 NOT NULL,
+                    val Utf8,
+                    PRIMARY KEY (id)
+                );
+            "
+        ))
+        .await?;
+
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("id", DataType::Int64, false),
+        Field::new("val", DataType::Utf8, true),
+    ]));
+    let batch = RecordBatch::try_new(
+        schema,
+        vec![
+            Arc::new(Int64Array::from(vec![1, 2])),
+            Arc::new(StringArray::from(vec![Some("test"), None])),
+        ],
+    )?;
+    table_client
+        .bulk_upsert_arrow(format!("/local/{table_name}"), batch.clone())
+        .await?;
+
+    let read = table_client
+        .retry_transaction(|mut t| async move {
+            let res = t
+                .query(Query::new(format!(
+                    "SELECT id, val FROM {table_name} ORDER BY id"
+                )))
+                .await?;
+            Ok(res.into_only_result()?.to_record_batch()?)
+        })
+        .await?;
+
+    assert_eq!(
+        vec![1, 2],
+        read.column(0).as_primitive::<Int64Type>().values().to_vec()
+    );
+    assert_eq!(batch.column(1), read.column(1));
+
+    table_client
+        .retry_execute_scheme_query(format!("DROP TABLE {table_name}"))
+        .await?;
+    Ok(())
+}
+
+#[tokio::test]
+#[traced_test]
+#[ignore] // need YDB access
+async fn create_alter_rename_drop_table() -> YdbResult<()> {
+    let client = create_client().await?;
+    let table_client = client.table_client();
+    let database_path = client.database();
+
+    let rand_str = Alphanumeric.sample_string(&mut rand::thread_rng(), 16);
+    let table_path = format!("{database_path}/temp_table_{rand_str}");
+    let renamed_table_path = format!("{database_path}/renamed_temp_table_{rand_str}");
+
+    let description = TableDescriptionBuilder::default()
+        .column(
+            TableColumnBuilder::default()
+                .name("id")
+                .type_example(Value::Uint64(0))
+                .build()?,
+        )
+        .column(
+            TableColumnBuilder::default()
+                .name("name")
+                .type_example(Value::from(None::<String>))
+                .build()?,
+        )
+        .column(
+            TableColumnBuilder::default()
+                .name("created_at")
+                .type_example(Value::optional_from(Value::Timestamp(UNIX_EPOCH), None)?)
+                .build()?,
+        )
+        .primary_key_column("id".to_string())
+        .index(
+            TableIndexBuilder::default()
+                .name("by_name")
+                .index_columns(vec!["name".to_string()])
+                .index_type(TableIndexType::GlobalAsync)
+                .build()?,
+        )
+        .ttl_settings(
+            TtlSettingsBuilder::default()
+                .column_name("created_at")
+                .expire_after(time::Duration::from_secs(3600))
+                .build()?,
+        )
+        .partitioning_settings(
+            TablePartitioningSettingsBuilder::default()
+                .partitioning_by_size(true)
+                .min_partitions_count(2)
+                .build()?,
+        )
+        .uniform_partitions(2)
+        .changefeed(
+            ChangefeedBuilder::default()
+                .name("updates")
+                .mode(ChangefeedMode::Updates)
+                .build()?,
+        )
+        .build()?;
+    table_client
+        .create_table(table_path.clone(), description)
+        .await?;
+
+    table_client
+        .alter_table(
+            table_path.clone(),
+            AlterTableOptionsBuilder::default()
+                .add_column(
+                    TableColumnBuilder::default()
+                        .name("value")
+                        .type_example(Value::from(None::<i64>))
+                        .build()?,
+                )
+                .drop_index("by_name".to_string())
+                .drop_changefeed("updates".to_string())
+                .drop_ttl_settings(true)
+                .build()?,
+        )
+        .await?;
+
+    table_client
+        .rename_tables(vec![RenameTableItem::new(
+            table_path,
+            renamed_table_path.clone(),
+            false,
+        )])
+        .await?;
+
+    table_client.drop_table(renamed_table_path).await?;
+    Ok(())
+}
+
+#[tokio::test]
+#[traced_test]
+#[ignore] // need YDB access
+async fn describe_table() -> YdbResult<()> {
+    let client = create_client().await?;
+    let table_client = client.table_client();
+    let database_path = client.database();
+
+    let rand_str = Alphanumeric.sample_string(&mut rand::thread_rng(), 16);
+    let table_path = format!("{database_path}/temp_table_{rand_str}");
+
+    let description = TableDescriptionBuilder::default()
+        .column(
+            TableColumnBuilder::default()
+                .name("id")
+                .type_example(Value::Uint64(0))
+                .build()?,
+        )
+        .column(
+            TableColumnBuilder::default()
+                .name("name")
+                .type_example(Value::from(None::<String>))
+                .build()?,
+        )
+        .primary_key_column("id".to_string())
+        .index(
+            TableIndexBuilder::default()
+                .name("by_name")
+                .index_columns(vec!["name".to_string()])
+                .build()?,
+        )
+        .uniform_partitions(4)
+        .build()?;
+    table_client
+        .create_table(table_path.clone(), description)
+        .await?;
+
+    let description = table_client
+        .describe_table(
+            table_path.clone(),
+            DescribeTableOptionsBuilder::default()
+                .include_shard_key_bounds(true)
+                .include_table_stats(true)
+                .include_partition_stats(true)
+                .build()?,
+        )
+        .await?;
+
+    let columns: Vec<_> = description
+        .columns
+        .iter()
+        .map(|column| (column.name.as_str(), column.type_example.clone()))
+        .collect();
+    assert_eq!(
+        vec![
+            ("id", Value::from(None::<u64>)),
+            ("name", Value::from(None::<String>))
+        ],
+        columns
+    );
+    assert_eq!(vec!["id".to_string()], description.primary_key);
+    assert_eq!(1, description.indexes.len());
+    assert_eq!("by_name", description.indexes[0].name);
+    assert_eq!(TableIndexType::Global, description.indexes[0].index_type);
+    assert_eq!(3, description.shard_key_bounds.len());
+
+    let stats = description.table_stats.expect("table stats");
+    assert_eq!(4, stats.partitions);
+    assert_eq!(4, stats.partition_stats.len());
+
+    table_client.drop_table(table_path).await?;
+    Ok(())
+}
+
+#[tokio::test]
+#[traced_test]
+#[ignore] // need YDB access
+async fn read_rows() -> YdbResult<()> {
+    let client = create_client().await?;
+    let table_client = client.table_client();
+    let database_path = client.database();
+
+    let rand_str = Alphanumeric.sample_string(&mut rand::thread_rng(), 16);
+    let table_path = format!("{database_path}/temp_table_{rand_str}");
+
+    table_client
+        .retry_execute_scheme_query(format!(
+            "CREATE TABLE `{table_path}` (id Int64 NOT NULL, val Utf8, PRIMARY KEY (id))"
+        ))
+        .await?;
+
+    let rows = (0..10_i64)
+        .map(|id| ydb_struct!("id" => id, "val" => format!("val-{id}")))
+        .collect();
+    table_client
+        .retry_execute_bulk_upsert(table_path.clone(), rows)
+        .await?;
+
+    let keys = Value::list_from(
+        ydb_struct!("id" => 0_i64),
+        vec![
+            ydb_struct!("id" => 2_i64),
+            ydb_struct!("id" => 5_i64),
+            ydb_struct!("id" => 100_i64),
+        ],
+    )?;
+    let result_set = table_client
+        .read_rows(
+            table_path.clone(),
+            keys,
+            vec!["id".to_string(), "val".to_string()],
+        )
+        .await?;
+
+    let mut read: Vec<(i64, Option<String>)> = result_set
+        .rows()
+        .map(|mut row| {
+            let id: i64 = row.remove_field_by_name("id")?.try_into()?;
+            let val: Option<String> = row.remove_field_by_name("val")?.try_into()?;
+            Ok((id, val))
+        })
+        .collect::<YdbResult<_>>()?;
+    read.sort();
+    assert_eq!(
+        vec![
+            (2, Some("val-2".to_string())),
+            (5, Some("val-5".to_string()))
+        ],
+        read
+    );
+
+    table_client.drop_table(table_path).await?;
+    Ok(())
+}
+
+#[tokio::test]
+#[traced_test]
+#[ignore] // need YDB access
+async fn read_table() -> YdbResult<()> {
+    let client = create_client().await?;
+    let table_client = client.table_client();
+    let database_path = client.database();
+
+    let rand_str = Alphanumeric.sample_string(&mut rand::thread_rng(), 16);
+    let table_path = format!("{database_path}/temp_table_{rand_str}");
+
+    table_client
+        .retry_execute_scheme_query(format!(
+            "CREATE TABLE `{table_path}` (id Int64, val Utf8, PRIMARY KEY (id))"
+        ))
+        .await?;
+
+    let rows = (0..1000_i64)
+        .map(|id| ydb_struct!("id" => id, "val" => format!("val-{id}")))
+        .collect();
+    table_client
+        .retry_execute_bulk_upsert(table_path.clone(), rows)
+        .await?;
+
+    let mut stream = table_client
+        .read_table(
+            table_path.clone(),
+            ReadTableOptionsBuilder::default()
+                .column("id")
+                .key_range(
+                    KeyRangeBuilder::default()
+                        .from(KeyBound::inclusive(vec![Value::from(Some(100_i64))]))
+                        .to(KeyBound::exclusive(vec![Value::from(Some(200_i64))]))
+                        .build()?,
+                )
+                .ordered(true)
+                .use_snapshot(true)
+                .build()?,
+        )
+        .await?;
+
+    let mut ids = Vec::new();
+    while let Some(result_set) = stream.next().await {
+        for mut row in result_set?.rows() {
+            let id: Option<i64> = row.remove_field_by_name("id")?.try_into()?;
+            ids.push(id.unwrap());
+        }
+    }
+    assert_eq!((100..200).collect::<Vec<i64>>(), ids);
+
+    let mut stream = table_client
+        .read_table(
+            table_path.clone(),
+            ReadTableOptionsBuilder::default().row_limit(10).build()?,
+        )
+        .await?;
+    let mut rows_count = 0;
+    while let Some(result_set) = stream.next().await {
+        rows_count += result_set?.rows().count();
+    }
+    assert_eq!(10, rows_count);
+
+    table_client.drop_table(table_path).await?;
+    Ok(())
+}
+
+#[tokio::test]
+#[traced_test]
+#[ignore] // need YDB access
+async fn explain() -> YdbResult<()> {
+    let client = create_client().await?;
+    let table_client = client.table_client();
+    let database_path = client.database();
+
+    let rand_str = Alphanumeric.sample_string(&mut rand::thread_rng(), 16);
+    let table_path = format!("{database_path}/temp_table_{rand_str}");
+
+    table_client
+        .retry_execute_scheme_query(format!(
+            "CREATE TABLE `{table_path}` (id Int64 NOT NULL, val Utf8, PRIMARY KEY (id))"
+        ))
+        .await?;
+
+    let full_scan = table_client
+        .explain(Query::new(format!(
+            "SELECT id FROM `{table_path}` WHERE val = 'test'"
+        )))
+        .await?;
+    assert!(!full_scan.ast.is_empty());
+    assert!(full_scan.root.is_some());
+    assert_eq!(table_path, full_scan.tables[0].name);
+    assert!(full_scan.has_full_scan());
+
+    let lookup = table_client
+        .explain(Query::new(format!(
+            "DECLARE $id AS Int64; SELECT val FROM `{table_path}` WHERE id = $id"
+        )))
+        .await?;
+    assert!(!lookup.has_full_scan());
+
+    table_client.drop_table(table_path).await?;
+    Ok(())
+}
+
+#[tokio::test]
+#[traced_test]
+#[ignore] // need YDB access
+async fn prepare_and_execute_prepared() -> YdbResult<()> {
+    let client = create_client().await?;
+    let mut session = client.table_client().session().await?;
+
+    let text = "DECLARE $val AS Int64; SELECT $val + 1 AS res";
+    let prepared = session
+        .prepare(Query::new(text).with_params(ydb_params!("$val" => 1_i64)))
+        .await?;
+    assert!(!prepared.query_id().is_empty());
+    assert_eq!(
+        Some(&Value::Int64(0)),
+        prepared.parameter_types().get("$val")
+    );
+
+    let res: i64 = session
+        .execute_prepared(prepared)
+        .await?
+        .into_only_row()?
+        .remove_field_by_name("res")?
+        .try_into()?;
+    assert_eq!(2, res);
+
+    // second prepare of same text served from session cache
+    let cached = session.prepare(Query::new(text)).await?;
+    let res: i64 = session
+        .execute_prepared(cached.with_params(ydb_params!("$val" => 2_i64)))
+        .await?
+        .into_only_row()?
+        .remove_field_by_name("res")?
+        .try_into()?;
+    assert_eq!(3, res);
+    Ok(())
+}
+
+#[tokio::test]
+#[traced_test]
+#[ignore] // need YDB access
+async fn query_stats() -> YdbResult<()> {
+    let client = create_client().await?;
+    let table_client = client.table_client();
+
+    let res = table_client
+        .retry_transaction(|mut t| async move { Ok(t.query(Query::new("SELECT 1 AS res")).await?) })
+        .await?;
+    assert!(res.stats().is_none());
+
+    let res = table_client
+        .retry_transaction(|mut t| async move {
+            let query = Query::new("SELECT 1 AS res").with_stats(StatsMode::Full);
+            Ok(t.query(query).await?)
+        })
+        .await?;
+    let stats = res.stats().unwrap();
+    assert!(!stats.query_phases.is_empty());
+    assert!(!stats.query_ast.is_empty());
+    stats.plan()?;
+    Ok(())
+}
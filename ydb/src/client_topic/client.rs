@@ -14,10 +14,14 @@ use crate::grpc_wrapper::raw_topic_service::create_topic::RawCreateTopicRequest;
 use crate::grpc_wrapper::raw_topic_service::describe_consumer::RawDescribeConsumerRequest;
 use crate::grpc_wrapper::raw_topic_service::describe_topic::RawDescribeTopicRequest;
 use crate::grpc_wrapper::raw_topic_service::drop_topic::RawDropTopicRequest;
+use crate::retry::{retry_ydb_with_policy, BackoffRetrier, NoRetrier, Retry, RetryBudget};
+use crate::shutdown::ClientShutdown;
 use crate::YdbError::InternalError;
 use crate::{grpc_wrapper, YdbResult};
 use derive_builder::{Builder, UninitializedFieldError};
 use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Arc;
 use std::time::Duration;
 
 #[derive(Builder)]
@@ -118,6 +122,9 @@ pub struct TopicClient {
     timeouts: TimeoutSettings,
     connection_manager: GrpcConnectionManager,
     token_cache: TokenCache,
+    retrier: Arc<Box<dyn Retry>>,
+    retry_budget: RetryBudget,
+    shutdown: ClientShutdown,
 }
 
 impl TopicClient {
@@ -125,14 +132,32 @@ impl TopicClient {
         timeouts: TimeoutSettings,
         connection_manager: GrpcConnectionManager,
         token_cache: TokenCache,
+        retry_budget: RetryBudget,
+        shutdown: ClientShutdown,
     ) -> Self {
         Self {
             timeouts,
             connection_manager,
             token_cache,
+            retrier: Arc::new(Box::<BackoffRetrier>::default()),
+            retry_budget,
+            shutdown,
         }
     }
 
+    /// Clone the topic client and set retry policy for topic operations, see [Retry]
+    pub fn clone_with_retry_policy(&self, policy: impl Retry + 'static) -> Self {
+        Self {
+            retrier: Arc::new(Box::new(policy)),
+            ..self.clone()
+        }
+    }
+
+    /// Clone the topic client and deny retries of topic operations
+    pub fn clone_with_no_retry(&self) -> Self {
+        self.clone_with_retry_policy(NoRetrier {})
+    }
+
     pub async fn create_topic(
         &mut self,
         path: String,
@@ -140,19 +165,23 @@ impl TopicClient {
     ) -> YdbResult<()> {
         let req = RawCreateTopicRequest::new(path, self.timeouts.operation_params(), options);
 
-        let mut service = self.raw_client_connection().await?;
-        service.create_topic(req).await?;
-
-        Ok(())
+        self.retry(false, || async {
+            let mut service = self.raw_client_connection().await?;
+            service.create_topic(req.clone()).await?;
+            Ok(())
+        })
+        .await
     }
 
     pub async fn alter_topic(&mut self, path: String, options: AlterTopicOptions) -> YdbResult<()> {
         let req = RawAlterTopicRequest::new(path, self.timeouts.operation_params(), options);
 
-        let mut service = self.raw_client_connection().await?;
-        service.alter_topic(req).await?;
-
-        Ok(())
+        self.retry(false, || async {
+            let mut service = self.raw_client_connection().await?;
+            service.alter_topic(req.clone()).await?;
+            Ok(())
+        })
+        .await
     }
 
     pub async fn describe_consumer(
@@ -168,8 +197,12 @@ impl TopicClient {
             options,
         );
 
-        let mut service = self.raw_client_connection().await?;
-        let result = service.describe_consumer(req).await?;
+        let result = self
+            .retry(true, || async {
+                let mut service = self.raw_client_connection().await?;
+                Ok(service.describe_consumer(req.clone()).await?)
+            })
+            .await?;
         let description = super::list_types::ConsumerDescription::from(result);
 
         Ok(description)
@@ -182,8 +215,12 @@ impl TopicClient {
     ) -> YdbResult<TopicDescription> {
         let req = RawDescribeTopicRequest::new(path, self.timeouts.operation_params(), options);
 
-        let mut service = self.raw_client_connection().await?;
-        let result = service.describe_topic(req).await?;
+        let result = self
+            .retry(true, || async {
+                let mut service = self.raw_client_connection().await?;
+                Ok(service.describe_topic(req.clone()).await?)
+            })
+            .await?;
         let description = TopicDescription::from(result);
 
         Ok(description)
@@ -195,10 +232,12 @@ impl TopicClient {
             path,
         };
 
-        let mut service = self.raw_client_connection().await?;
-        service.delete_topic(req).await?;
-
-        Ok(())
+        self.retry(false, || async {
+            let mut service = self.raw_client_connection().await?;
+            service.delete_topic(req.clone()).await?;
+            Ok(())
+        })
+        .await
     }
 
     pub async fn create_reader(
@@ -206,11 +245,13 @@ impl TopicClient {
         consumer: String,
         topic: impl Into<TopicSelectors>,
     ) -> YdbResult<TopicReader> {
+        self.shutdown.check_not_closing()?;
         TopicReader::new(
             consumer,
             topic.into(),
             self.connection_manager.clone(),
             self.token_cache.clone(),
+            self.shutdown.stop_token(),
         )
         .await
     }
@@ -219,16 +260,40 @@ impl TopicClient {
         &mut self,
         writer_options: TopicWriterOptions,
     ) -> YdbResult<TopicWriter> {
-        TopicWriter::new(writer_options, self.connection_manager.clone()).await
+        self.shutdown.check_not_closing()?;
+        let writer = TopicWriter::new(
+            writer_options,
+            self.connection_manager.clone(),
+            self.shutdown.stop_token(),
+        )
+        .await?;
+        self.shutdown.register_topic_writer(writer.flush_handle());
+        Ok(writer)
     }
 
     pub async fn create_writer(&mut self, path: String) -> YdbResult<TopicWriter> {
-        TopicWriter::new(
+        self.create_writer_with_params(
             TopicWriterOptionsBuilder::default()
                 .topic_path(path)
                 .build()
                 .unwrap(),
-            self.connection_manager.clone(),
+        )
+        .await
+    }
+
+    async fn retry<CallbackFuture, CallbackResult>(
+        &self,
+        idempotent_operation: bool,
+        callback: impl Fn() -> CallbackFuture,
+    ) -> YdbResult<CallbackResult>
+    where
+        CallbackFuture: Future<Output = YdbResult<CallbackResult>>,
+    {
+        retry_ydb_with_policy(
+            self.retrier.as_ref().as_ref(),
+            &self.retry_budget,
+            idempotent_operation,
+            callback,
         )
         .await
     }
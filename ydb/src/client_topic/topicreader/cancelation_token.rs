@@ -6,10 +6,8 @@ pub struct YdbCancellationToken {
 }
 
 impl YdbCancellationToken {
-    pub(crate) fn new() -> Self {
-        Self {
-            token: TokioCancellationToken::new(),
-        }
+    pub(crate) fn from_tokio_token(token: TokioCancellationToken) -> Self {
+        Self { token }
     }
 
     pub fn cancel(&self) {
@@ -26,6 +26,7 @@ use std::time;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use tokio::select;
 use tokio::sync::mpsc::UnboundedSender;
+use tokio_util::sync::CancellationToken;
 use tracing::{debug, error, info, warn};
 use ydb_grpc::ydb_proto::topic::stream_read_message::{FromClient, FromServer};
 
@@ -66,7 +67,13 @@ impl TopicReader {
                 return Ok(batch);
             }
 
-            let resp = self.stream.receive::<RawFromServer>().await?;
+            let stop_token = self.stop_backgroung_work_token.to_tokio_token();
+            let resp = select! {
+                _ = stop_token.cancelled() => {
+                    return Err(YdbError::Cancelled("topic reader closed".to_string()));
+                }
+                resp = self.stream.receive::<RawFromServer>() => resp?,
+            };
             self.process_incoming_message(resp)?
         }
     }
@@ -162,6 +169,7 @@ impl TopicReader {
         selectors: TopicSelectors,
         connection_manager: GrpcConnectionManager,
         token_cache: TokenCache,
+        stop_token: CancellationToken,
     ) -> YdbResult<Self> {
         let mut topic_service = connection_manager
             .get_auth_service(RawTopicClient::new)
@@ -181,7 +189,7 @@ impl TopicReader {
             }))
             .await?;
 
-        let stop_backgroung_work_token = YdbCancellationToken::new();
+        let stop_backgroung_work_token = YdbCancellationToken::from_tokio_token(stop_token);
 
         let stop_update_token = stop_backgroung_work_token.clone();
 
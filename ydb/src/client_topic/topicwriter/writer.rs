@@ -10,13 +10,15 @@ use crate::grpc_wrapper::grpc_stream_wrapper::AsyncGrpcStreamWrapper;
 use crate::grpc_wrapper::raw_topic_service::common::codecs::RawSupportedCodecs;
 use crate::grpc_wrapper::raw_topic_service::stream_write::init::RawInitResponse;
 use crate::grpc_wrapper::raw_topic_service::stream_write::RawServerMessage;
+use crate::shutdown::FlushableWriter;
 use crate::{grpc_wrapper, YdbError, YdbResult};
+use async_trait::async_trait;
 use std::borrow::{Borrow, BorrowMut};
 
 use std::future::Future;
 use std::ops::Deref;
 use std::pin::Pin;
-use std::sync::{Arc, Mutex};
+use std::sync::{Arc, Mutex, Weak};
 use std::task::{Context, Poll};
 use std::time::Instant;
 use std::time::{Duration, UNIX_EPOCH};
@@ -96,6 +98,7 @@ impl TopicWriter {
     pub(crate) async fn new(
         writer_options: TopicWriterOptions,
         connection_manager: GrpcConnectionManager,
+        cancellation_token: CancellationToken,
     ) -> YdbResult<Self> {
         //TODO: split to smaller functions
 
@@ -124,7 +127,6 @@ impl TopicWriter {
             mpsc::Sender<TopicWriterMessage>,
             mpsc::Receiver<TopicWriterMessage>,
         ) = mpsc::channel(32_usize);
-        let cancellation_token = CancellationToken::new();
         let topic_writer_state = Arc::new(Mutex::new(TopicWriterMode::Working));
         let confirmation_reception_queue = Arc::new(Mutex::new(TopicWriterReceptionQueue::new()));
 
@@ -426,20 +428,62 @@ impl TopicWriter {
 
     pub async fn flush(&self) -> YdbResult<()> {
         self.is_cancelled().await?;
+        flush_reception_queue(&self.confirmation_reception_queue).await
+    }
 
-        let flush_op_completed = {
-            let mut reception_queue = self.confirmation_reception_queue.lock().unwrap();
-            reception_queue.init_flush_op()?
-        };
-
-        Ok(flush_op_completed.await?)
+    // Handle for flush the writer while close client
+    pub(crate) fn flush_handle(&self) -> TopicWriterFlushHandle {
+        TopicWriterFlushHandle {
+            writer_state: Arc::downgrade(&self.writer_state),
+            confirmation_reception_queue: Arc::downgrade(&self.confirmation_reception_queue),
+        }
     }
 
     async fn is_cancelled(&self) -> YdbResult<()> {
         let state = self.writer_state.lock().unwrap();
         match state.deref() {
+            TopicWriterMode::Working if self.cancellation_token.is_cancelled() => {
+                Err(YdbError::Cancelled("topic writer closed".to_string()))
+            }
             TopicWriterMode::Working => Ok(()),
             TopicWriterMode::FinishedWithError(err) => Err(err.clone()),
         }
     }
 }
+
+async fn flush_reception_queue(
+    confirmation_reception_queue: &Mutex<TopicWriterReceptionQueue>,
+) -> YdbResult<()> {
+    let flush_op_completed = {
+        let mut reception_queue = confirmation_reception_queue.lock().unwrap();
+        reception_queue.init_flush_op()?
+    };
+
+    Ok(flush_op_completed.await?)
+}
+
+pub(crate) struct TopicWriterFlushHandle {
+    writer_state: Weak<Mutex<TopicWriterMode>>,
+    confirmation_reception_queue: Weak<Mutex<TopicWriterReceptionQueue>>,
+}
+
+#[async_trait]
+impl FlushableWriter for TopicWriterFlushHandle {
+    fn is_alive(&self) -> bool {
+        self.confirmation_reception_queue.strong_count() > 0
+    }
+
+    async fn flush(&self) -> YdbResult<()> {
+        let (Some(writer_state), Some(confirmation_reception_queue)) = (
+            self.writer_state.upgrade(),
+            self.confirmation_reception_queue.upgrade(),
+        ) else {
+            return Ok(());
+        };
+
+        if let TopicWriterMode::FinishedWithError(err) = writer_state.lock().unwrap().deref() {
+            return Err(err.clone());
+        }
+        flush_reception_queue(&confirmation_reception_queue).await
+    }
+}
@@ -239,7 +239,7 @@ impl CommandLineCredentials {
         let cmd_parts: Vec<&str> = cmd.split_whitespace().collect();
 
         if cmd_parts.is_empty() {
-            return Err(YdbError::Custom(format!(
+            return Err(YdbError::Credentials(format!(
                 "can't split get token command: '{cmd}'"
             )));
         }
@@ -258,7 +258,7 @@ impl Credentials for CommandLineCredentials {
         let result = self.command.lock()?.output()?;
         if !result.status.success() {
             let err = String::from_utf8(result.stderr)?;
-            return Err(YdbError::Custom(format!(
+            return Err(YdbError::Credentials(format!(
                 "can't execute yc ({}): {}",
                 result.status.code().unwrap(),
                 err
@@ -408,9 +408,10 @@ impl ServiceAccountCredentials {
         let token = encode(
             &header,
             &claims,
-            &EncodingKey::from_rsa_pem(private_key).map_err(|e| YdbError::custom(e.to_string()))?,
+            &EncodingKey::from_rsa_pem(private_key)
+                .map_err(|e| YdbError::Credentials(e.to_string()))?,
         )
-        .map_err(|e| YdbError::custom(format!("can't build jwt: {e}")))?;
+        .map_err(|e| YdbError::Credentials(format!("can't build jwt: {e}")))?;
 
         debug!("Token was built");
         Ok(token)
@@ -0,0 +1,120 @@
+#[cfg(test)]
+#[path = "derive_test.rs"]
+mod derive_test;
+
+use crate::errors::YdbError;
+use crate::Value;
+
+/// Rust struct, mapped to Value::Struct
+///
+/// Implemented by `#[derive(YdbStruct)]` together with `From<T> for Value` and `TryFrom<Value> for T`.
+/// Derive Default for the struct for use it in Option and lists, Default value used as example of type.
+///
+/// Example:
+/// ```
+/// # use ydb::{Value, YdbResult, YdbStruct};
+/// # fn main() -> YdbResult<()> {
+/// #[derive(Debug, Default, PartialEq, YdbStruct)]
+/// struct Episode {
+///     id: u64,
+///     #[ydb(rename = "episode_title")]
+///     title: String,
+///     air_date: Option<String>,
+/// }
+///
+/// let episodes = vec![
+///     Episode { id: 1, title: "first".to_string(), air_date: None },
+///     Episode { id: 2, title: "second".to_string(), air_date: Some("2024-03-01".to_string()) },
+/// ];
+///
+/// // List of structs for query parameter or bulk upsert
+/// let list: Value = episodes.into_iter().collect();
+///
+/// let episodes: Vec<Episode> = list.try_into()?;
+/// assert_eq!("second", episodes[1].title);
+/// # Ok(())
+/// # }
+/// ```
+pub trait YdbStruct: Into<Value> + TryFrom<Value, Error = YdbError> {}
+
+impl<T: YdbStruct> TryFrom<Value> for Option<T> {
+    type Error = YdbError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Optional(opt_val) => opt_val.value.map(T::try_from).transpose(),
+            value => Ok(Some(value.try_into()?)),
+        }
+    }
+}
+
+/// Functions for code, generated by derive macros
+#[doc(hidden)]
+pub mod derive_helpers {
+    use crate::errors::{YdbError, YdbResult};
+    use crate::{Row, Value};
+    use std::collections::HashMap;
+
+    fn field_error(name: &str, err: YdbError) -> YdbError {
+        YdbError::Convert(format!("failed to convert field '{name}': {err}"))
+    }
+
+    pub fn row_field<T>(row: &mut Row, name: &str) -> YdbResult<T>
+    where
+        T: TryFrom<Value>,
+        YdbError: From<T::Error>,
+    {
+        if !row.has_field(name) {
+            return Err(YdbError::Convert(format!("row has no column '{name}'")));
+        }
+        T::try_from(row.remove_field_by_name(name)?).map_err(|err| field_error(name, err.into()))
+    }
+
+    pub fn row_optional_field<T>(row: &mut Row, name: &str) -> YdbResult<Option<T>>
+    where
+        Option<T>: TryFrom<Value>,
+        YdbError: From<<Option<T> as TryFrom<Value>>::Error>,
+    {
+        if row.has_field(name) {
+            row_field(row, name)
+        } else {
+            Ok(None)
+        }
+    }
+
+    pub fn struct_fields(value: Value, struct_name: &str) -> YdbResult<HashMap<String, Value>> {
+        match value {
+            Value::Struct(value_struct) => Ok(value_struct.into()),
+            value => Err(YdbError::Convert(format!(
+                "failed to convert from {} to {struct_name}",
+                value.kind_static()
+            ))),
+        }
+    }
+
+    pub fn struct_field<T>(fields: &mut HashMap<String, Value>, name: &str) -> YdbResult<T>
+    where
+        T: TryFrom<Value>,
+        YdbError: From<T::Error>,
+    {
+        let Some(value) = fields.remove(name) else {
+            return Err(YdbError::Convert(format!("struct has no field '{name}'")));
+        };
+        T::try_from(value).map_err(|err| field_error(name, err.into()))
+    }
+
+    pub fn struct_optional_field<T>(
+        fields: &mut HashMap<String, Value>,
+        name: &str,
+    ) -> YdbResult<Option<T>>
+    where
+        Option<T>: TryFrom<Value>,
+        YdbError: From<<Option<T> as TryFrom<Value>>::Error>,
+    {
+        if fields.contains_key(name) {
+            struct_field(fields, name)
+        } else {
+            Ok(None)
+        }
+    }
+}
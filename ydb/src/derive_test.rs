@@ -0,0 +1,161 @@
+use crate::errors::YdbResult;
+use crate::test_helpers::make_result_set;
+use crate::{Value, YdbRow, YdbStruct};
+
+#[derive(Clone, Debug, Default, PartialEq, YdbStruct)]
+struct Info {
+    #[ydb(rename = "info_text")]
+    text: String,
+    rating: Option<f64>,
+}
+
+#[derive(Debug, PartialEq, YdbRow)]
+struct Series {
+    id: u64,
+    #[ydb(rename = "series_title")]
+    title: String,
+    info: Info,
+    extra_info: Option<Info>,
+    release_year: Option<i32>,
+}
+
+#[test]
+fn row_to_struct() -> YdbResult<()> {
+    let info = Info {
+        text: "text".to_string(),
+        rating: Some(9.5),
+    };
+
+    let result_set = make_result_set(
+        vec![
+            ("id", Value::Uint64(0)),
+            ("series_title", Value::Text(String::new())),
+            ("info", info.clone().into()),
+            ("extra_info", Value::from(None::<Info>)),
+        ],
+        vec![
+            vec![
+                Value::Uint64(1),
+                Value::Text("first".to_string()),
+                info.clone().into(),
+                Value::from(Some(info.clone())),
+            ],
+            vec![
+                Value::Uint64(2),
+                Value::Text("second".to_string()),
+                Info::default().into(),
+                Value::from(None::<Info>),
+            ],
+        ],
+    )?;
+
+    let series: Vec<Series> = result_set.into_typed().collect::<YdbResult<_>>()?;
+    assert_eq!(
+        vec![
+            Series {
+                id: 1,
+                title: "first".to_string(),
+                info: info.clone(),
+                extra_info: Some(info),
+                release_year: None, // optional field without column
+            },
+            Series {
+                id: 2,
+                title: "second".to_string(),
+                info: Info::default(),
+                extra_info: None,
+                release_year: None,
+            }
+        ],
+        series
+    );
+    Ok(())
+}
+
+#[test]
+fn row_errors() -> YdbResult<()> {
+    let result_set = make_result_set(vec![("id", Value::Uint64(0))], vec![vec![Value::Uint64(1)]])?;
+    let err = result_set
+        .into_typed::<Series>()
+        .next()
+        .unwrap()
+        .unwrap_err();
+    assert!(err.to_string().contains("series_title"), "{err}");
+
+    let result_set = make_result_set(
+        vec![
+            ("id", Value::Text(String::new())),
+            ("series_title", Value::Text(String::new())),
+            ("info", Info::default().into()),
+        ],
+        vec![vec![
+            Value::Text("1".to_string()),
+            Value::Text("first".to_string()),
+            Info::default().into(),
+        ]],
+    )?;
+    let err = result_set
+        .into_typed::<Series>()
+        .next()
+        .unwrap()
+        .unwrap_err();
+    assert!(err.to_string().contains("'id'"), "{err}");
+    Ok(())
+}
+
+#[test]
+fn struct_conversions() -> YdbResult<()> {
+    let info = Info {
+        text: "text".to_string(),
+        rating: None,
+    };
+
+    let value: Value = info.clone().into();
+    assert_eq!(
+        Value::struct_from_fields(vec![
+            ("info_text".to_string(), "text".into()),
+            ("rating".to_string(), Value::from(None::<f64>)),
+        ]),
+        value
+    );
+    assert_eq!(info, Info::try_from(value)?);
+
+    // optional field can be absent in struct value
+    let value = Value::struct_from_fields(vec![("info_text".to_string(), "text".into())]);
+    assert_eq!(info, Info::try_from(value)?);
+
+    let list: Value = vec![info.clone(), Info::default()].into_iter().collect();
+    assert_eq!(vec![info, Info::default()], Vec::<Info>::try_from(list)?);
+
+    assert!(Info::try_from(Value::Int32(1)).is_err());
+    assert!(Info::try_from(Value::struct_from_fields(vec![])).is_err());
+    Ok(())
+}
+
+#[derive(Debug, PartialEq, YdbRow, YdbStruct)]
+struct Event {
+    r#type: String,
+}
+
+#[test]
+fn raw_identifier_field() -> YdbResult<()> {
+    let result_set = make_result_set(
+        vec![("type", Value::Text(String::new()))],
+        vec![vec![Value::Text("click".to_string())]],
+    )?;
+    let events: Vec<Event> = result_set.into_typed().collect::<YdbResult<_>>()?;
+    assert_eq!(
+        vec![Event {
+            r#type: "click".to_string()
+        }],
+        events
+    );
+
+    assert_eq!(
+        Value::struct_from_fields(vec![("type".to_string(), "click".into())]),
+        Value::from(Event {
+            r#type: "click".to_string()
+        })
+    );
+    Ok(())
+}
@@ -15,6 +15,7 @@ use itertools::Itertools;
 use std::time::Duration;
 use tokio::sync::watch::Receiver;
 use tokio::sync::{watch, Mutex};
+use tokio_util::sync::CancellationToken;
 
 use crate::grpc_connection_manager::GrpcConnectionManager;
 
@@ -192,15 +193,22 @@ impl TimerDiscovery {
         endpoint: &str,
         interval: Duration,
         token_waiter: Box<dyn Waiter>,
+        stop: CancellationToken,
     ) -> YdbResult<Self> {
         let state = Arc::new(DiscoverySharedState::new(connection_manager, endpoint)?);
         let state_weak = Arc::downgrade(&state);
-        tokio::spawn(async move {
+        let discovery = async move {
             trace!("timer discovery wait token");
             let result = token_waiter.wait().await;
             trace!("timer discovery first token done with result: {:?}", result);
             drop(token_waiter);
             DiscoverySharedState::background_discovery(state_weak, interval).await;
+        };
+        tokio::spawn(async move {
+            tokio::select! {
+                _ = stop.cancelled() => trace!("timer discovery stopped"),
+                _ = discovery => {}
+            }
         });
         Ok(TimerDiscovery { state })
     }
@@ -400,6 +408,7 @@ mod test {
     use std::str::FromStr;
     use std::sync::Arc;
     use std::time::Duration;
+    use tokio_util::sync::CancellationToken;
 
     #[tokio::test]
     #[ignore] // need YDB access
@@ -407,7 +416,10 @@ mod test {
         let cred = DBCredentials {
             database: test_client_builder().database.clone(),
             token_cache: tokio::task::spawn_blocking(|| {
-                TokenCache::new(test_client_builder().credentials.clone())
+                TokenCache::new(
+                    test_client_builder().credentials.clone(),
+                    CancellationToken::new(),
+                )
             })
             .await??,
         };
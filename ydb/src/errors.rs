@@ -1,6 +1,11 @@
+#[cfg(test)]
+#[path = "errors_test.rs"]
+mod errors_test;
+
 use crate::errors::NeedRetry::IdempotentOnly;
 
 use crate::grpc_wrapper::raw_errors::RawError;
+use crate::retry::BackoffType;
 use std::fmt::{Debug, Display, Formatter};
 use std::sync::Arc;
 use ydb_grpc::ydb_proto::status_ids::StatusCode;
@@ -74,7 +79,8 @@ pub(crate) enum NeedRetry {
 
 /// Error which can be returned from the crate.
 ///
-/// Now most of errors are simple Custom error with custom text.
+/// Use variants and classification methods ([YdbError::is_retryable], [YdbError::status_code],
+/// [YdbError::is_not_found], etc.) for detect error kind.
 /// Please not parse the text - it can be change at any time without compile check.
 /// Write about error type you need or PR it.
 #[derive(Clone, Debug)]
@@ -106,6 +112,27 @@ pub enum YdbError {
 
     /// Error from operation status
     YdbStatusError(YdbStatusError),
+
+    /// Input/output error, for example while read file with credentials key
+    Io(Arc<std::io::Error>),
+
+    /// Operation doesn't completed in time
+    Timeout(String),
+
+    /// Error of serialize or deserialize json
+    Json(Arc<serde_json::Error>),
+
+    /// Bad connection string, endpoint or other uri
+    InvalidConnectionString(String),
+
+    /// Error while create credentials or get token
+    Credentials(String),
+
+    /// Operation or background task was cancelled
+    Cancelled(String),
+
+    /// No free session in session pool during acquire timeout, the error is not retried
+    SessionPoolExhausted(String),
 }
 
 impl YdbError {
@@ -248,6 +275,14 @@ impl YdbError {
             Self::Custom(_) => NeedRetry::False,
             Self::InternalError(_) => NeedRetry::False,
             Self::NoRows => NeedRetry::False,
+            Self::Io(_) => NeedRetry::False,
+            Self::Timeout(_) => NeedRetry::IdempotentOnly,
+            Self::Json(_) => NeedRetry::False,
+            Self::InvalidConnectionString(_) => NeedRetry::False,
+            Self::Credentials(_) => NeedRetry::False,
+            Self::Cancelled(_) => NeedRetry::False,
+            // retry adds waiters to saturated pool, return acquire timeout to caller as is
+            Self::SessionPoolExhausted(_) => NeedRetry::False,
             Self::TransportDial(_) => NeedRetry::True,
             Self::Transport(_) => IdempotentOnly, // TODO: check when transport error created
             Self::TransportGRPCStatus(status) => {
@@ -277,6 +312,87 @@ impl YdbError {
             }
         }
     }
+
+    /// Check if operation with the error can be retried
+    ///
+    /// Some errors leave operation in unknown state (it may be completed or not),
+    /// retry of them is safe for idempotent operations only.
+    pub fn is_retryable(&self, idempotent: bool) -> bool {
+        match self.need_retry() {
+            NeedRetry::True => true,
+            NeedRetry::IdempotentOnly => idempotent,
+            NeedRetry::False => false,
+        }
+    }
+
+    /// Operation status from server, if the error is operation status error
+    ///
+    /// ```
+    /// # use ydb::{YdbError, YdbStatusError};
+    /// # use ydb_grpc::ydb_proto::status_ids::StatusCode;
+    /// let mut status = YdbStatusError::default();
+    /// status.operation_status = StatusCode::NotFound as i32;
+    /// let err = YdbError::YdbStatusError(status);
+    /// assert_eq!(err.status_code(), Some(StatusCode::NotFound));
+    /// assert!(err.is_not_found());
+    /// ```
+    pub fn status_code(&self) -> Option<StatusCode> {
+        match self {
+            Self::YdbStatusError(ydb_err) => StatusCode::try_from(ydb_err.operation_status).ok(),
+            _ => None,
+        }
+    }
+
+    /// Requested object not found
+    pub fn is_not_found(&self) -> bool {
+        self.status_code() == Some(StatusCode::NotFound)
+            || self.grpc_code() == Some(tonic::Code::NotFound)
+    }
+
+    /// Precondition of operation failed, for example insert existed row
+    pub fn is_precondition_failed(&self) -> bool {
+        self.status_code() == Some(StatusCode::PreconditionFailed)
+            || self.grpc_code() == Some(tonic::Code::FailedPrecondition)
+    }
+
+    /// Session is broken or expired, the session must not be used for next queries
+    pub fn is_session_expired(&self) -> bool {
+        matches!(
+            self.status_code(),
+            Some(StatusCode::BadSession | StatusCode::SessionExpired)
+        )
+    }
+
+    /// Error on connection or grpc level, server may not receive the request
+    pub fn is_transport(&self) -> bool {
+        matches!(
+            self,
+            Self::TransportDial(_) | Self::Transport(_) | Self::TransportGRPCStatus(_)
+        )
+    }
+
+    fn grpc_code(&self) -> Option<tonic::Code> {
+        match self {
+            Self::TransportGRPCStatus(status) => Some(status.code()),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn backoff_type(&self) -> BackoffType {
+        match self {
+            Self::TransportGRPCStatus(status)
+                if status.code() == tonic::Code::ResourceExhausted =>
+            {
+                BackoffType::Slow
+            }
+            Self::YdbStatusError(ydb_err)
+                if ydb_err.operation_status == StatusCode::Overloaded as i32 =>
+            {
+                BackoffType::Slow
+            }
+            _ => BackoffType::Fast,
+        }
+    }
 }
 
 impl Display for YdbError {
@@ -303,25 +419,73 @@ to_custom_ydb_err!(
     YdbOrCustomerError,
     std::convert::Infallible,
     http::Error,
-    http::uri::InvalidUriParts,
-    reqwest::Error,
-    serde_json::Error,
-    std::env::VarError,
-    std::io::Error,
     std::num::TryFromIntError,
     std::string::FromUtf8Error,
     std::time::SystemTimeError,
     &str,
     strum::ParseError,
     tonic::transport::Error,
-    tokio::sync::AcquireError,
-    tokio::sync::oneshot::error::RecvError,
-    tokio::sync::watch::error::RecvError,
-    tokio::task::JoinError,
+    tokio::sync::AcquireError
+);
+
+macro_rules! to_ydb_err {
+    ($variant:ident, $($t:ty),+) => {
+        $(
+        impl From<$t> for YdbError {
+            fn from(e: $t) -> Self {
+                return YdbError::$variant(e.to_string());
+            }
+        }
+        )+
+    };
+}
+
+to_ydb_err!(
+    InvalidConnectionString,
+    http::uri::InvalidUriParts,
     tonic::codegen::http::uri::InvalidUri,
     url::ParseError
 );
 
+to_ydb_err!(Credentials, reqwest::Error, std::env::VarError);
+
+to_ydb_err!(
+    Cancelled,
+    tokio::sync::oneshot::error::RecvError,
+    tokio::sync::watch::error::RecvError
+);
+
+impl From<std::io::Error> for YdbError {
+    fn from(e: std::io::Error) -> Self {
+        YdbError::Io(Arc::new(e))
+    }
+}
+
+impl From<serde_json::Error> for YdbError {
+    fn from(e: serde_json::Error) -> Self {
+        YdbError::Json(Arc::new(e))
+    }
+}
+
+impl From<tokio::time::error::Elapsed> for YdbError {
+    fn from(e: tokio::time::error::Elapsed) -> Self {
+        YdbError::Timeout(e.to_string())
+    }
+}
+
+impl From<tokio::task::JoinError> for YdbError {
+    fn from(e: tokio::task::JoinError) -> Self {
+        if e.is_cancelled() {
+            YdbError::Cancelled(e.to_string())
+        } else {
+            YdbError::Custom(e.to_string())
+        }
+    }
+}
+
+#[cfg(feature = "arrow")]
+to_custom_ydb_err!(arrow_schema::ArrowError);
+
 impl From<Box<dyn std::any::Any + Send>> for YdbError {
     fn from(e: Box<dyn std::any::Any + Send>) -> Self {
         YdbError::Custom(format!("{e:?}"))
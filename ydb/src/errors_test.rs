@@ -0,0 +1,91 @@
+use super::*;
+
+fn status_error(status: StatusCode) -> YdbError {
+    YdbError::YdbStatusError(YdbStatusError {
+        operation_status: status as i32,
+        ..YdbStatusError::default()
+    })
+}
+
+#[test]
+fn is_retryable() {
+    for (err, retryable, retryable_idempotent) in [
+        (status_error(StatusCode::Aborted), true, true),
+        (status_error(StatusCode::Undetermined), false, true),
+        (status_error(StatusCode::SchemeError), false, false),
+        (YdbError::from(tonic::Status::unavailable("")), false, true),
+        (YdbError::Timeout("timeout".to_string()), false, true),
+        (YdbError::Custom("custom".to_string()), false, false),
+    ] {
+        assert_eq!(retryable, err.is_retryable(false), "{err}");
+        assert_eq!(retryable_idempotent, err.is_retryable(true), "{err}");
+    }
+}
+
+#[test]
+fn classification() {
+    let not_found = status_error(StatusCode::NotFound);
+    assert_eq!(Some(StatusCode::NotFound), not_found.status_code());
+    assert!(not_found.is_not_found());
+    assert!(!not_found.is_precondition_failed());
+    assert!(!not_found.is_transport());
+    assert!(YdbError::from(tonic::Status::not_found("")).is_not_found());
+
+    assert!(status_error(StatusCode::PreconditionFailed).is_precondition_failed());
+    assert!(YdbError::from(tonic::Status::failed_precondition("")).is_precondition_failed());
+
+    assert!(status_error(StatusCode::BadSession).is_session_expired());
+    assert!(status_error(StatusCode::SessionExpired).is_session_expired());
+    assert!(!status_error(StatusCode::Aborted).is_session_expired());
+
+    let transport = YdbError::from(tonic::Status::unavailable(""));
+    assert!(transport.is_transport());
+    assert_eq!(None, transport.status_code());
+
+    let unknown_status = YdbError::YdbStatusError(YdbStatusError {
+        operation_status: 1,
+        ..YdbStatusError::default()
+    });
+    assert_eq!(None, unknown_status.status_code());
+}
+
+#[test]
+fn dedicated_variants() {
+    assert!(matches!(
+        YdbError::from(std::io::Error::from(std::io::ErrorKind::NotFound)),
+        YdbError::Io(_)
+    ));
+    assert!(matches!(
+        YdbError::from(serde_json::from_str::<i32>("bad").unwrap_err()),
+        YdbError::Json(_)
+    ));
+    assert!(matches!(
+        YdbError::from(url::Url::parse("bad url").unwrap_err()),
+        YdbError::InvalidConnectionString(_)
+    ));
+    assert!(matches!(
+        YdbError::from(std::env::VarError::NotPresent),
+        YdbError::Credentials(_)
+    ));
+
+    let (sender, receiver) = tokio::sync::oneshot::channel::<()>();
+    drop(sender);
+    let err = receiver.blocking_recv().unwrap_err();
+    assert!(matches!(YdbError::from(err), YdbError::Cancelled(_)));
+}
+
+#[tokio::test]
+async fn timeout_and_cancelled() {
+    let elapsed = tokio::time::timeout(
+        std::time::Duration::from_millis(1),
+        std::future::pending::<()>(),
+    )
+    .await
+    .unwrap_err();
+    assert!(matches!(YdbError::from(elapsed), YdbError::Timeout(_)));
+
+    let task = tokio::spawn(std::future::pending::<()>());
+    task.abort();
+    let err = task.await.unwrap_err();
+    assert!(matches!(YdbError::from(err), YdbError::Cancelled(_)));
+}
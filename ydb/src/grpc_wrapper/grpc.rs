@@ -38,6 +38,17 @@ where
     Ok(())
 }
 
+pub(crate) fn grpc_read_status(status: i32, issues: Vec<IssueMessage>) -> RawResult<()> {
+    if status != StatusCode::Success as i32 {
+        return Err(RawError::YdbStatus(crate::errors::YdbStatusError {
+            message: format!("{:?}", &issues),
+            operation_status: status,
+            issues: proto_issues_to_ydb_issues(issues),
+        }));
+    }
+    Ok(())
+}
+
 pub(crate) fn create_operation_error(op: ydb_grpc::ydb_proto::operations::Operation) -> RawError {
     RawError::YdbStatus(crate::errors::YdbStatusError {
         message: format!("{:?}", &op),
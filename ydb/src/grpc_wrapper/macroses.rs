@@ -73,6 +73,54 @@ macro_rules! request_with_hidden_result {
     };
 }
 
+// for services, which return status directly in response instead of operation object
+macro_rules! request_with_status {
+    (
+        $self: ident .service. $method: ident,
+        $RawRequest: ident => $GrpcRequestType: ty,
+        $GrpcResponseType: ty => $RawResultType: ty
+    ) => {
+        let req = <$GrpcRequestType>::from($RawRequest);
+
+        trace!(
+            " {} request: {}",
+            stringify!($ClientType.$method),
+            crate::trace_helpers::ensure_len_string(
+                serde_json::to_string(&req).unwrap_or("bad json".into())
+            )
+        );
+
+        let response: $GrpcResponseType = $self.service.$method(req).await?.into_inner();
+
+        trace!(
+            "{} result: {}",
+            stringify!($ClientType.$method),
+            crate::trace_helpers::ensure_len_string(
+                serde_json::to_string(&response).unwrap_or("bad json".into())
+            )
+        );
+
+        return <$RawResultType>::try_from(response);
+    };
+}
+
+macro_rules! request_with_status_without_result {
+    ( $self: ident .service. $method: ident, $RawRequest: ident => $GrpcRequestType: ty) => {
+        let req = <$GrpcRequestType>::from($RawRequest);
+
+        trace!(
+            " {} request: {}",
+            stringify!($ClientType.$method),
+            crate::trace_helpers::ensure_len_string(
+                serde_json::to_string(&req).unwrap_or("bad json".into())
+            )
+        );
+
+        let response = $self.service.$method(req).await?.into_inner();
+        return crate::grpc_wrapper::grpc::grpc_read_status(response.status, response.issues);
+    };
+}
+
 #[allow(unused_macros)]
 macro_rules! bidirectional_streaming_request {
     (
@@ -70,7 +70,7 @@ impl TryFrom<i32> for RawRateLimiterCountersMode {
     }
 }
 
-#[derive(Debug, serde::Serialize)]
+#[derive(Clone, Debug, serde::Serialize)]
 pub(crate) struct RawCoordinationNodeConfig {
     pub path: String,
     pub self_check_period_millis: u32,
@@ -4,7 +4,7 @@ use crate::grpc_wrapper::raw_ydb_operation::RawOperationParams;
 
 use super::config::RawCoordinationNodeConfig;
 
-#[derive(serde::Serialize)]
+#[derive(Clone, serde::Serialize)]
 pub(crate) struct RawCreateNodeRequest {
     pub path: String,
     pub operation_params: RawOperationParams,
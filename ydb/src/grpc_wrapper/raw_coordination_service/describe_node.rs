@@ -8,7 +8,7 @@ use crate::grpc_wrapper::{
 
 use super::config::RawCoordinationNodeConfig;
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub(crate) struct RawDescribeNodeRequest {
     pub path: String,
     pub operation_params: RawOperationParams,
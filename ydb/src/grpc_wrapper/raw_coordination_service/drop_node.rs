@@ -2,7 +2,7 @@ use ydb_grpc::ydb_proto::coordination::DropNodeRequest;
 
 use crate::grpc_wrapper::raw_ydb_operation::RawOperationParams;
 
-#[derive(serde::Serialize)]
+#[derive(Clone, serde::Serialize)]
 pub(crate) struct RawDropNodeRequest {
     pub path: String,
     pub operation_params: RawOperationParams,
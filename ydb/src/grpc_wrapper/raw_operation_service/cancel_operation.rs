@@ -0,0 +1,9 @@
+pub(crate) struct RawCancelOperationRequest {
+    pub id: String,
+}
+
+impl From<RawCancelOperationRequest> for ydb_grpc::ydb_proto::operations::CancelOperationRequest {
+    fn from(value: RawCancelOperationRequest) -> Self {
+        Self { id: value.id }
+    }
+}
@@ -0,0 +1,56 @@
+use crate::grpc_wrapper::raw_errors::{RawError, RawResult};
+use crate::grpc_wrapper::raw_operation_service::cancel_operation::RawCancelOperationRequest;
+use crate::grpc_wrapper::raw_operation_service::forget_operation::RawForgetOperationRequest;
+use crate::grpc_wrapper::raw_operation_service::get_operation::RawGetOperationRequest;
+use crate::grpc_wrapper::raw_services::{GrpcServiceForDiscovery, Service};
+use crate::grpc_wrapper::runtime_interceptors::InterceptedChannel;
+use tracing::trace;
+use ydb_grpc::ydb_proto::operation::v1::operation_service_client::OperationServiceClient;
+use ydb_grpc::ydb_proto::operations::Operation;
+
+pub(crate) struct RawOperationClient {
+    service: OperationServiceClient<InterceptedChannel>,
+}
+
+impl RawOperationClient {
+    pub fn new(service: InterceptedChannel) -> Self {
+        Self {
+            service: OperationServiceClient::new(service),
+        }
+    }
+
+    // Result type depends on kind of the operation, it must check operation status itself
+    pub async fn get_operation<T: TryFrom<Operation, Error = RawError>>(
+        &mut self,
+        req: RawGetOperationRequest,
+    ) -> RawResult<T> {
+        let req = ydb_grpc::ydb_proto::operations::GetOperationRequest::from(req);
+        trace!("get_operation request: {}", req.id);
+
+        let response = self.service.get_operation(req).await?.into_inner();
+        let operation = response
+            .operation
+            .ok_or_else(|| RawError::custom("no operation object in result"))?;
+        T::try_from(operation)
+    }
+
+    pub async fn cancel_operation(&mut self, req: RawCancelOperationRequest) -> RawResult<()> {
+        request_with_status_without_result!(
+            self.service.cancel_operation,
+            req => ydb_grpc::ydb_proto::operations::CancelOperationRequest
+        );
+    }
+
+    pub async fn forget_operation(&mut self, req: RawForgetOperationRequest) -> RawResult<()> {
+        request_with_status_without_result!(
+            self.service.forget_operation,
+            req => ydb_grpc::ydb_proto::operations::ForgetOperationRequest
+        );
+    }
+}
+
+impl GrpcServiceForDiscovery for RawOperationClient {
+    fn get_grpc_discovery_service() -> Service {
+        Service::Operation
+    }
+}
@@ -0,0 +1,9 @@
+pub(crate) struct RawForgetOperationRequest {
+    pub id: String,
+}
+
+impl From<RawForgetOperationRequest> for ydb_grpc::ydb_proto::operations::ForgetOperationRequest {
+    fn from(value: RawForgetOperationRequest) -> Self {
+        Self { id: value.id }
+    }
+}
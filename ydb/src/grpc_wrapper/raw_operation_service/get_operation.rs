@@ -0,0 +1,9 @@
+pub(crate) struct RawGetOperationRequest {
+    pub id: String,
+}
+
+impl From<RawGetOperationRequest> for ydb_grpc::ydb_proto::operations::GetOperationRequest {
+    fn from(value: RawGetOperationRequest) -> Self {
+        Self { id: value.id }
+    }
+}
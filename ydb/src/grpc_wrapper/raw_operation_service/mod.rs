@@ -0,0 +1,4 @@
+pub(crate) mod cancel_operation;
+pub(crate) mod client;
+pub(crate) mod forget_operation;
+pub(crate) mod get_operation;
@@ -0,0 +1,36 @@
+use crate::grpc_wrapper::grpc::grpc_read_status;
+use crate::grpc_wrapper::raw_errors::{RawError, RawResult};
+use ydb_grpc::ydb_proto::query::SessionState;
+
+pub(crate) struct RawAttachSessionRequest {
+    pub session_id: String,
+}
+
+impl From<RawAttachSessionRequest> for ydb_grpc::ydb_proto::query::AttachSessionRequest {
+    fn from(value: RawAttachSessionRequest) -> Self {
+        Self {
+            session_id: value.session_id,
+        }
+    }
+}
+
+// Session is alive on server while the stream is open
+pub(crate) struct RawAttachSessionStream {
+    stream: tonic::codec::Streaming<SessionState>,
+}
+
+impl RawAttachSessionStream {
+    pub(crate) fn new(stream: tonic::codec::Streaming<SessionState>) -> Self {
+        Self { stream }
+    }
+
+    // wait next session state message, return error if the session is broken
+    pub(crate) async fn next_state(&mut self) -> RawResult<()> {
+        let state = self
+            .stream
+            .message()
+            .await?
+            .ok_or_else(|| RawError::custom("attach session stream closed by server"))?;
+        grpc_read_status(state.status, state.issues)
+    }
+}
@@ -0,0 +1,35 @@
+use crate::grpc_wrapper::grpc::grpc_read_status;
+use crate::grpc_wrapper::raw_errors::RawError;
+use crate::grpc_wrapper::raw_table_service::transaction_control::RawTxSettings;
+use ydb_grpc::ydb_proto::query::BeginTransactionResponse;
+
+#[derive(serde::Serialize)]
+pub(crate) struct RawBeginTransactionRequest {
+    pub session_id: String,
+    pub tx_settings: RawTxSettings,
+}
+
+impl From<RawBeginTransactionRequest> for ydb_grpc::ydb_proto::query::BeginTransactionRequest {
+    fn from(value: RawBeginTransactionRequest) -> Self {
+        Self {
+            session_id: value.session_id,
+            tx_settings: Some(value.tx_settings.into()),
+        }
+    }
+}
+
+pub(crate) struct RawBeginTransactionResult {
+    pub tx_id: String,
+}
+
+impl TryFrom<BeginTransactionResponse> for RawBeginTransactionResult {
+    type Error = RawError;
+
+    fn try_from(value: BeginTransactionResponse) -> Result<Self, Self::Error> {
+        grpc_read_status(value.status, value.issues)?;
+        let tx_meta = value
+            .tx_meta
+            .ok_or_else(|| RawError::custom("no tx_meta at BeginTransactionResponse"))?;
+        Ok(Self { tx_id: tx_meta.id })
+    }
+}
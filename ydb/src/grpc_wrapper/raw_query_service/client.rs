@@ -0,0 +1,129 @@
+use crate::grpc_wrapper::raw_errors::RawResult;
+use crate::grpc_wrapper::raw_query_service::attach_session::{
+    RawAttachSessionRequest, RawAttachSessionStream,
+};
+use crate::grpc_wrapper::raw_query_service::begin_transaction::{
+    RawBeginTransactionRequest, RawBeginTransactionResult,
+};
+use crate::grpc_wrapper::raw_query_service::commit_transaction::RawCommitTransactionRequest;
+use crate::grpc_wrapper::raw_query_service::create_session::{
+    RawCreateSessionRequest, RawCreateSessionResult,
+};
+use crate::grpc_wrapper::raw_query_service::execute_query::{
+    RawExecuteQueryRequest, RawExecuteQueryStream,
+};
+use crate::grpc_wrapper::raw_query_service::execute_script::{
+    RawExecuteScriptRequest, RawScriptOperation,
+};
+use crate::grpc_wrapper::raw_query_service::fetch_script_results::{
+    RawFetchScriptResultsRequest, RawFetchScriptResultsResult,
+};
+use crate::grpc_wrapper::raw_query_service::rollback_transaction::RawRollbackTransactionRequest;
+use crate::grpc_wrapper::raw_services::{GrpcServiceForDiscovery, Service};
+use crate::grpc_wrapper::runtime_interceptors::InterceptedChannel;
+use crate::trace_helpers::ensure_len_string;
+use tracing::trace;
+use ydb_grpc::ydb_proto::query::v1::query_service_client::QueryServiceClient;
+
+pub(crate) struct RawQueryClient {
+    service: QueryServiceClient<InterceptedChannel>,
+}
+
+impl RawQueryClient {
+    pub fn new(service: InterceptedChannel) -> Self {
+        Self {
+            service: QueryServiceClient::new(service),
+        }
+    }
+
+    pub async fn create_session(&mut self) -> RawResult<RawCreateSessionResult> {
+        let req = RawCreateSessionRequest {};
+
+        request_with_status!(
+            self.service.create_session,
+            req => ydb_grpc::ydb_proto::query::CreateSessionRequest,
+            ydb_grpc::ydb_proto::query::CreateSessionResponse => RawCreateSessionResult
+        );
+    }
+
+    pub async fn attach_session(
+        &mut self,
+        req: RawAttachSessionRequest,
+    ) -> RawResult<RawAttachSessionStream> {
+        let req = ydb_grpc::ydb_proto::query::AttachSessionRequest::from(req);
+        trace!("attach session: {}", req.session_id);
+
+        let stream = self.service.attach_session(req).await?.into_inner();
+        Ok(RawAttachSessionStream::new(stream))
+    }
+
+    pub async fn begin_transaction(
+        &mut self,
+        req: RawBeginTransactionRequest,
+    ) -> RawResult<RawBeginTransactionResult> {
+        request_with_status!(
+            self.service.begin_transaction,
+            req => ydb_grpc::ydb_proto::query::BeginTransactionRequest,
+            ydb_grpc::ydb_proto::query::BeginTransactionResponse => RawBeginTransactionResult
+        );
+    }
+
+    pub async fn commit_transaction(&mut self, req: RawCommitTransactionRequest) -> RawResult<()> {
+        request_with_status_without_result!(
+            self.service.commit_transaction,
+            req => ydb_grpc::ydb_proto::query::CommitTransactionRequest
+        );
+    }
+
+    pub async fn rollback_transaction(
+        &mut self,
+        req: RawRollbackTransactionRequest,
+    ) -> RawResult<()> {
+        request_with_status_without_result!(
+            self.service.rollback_transaction,
+            req => ydb_grpc::ydb_proto::query::RollbackTransactionRequest
+        );
+    }
+
+    pub async fn execute_query(
+        &mut self,
+        req: RawExecuteQueryRequest,
+    ) -> RawResult<RawExecuteQueryStream> {
+        let req = ydb_grpc::ydb_proto::query::ExecuteQueryRequest::from(req);
+        trace!(
+            "execute_query request: {}",
+            ensure_len_string(serde_json::to_string(&req).unwrap_or("bad json".into()))
+        );
+
+        let stream = self.service.execute_query(req).await?.into_inner();
+        Ok(RawExecuteQueryStream::new(stream))
+    }
+
+    pub async fn execute_script(
+        &mut self,
+        req: RawExecuteScriptRequest,
+    ) -> RawResult<RawScriptOperation> {
+        request_with_status!(
+            self.service.execute_script,
+            req => ydb_grpc::ydb_proto::query::ExecuteScriptRequest,
+            ydb_grpc::ydb_proto::operations::Operation => RawScriptOperation
+        );
+    }
+
+    pub async fn fetch_script_results(
+        &mut self,
+        req: RawFetchScriptResultsRequest,
+    ) -> RawResult<RawFetchScriptResultsResult> {
+        request_with_status!(
+            self.service.fetch_script_results,
+            req => ydb_grpc::ydb_proto::query::FetchScriptResultsRequest,
+            ydb_grpc::ydb_proto::query::FetchScriptResultsResponse => RawFetchScriptResultsResult
+        );
+    }
+}
+
+impl GrpcServiceForDiscovery for RawQueryClient {
+    fn get_grpc_discovery_service() -> Service {
+        Service::Query
+    }
+}
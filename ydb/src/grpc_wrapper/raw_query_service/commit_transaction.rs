@@ -0,0 +1,13 @@
+pub(crate) struct RawCommitTransactionRequest {
+    pub session_id: String,
+    pub tx_id: String,
+}
+
+impl From<RawCommitTransactionRequest> for ydb_grpc::ydb_proto::query::CommitTransactionRequest {
+    fn from(value: RawCommitTransactionRequest) -> Self {
+        Self {
+            session_id: value.session_id,
+            tx_id: value.tx_id,
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use crate::grpc_wrapper::grpc::grpc_read_status;
+use crate::grpc_wrapper::raw_errors::RawError;
+use ydb_grpc::ydb_proto::query::{CreateSessionRequest, CreateSessionResponse};
+
+pub(crate) struct RawCreateSessionRequest {}
+
+impl From<RawCreateSessionRequest> for CreateSessionRequest {
+    fn from(_: RawCreateSessionRequest) -> Self {
+        Self {}
+    }
+}
+
+pub(crate) struct RawCreateSessionResult {
+    pub id: String,
+}
+
+impl TryFrom<CreateSessionResponse> for RawCreateSessionResult {
+    type Error = RawError;
+
+    fn try_from(value: CreateSessionResponse) -> Result<Self, Self::Error> {
+        grpc_read_status(value.status, value.issues)?;
+        Ok(Self {
+            id: value.session_id,
+        })
+    }
+}
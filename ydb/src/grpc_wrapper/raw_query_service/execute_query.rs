@@ -0,0 +1,105 @@
+use crate::grpc_wrapper::grpc::grpc_read_status;
+use crate::grpc_wrapper::raw_errors::{RawError, RawResult};
+use crate::grpc_wrapper::raw_table_service::query_stats::{RawQueryStatMode, RawQueryStats};
+use crate::grpc_wrapper::raw_table_service::transaction_control::RawTransactionControl;
+use crate::grpc_wrapper::raw_table_service::value::{RawResultSet, RawTypedValue};
+use futures_util::Stream;
+use std::collections::HashMap;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use ydb_grpc::ydb_proto::query::{
+    execute_query_request, ExecMode, ExecuteQueryResponsePart, QueryContent, StatsMode, Syntax,
+};
+
+#[derive(serde::Serialize)]
+pub(crate) struct RawExecuteQueryRequest {
+    pub session_id: String,
+
+    // None for execute query without transaction
+    pub tx_control: Option<RawTransactionControl>,
+    pub yql_text: String,
+    pub params: HashMap<String, RawTypedValue>,
+    pub stats_mode: RawQueryStatMode,
+}
+
+impl From<RawQueryStatMode> for StatsMode {
+    fn from(value: RawQueryStatMode) -> Self {
+        match value {
+            RawQueryStatMode::None => StatsMode::None,
+            RawQueryStatMode::Basic => StatsMode::Basic,
+            RawQueryStatMode::Full => StatsMode::Full,
+            RawQueryStatMode::Profile => StatsMode::Profile,
+        }
+    }
+}
+
+impl From<RawExecuteQueryRequest> for ydb_grpc::ydb_proto::query::ExecuteQueryRequest {
+    fn from(v: RawExecuteQueryRequest) -> Self {
+        Self {
+            session_id: v.session_id,
+            exec_mode: ExecMode::Execute as i32,
+            tx_control: v.tx_control.map(Into::into),
+            query: Some(execute_query_request::Query::QueryContent(QueryContent {
+                syntax: Syntax::YqlV1 as i32,
+                text: v.yql_text,
+            })),
+            parameters: v.params.into_iter().map(|(k, v)| (k, v.into())).collect(),
+            stats_mode: StatsMode::from(v.stats_mode) as i32,
+            ..Self::default()
+        }
+    }
+}
+
+pub(crate) struct RawExecuteQueryResponsePart {
+    pub result_set_index: usize,
+    pub result_set: Option<RawResultSet>,
+    pub tx_id: Option<String>,
+
+    // sent with last part of the result
+    pub exec_stats: Option<RawQueryStats>,
+}
+
+impl TryFrom<ExecuteQueryResponsePart> for RawExecuteQueryResponsePart {
+    type Error = RawError;
+
+    fn try_from(value: ExecuteQueryResponsePart) -> Result<Self, Self::Error> {
+        grpc_read_status(value.status, value.issues)?;
+
+        let result_set = if let Some(proto_result_set) = value.result_set {
+            Some(RawResultSet::try_from(proto_result_set)?)
+        } else {
+            None
+        };
+
+        Ok(Self {
+            result_set_index: usize::try_from(value.result_set_index)?,
+            result_set,
+            tx_id: value.tx_meta.map(|meta| meta.id),
+            exec_stats: value.exec_stats.map(Into::into),
+        })
+    }
+}
+
+pub(crate) struct RawExecuteQueryStream {
+    stream: tonic::codec::Streaming<ExecuteQueryResponsePart>,
+}
+
+impl RawExecuteQueryStream {
+    pub(crate) fn new(stream: tonic::codec::Streaming<ExecuteQueryResponsePart>) -> Self {
+        Self { stream }
+    }
+}
+
+// parts read from network on demand only, so slow consumer does not buffer whole result
+impl Stream for RawExecuteQueryStream {
+    type Item = RawResult<RawExecuteQueryResponsePart>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.stream).poll_next(cx).map(|item| {
+            item.map(|res| {
+                res.map_err(RawError::from)
+                    .and_then(RawExecuteQueryResponsePart::try_from)
+            })
+        })
+    }
+}
@@ -0,0 +1,64 @@
+use crate::grpc_wrapper::grpc::create_operation_error;
+use crate::grpc_wrapper::raw_errors::RawError;
+use crate::grpc_wrapper::raw_table_service::value::RawTypedValue;
+use crate::grpc_wrapper::raw_ydb_operation::RawOperationParams;
+use prost::Message;
+use std::collections::HashMap;
+use ydb_grpc::ydb_proto::operations::Operation;
+use ydb_grpc::ydb_proto::query::{ExecMode, ExecuteScriptMetadata, QueryContent, Syntax};
+use ydb_grpc::ydb_proto::status_ids::StatusCode;
+
+#[derive(serde::Serialize)]
+pub(crate) struct RawExecuteScriptRequest {
+    pub operation_params: RawOperationParams,
+    pub yql_text: String,
+    pub params: HashMap<String, RawTypedValue>,
+}
+
+impl From<RawExecuteScriptRequest> for ydb_grpc::ydb_proto::query::ExecuteScriptRequest {
+    fn from(v: RawExecuteScriptRequest) -> Self {
+        Self {
+            operation_params: Some(v.operation_params.into()),
+            exec_mode: ExecMode::Execute as i32,
+            script_content: Some(QueryContent {
+                syntax: Syntax::YqlV1 as i32,
+                text: v.yql_text,
+            }),
+            parameters: v.params.into_iter().map(|(k, v)| (k, v.into())).collect(),
+            ..Self::default()
+        }
+    }
+}
+
+// State of script execution operation, returned by ExecuteScript and GetOperation calls
+pub(crate) struct RawScriptOperation {
+    pub id: String,
+    pub ready: bool,
+    pub execution_id: String,
+    pub exec_status: i32,
+    pub result_sets_count: usize,
+}
+
+impl TryFrom<Operation> for RawScriptOperation {
+    type Error = RawError;
+
+    fn try_from(value: Operation) -> Result<Self, Self::Error> {
+        if value.ready && value.status != StatusCode::Success as i32 {
+            return Err(create_operation_error(value));
+        }
+
+        let metadata = if let Some(metadata) = value.metadata {
+            ExecuteScriptMetadata::decode(metadata.value.as_slice())?
+        } else {
+            ExecuteScriptMetadata::default()
+        };
+
+        Ok(Self {
+            id: value.id,
+            ready: value.ready,
+            execution_id: metadata.execution_id,
+            exec_status: metadata.exec_status,
+            result_sets_count: metadata.result_sets_meta.len(),
+        })
+    }
+}
@@ -0,0 +1,49 @@
+use crate::grpc_wrapper::grpc::grpc_read_status;
+use crate::grpc_wrapper::raw_errors::RawError;
+use crate::grpc_wrapper::raw_table_service::value::RawResultSet;
+use ydb_grpc::ydb_proto::query::FetchScriptResultsResponse;
+
+#[derive(serde::Serialize)]
+pub(crate) struct RawFetchScriptResultsRequest {
+    pub operation_id: String,
+    pub result_set_index: i64,
+    pub fetch_token: String,
+    pub rows_limit: i64,
+}
+
+impl From<RawFetchScriptResultsRequest> for ydb_grpc::ydb_proto::query::FetchScriptResultsRequest {
+    fn from(v: RawFetchScriptResultsRequest) -> Self {
+        Self {
+            operation_id: v.operation_id,
+            result_set_index: v.result_set_index,
+            fetch_token: v.fetch_token,
+            rows_limit: v.rows_limit,
+        }
+    }
+}
+
+pub(crate) struct RawFetchScriptResultsResult {
+    pub result_set_index: usize,
+    pub result_set: Option<RawResultSet>,
+    pub next_fetch_token: String,
+}
+
+impl TryFrom<FetchScriptResultsResponse> for RawFetchScriptResultsResult {
+    type Error = RawError;
+
+    fn try_from(value: FetchScriptResultsResponse) -> Result<Self, Self::Error> {
+        grpc_read_status(value.status, value.issues)?;
+
+        let result_set = if let Some(proto_result_set) = value.result_set {
+            Some(RawResultSet::try_from(proto_result_set)?)
+        } else {
+            None
+        };
+
+        Ok(Self {
+            result_set_index: usize::try_from(value.result_set_index)?,
+            result_set,
+            next_fetch_token: value.next_fetch_token,
+        })
+    }
+}
@@ -0,0 +1,10 @@
+pub(crate) mod attach_session;
+pub(crate) mod begin_transaction;
+pub(crate) mod client;
+pub(crate) mod commit_transaction;
+pub(crate) mod create_session;
+pub(crate) mod execute_query;
+pub(crate) mod execute_script;
+pub(crate) mod fetch_script_results;
+pub(crate) mod rollback_transaction;
+pub(crate) mod transaction_control;
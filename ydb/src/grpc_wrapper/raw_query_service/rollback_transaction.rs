@@ -0,0 +1,15 @@
+pub(crate) struct RawRollbackTransactionRequest {
+    pub session_id: String,
+    pub tx_id: String,
+}
+
+impl From<RawRollbackTransactionRequest>
+    for ydb_grpc::ydb_proto::query::RollbackTransactionRequest
+{
+    fn from(value: RawRollbackTransactionRequest) -> Self {
+        Self {
+            session_id: value.session_id,
+            tx_id: value.tx_id,
+        }
+    }
+}
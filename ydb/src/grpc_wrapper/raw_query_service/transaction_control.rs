@@ -0,0 +1,51 @@
+// Query service use own protobuf messages for transaction control with same semantic as
+// table service, so raw types shared between services.
+use crate::grpc_wrapper::raw_table_service::transaction_control::{
+    RawOnlineReadonlySettings, RawTransactionControl, RawTxMode, RawTxSelector, RawTxSettings,
+};
+
+impl From<RawTransactionControl> for ydb_grpc::ydb_proto::query::TransactionControl {
+    fn from(v: RawTransactionControl) -> Self {
+        Self {
+            commit_tx: v.commit_tx,
+            tx_selector: Some(v.tx_selector.into()),
+        }
+    }
+}
+
+impl From<RawTxSelector> for ydb_grpc::ydb_proto::query::transaction_control::TxSelector {
+    fn from(v: RawTxSelector) -> Self {
+        use ydb_grpc::ydb_proto::query::transaction_control::TxSelector;
+        match v {
+            RawTxSelector::Id(id) => TxSelector::TxId(id),
+            RawTxSelector::Begin(tx_settings) => TxSelector::BeginTx(tx_settings.into()),
+        }
+    }
+}
+
+impl From<RawTxSettings> for ydb_grpc::ydb_proto::query::TransactionSettings {
+    fn from(v: RawTxSettings) -> Self {
+        Self {
+            tx_mode: Some(v.mode.into()),
+        }
+    }
+}
+
+impl From<RawTxMode> for ydb_grpc::ydb_proto::query::transaction_settings::TxMode {
+    fn from(v: RawTxMode) -> Self {
+        use ydb_grpc::ydb_proto::query;
+        use ydb_grpc::ydb_proto::query::transaction_settings::TxMode;
+        match v {
+            RawTxMode::SerializableReadWrite => {
+                TxMode::SerializableReadWrite(query::SerializableModeSettings {})
+            }
+            RawTxMode::OnlineReadOnly(RawOnlineReadonlySettings {
+                allow_inconsistent_reads,
+            }) => TxMode::OnlineReadOnly(query::OnlineModeSettings {
+                allow_inconsistent_reads,
+            }),
+            RawTxMode::StaleReadOnly => TxMode::StaleReadOnly(query::StaleModeSettings {}),
+            RawTxMode::SnapshotReadOnly => TxMode::SnapshotReadOnly(query::SnapshotModeSettings {}),
+        }
+    }
+}
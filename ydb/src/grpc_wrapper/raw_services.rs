@@ -26,6 +26,12 @@ pub(crate) enum Service {
     #[strum(serialize = "scripting")]
     Scripting,
 
+    #[strum(serialize = "operation_service")]
+    Operation,
+
+    #[strum(serialize = "query_service")]
+    Query,
+
     #[strum(serialize = "table_service")]
     Table,
 
@@ -0,0 +1,60 @@
+use crate::grpc_wrapper::raw_table_service::table_description::{
+    RawChangefeed, RawColumnFamily, RawColumnMeta, RawPartitioningSettings,
+    RawReadReplicasSettings, RawTableIndex, RawTtlSettings,
+};
+use crate::grpc_wrapper::raw_ydb_operation::RawOperationParams;
+use itertools::Itertools;
+use std::collections::HashMap;
+
+#[derive(serde::Serialize)]
+pub(crate) enum RawTtlAction {
+    Set(RawTtlSettings),
+    Drop,
+}
+
+#[derive(serde::Serialize)]
+pub(crate) struct RawAlterTableRequest {
+    pub session_id: String,
+    pub path: String,
+    pub operation_params: RawOperationParams,
+    pub add_columns: Vec<RawColumnMeta>,
+    pub drop_columns: Vec<String>,
+    pub add_indexes: Vec<RawTableIndex>,
+    pub drop_indexes: Vec<String>,
+    pub ttl_action: Option<RawTtlAction>,
+    pub add_column_families: Vec<RawColumnFamily>,
+    pub alter_column_families: Vec<RawColumnFamily>,
+    pub alter_attributes: HashMap<String, String>,
+    pub alter_partitioning_settings: Option<RawPartitioningSettings>,
+    pub set_read_replicas_settings: Option<RawReadReplicasSettings>,
+    pub add_changefeeds: Vec<RawChangefeed>,
+    pub drop_changefeeds: Vec<String>,
+}
+
+impl From<RawAlterTableRequest> for ydb_grpc::ydb_proto::table::AlterTableRequest {
+    fn from(value: RawAlterTableRequest) -> Self {
+        use ydb_grpc::ydb_proto::table::alter_table_request::TtlAction;
+
+        Self {
+            session_id: value.session_id,
+            path: value.path,
+            operation_params: Some(value.operation_params.into()),
+            add_columns: value.add_columns.into_iter().map_into().collect(),
+            drop_columns: value.drop_columns,
+            add_indexes: value.add_indexes.into_iter().map_into().collect(),
+            drop_indexes: value.drop_indexes,
+            ttl_action: value.ttl_action.map(|action| match action {
+                RawTtlAction::Set(settings) => TtlAction::SetTtlSettings(settings.into()),
+                RawTtlAction::Drop => TtlAction::DropTtlSettings(Default::default()),
+            }),
+            add_column_families: value.add_column_families.into_iter().map_into().collect(),
+            alter_column_families: value.alter_column_families.into_iter().map_into().collect(),
+            alter_attributes: value.alter_attributes,
+            alter_partitioning_settings: value.alter_partitioning_settings.map(Into::into),
+            set_read_replicas_settings: value.set_read_replicas_settings.map(Into::into),
+            add_changefeeds: value.add_changefeeds.into_iter().map_into().collect(),
+            drop_changefeeds: value.drop_changefeeds,
+            ..Self::default()
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use crate::grpc_wrapper::raw_errors::RawError;
+use crate::grpc_wrapper::raw_table_service::transaction_control::RawTxSettings;
+use crate::grpc_wrapper::raw_ydb_operation::RawOperationParams;
+
+pub(crate) struct RawBeginTransactionRequest {
+    pub session_id: String,
+    pub tx_settings: RawTxSettings,
+    pub operation_params: RawOperationParams,
+}
+
+impl From<RawBeginTransactionRequest> for ydb_grpc::ydb_proto::table::BeginTransactionRequest {
+    fn from(value: RawBeginTransactionRequest) -> Self {
+        Self {
+            session_id: value.session_id,
+            tx_settings: Some(value.tx_settings.into()),
+            operation_params: Some(value.operation_params.into()),
+        }
+    }
+}
+
+pub(crate) struct RawBeginTransactionResult {
+    pub tx_id: String,
+}
+
+impl TryFrom<ydb_grpc::ydb_proto::table::BeginTransactionResult> for RawBeginTransactionResult {
+    type Error = RawError;
+
+    fn try_from(
+        value: ydb_grpc::ydb_proto::table::BeginTransactionResult,
+    ) -> Result<Self, Self::Error> {
+        let tx_meta = value
+            .tx_meta
+            .ok_or_else(|| RawError::decode_error("no tx_meta in begin transaction result"))?;
+        Ok(Self { tx_id: tx_meta.id })
+    }
+}
@@ -1,20 +1,45 @@
 use crate::grpc_wrapper::raw_ydb_operation::RawOperationParams;
+use ydb_grpc::ydb_proto::formats::ArrowBatchSettings;
+use ydb_grpc::ydb_proto::table::bulk_upsert_request::DataFormat;
 use ydb_grpc::ydb_proto::table::BulkUpsertRequest;
 
 pub(crate) struct RawBulkUpsertRequest {
     pub table: String,
-    pub rows: ydb_grpc::ydb_proto::TypedValue,
+    pub data: RawBulkUpsertData,
     pub operation_params: RawOperationParams,
 }
 
+pub(crate) enum RawBulkUpsertData {
+    // list of structs
+    Rows(ydb_grpc::ydb_proto::TypedValue),
+
+    // serialized arrow schema and record batch messages
+    #[cfg_attr(not(feature = "arrow"), allow(dead_code))]
+    ArrowBatch {
+        schema: Vec<u8>,
+        data: Vec<u8>,
+    },
+}
+
 impl From<RawBulkUpsertRequest> for BulkUpsertRequest {
     fn from(value: RawBulkUpsertRequest) -> Self {
+        let (rows, data, data_format) = match value.data {
+            RawBulkUpsertData::Rows(rows) => (Some(rows), Vec::new(), None),
+            RawBulkUpsertData::ArrowBatch { schema, data } => (
+                None,
+                data,
+                Some(DataFormat::ArrowBatchSettings(ArrowBatchSettings {
+                    schema,
+                })),
+            ),
+        };
+
         Self {
             table: value.table,
-            rows: Some(value.rows),
+            rows,
             operation_params: Some(value.operation_params.into()),
-            data: Default::default(),
-            data_format: None,
+            data,
+            data_format,
         }
     }
 }
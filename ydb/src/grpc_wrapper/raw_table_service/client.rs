@@ -1,6 +1,10 @@
 use crate::client::TimeoutSettings;
 use crate::grpc_wrapper::raw_errors::RawResult;
 use crate::grpc_wrapper::raw_services::{GrpcServiceForDiscovery, Service};
+use crate::grpc_wrapper::raw_table_service::alter_table::RawAlterTableRequest;
+use crate::grpc_wrapper::raw_table_service::begin_transaction::{
+    RawBeginTransactionRequest, RawBeginTransactionResult,
+};
 use crate::grpc_wrapper::raw_table_service::bulk_upsert::RawBulkUpsertRequest;
 use crate::grpc_wrapper::raw_table_service::commit_transaction::{
     RawCommitTransactionRequest, RawCommitTransactionResult,
@@ -11,13 +15,29 @@ use crate::grpc_wrapper::raw_table_service::copy_table::{
 use crate::grpc_wrapper::raw_table_service::create_session::{
     RawCreateSessionRequest, RawCreateSessionResult,
 };
+use crate::grpc_wrapper::raw_table_service::create_table::RawCreateTableRequest;
+use crate::grpc_wrapper::raw_table_service::delete_session::RawDeleteSessionRequest;
+use crate::grpc_wrapper::raw_table_service::describe_table::{
+    RawDescribeTableRequest, RawDescribeTableResult,
+};
+use crate::grpc_wrapper::raw_table_service::drop_table::RawDropTableRequest;
 use crate::grpc_wrapper::raw_table_service::execute_data_query::{
     RawExecuteDataQueryRequest, RawExecuteDataQueryResult,
 };
 use crate::grpc_wrapper::raw_table_service::execute_scheme_query::RawExecuteSchemeQueryRequest;
+use crate::grpc_wrapper::raw_table_service::explain_data_query::{
+    RawExplainDataQueryRequest, RawExplainDataQueryResult,
+};
 use crate::grpc_wrapper::raw_table_service::keepalive::{RawKeepAliveRequest, RawKeepAliveResult};
+use crate::grpc_wrapper::raw_table_service::prepare_data_query::{
+    RawPrepareDataQueryRequest, RawPrepareDataQueryResult,
+};
+use crate::grpc_wrapper::raw_table_service::read_rows::{RawReadRowsRequest, RawReadRowsResult};
+use crate::grpc_wrapper::raw_table_service::read_table::{RawReadTableRequest, RawReadTableStream};
+use crate::grpc_wrapper::raw_table_service::rename_tables::RawRenameTablesRequest;
 use crate::grpc_wrapper::raw_table_service::rollback_transaction::RawRollbackTransactionRequest;
 use crate::grpc_wrapper::runtime_interceptors::InterceptedChannel;
+use crate::trace_helpers::ensure_len_string;
 use tracing::trace;
 use ydb_grpc::ydb_proto::table::v1::table_service_client::TableServiceClient;
 
@@ -39,6 +59,17 @@ impl RawTableClient {
         self
     }
 
+    pub async fn begin_transaction(
+        &mut self,
+        req: RawBeginTransactionRequest,
+    ) -> RawResult<RawBeginTransactionResult> {
+        request_with_result!(
+            self.service.begin_transaction,
+            req => ydb_grpc::ydb_proto::table::BeginTransactionRequest,
+            ydb_grpc::ydb_proto::table::BeginTransactionResult => RawBeginTransactionResult
+        );
+    }
+
     pub async fn commit_transaction(
         &mut self,
         req: RawCommitTransactionRequest,
@@ -62,6 +93,13 @@ impl RawTableClient {
         );
     }
 
+    pub async fn delete_session(&mut self, req: RawDeleteSessionRequest) -> RawResult<()> {
+        request_without_result!(
+            self.service.delete_session,
+            req => ydb_grpc::ydb_proto::table::DeleteSessionRequest
+        );
+    }
+
     pub async fn execute_data_query(
         &mut self,
         req: RawExecuteDataQueryRequest,
@@ -83,6 +121,17 @@ impl RawTableClient {
         );
     }
 
+    pub async fn explain_data_query(
+        &mut self,
+        req: RawExplainDataQueryRequest,
+    ) -> RawResult<RawExplainDataQueryResult> {
+        request_with_result!(
+            self.service.explain_data_query,
+            req => ydb_grpc::ydb_proto::table::ExplainDataQueryRequest,
+            ydb_grpc::ydb_proto::table::ExplainQueryResult => RawExplainDataQueryResult
+        );
+    }
+
     pub async fn keep_alive(&mut self, req: RawKeepAliveRequest) -> RawResult<RawKeepAliveResult> {
         request_with_result!(
             self.service.keep_alive,
@@ -91,6 +140,36 @@ impl RawTableClient {
         );
     }
 
+    pub async fn prepare_data_query(
+        &mut self,
+        req: RawPrepareDataQueryRequest,
+    ) -> RawResult<RawPrepareDataQueryResult> {
+        request_with_result!(
+            self.service.prepare_data_query,
+            req => ydb_grpc::ydb_proto::table::PrepareDataQueryRequest,
+            ydb_grpc::ydb_proto::table::PrepareQueryResult => RawPrepareDataQueryResult
+        );
+    }
+
+    pub async fn read_rows(&mut self, req: RawReadRowsRequest) -> RawResult<RawReadRowsResult> {
+        request_with_status!(
+            self.service.read_rows,
+            req => ydb_grpc::ydb_proto::table::ReadRowsRequest,
+            ydb_grpc::ydb_proto::table::ReadRowsResponse => RawReadRowsResult
+        );
+    }
+
+    pub async fn read_table(&mut self, req: RawReadTableRequest) -> RawResult<RawReadTableStream> {
+        let req = ydb_grpc::ydb_proto::table::ReadTableRequest::from(req);
+        trace!(
+            "read_table request: {}",
+            ensure_len_string(serde_json::to_string(&req).unwrap_or("bad json".into()))
+        );
+
+        let stream = self.service.stream_read_table(req).await?.into_inner();
+        Ok(RawReadTableStream::new(stream))
+    }
+
     pub async fn rollback_transaction(
         &mut self,
         req: RawRollbackTransactionRequest,
@@ -115,6 +194,45 @@ impl RawTableClient {
         );
     }
 
+    pub async fn create_table(&mut self, req: RawCreateTableRequest) -> RawResult<()> {
+        request_without_result!(
+            self.service.create_table,
+            req => ydb_grpc::ydb_proto::table::CreateTableRequest
+        );
+    }
+
+    pub async fn alter_table(&mut self, req: RawAlterTableRequest) -> RawResult<()> {
+        request_without_result!(
+            self.service.alter_table,
+            req => ydb_grpc::ydb_proto::table::AlterTableRequest
+        );
+    }
+
+    pub async fn describe_table(
+        &mut self,
+        req: RawDescribeTableRequest,
+    ) -> RawResult<RawDescribeTableResult> {
+        request_with_result!(
+            self.service.describe_table,
+            req => ydb_grpc::ydb_proto::table::DescribeTableRequest,
+            ydb_grpc::ydb_proto::table::DescribeTableResult => RawDescribeTableResult
+        );
+    }
+
+    pub async fn drop_table(&mut self, req: RawDropTableRequest) -> RawResult<()> {
+        request_without_result!(
+            self.service.drop_table,
+            req => ydb_grpc::ydb_proto::table::DropTableRequest
+        );
+    }
+
+    pub async fn rename_tables(&mut self, req: RawRenameTablesRequest) -> RawResult<()> {
+        request_without_result!(
+            self.service.rename_tables,
+            req => ydb_grpc::ydb_proto::table::RenameTablesRequest
+        );
+    }
+
     pub async fn bulk_upsert(&mut self, req: RawBulkUpsertRequest) -> RawResult<()> {
         request_without_result!(
             self.service.bulk_upsert,
@@ -179,24 +297,3 @@ impl From<CollectStatsMode> for i32 {
         grpc_val as i32
     }
 }
-
-#[derive(Debug)]
-pub(crate) struct RawQueryStats {
-    process_cpu_time: std::time::Duration,
-    query_plan: String,
-    query_ast: String,
-    total_duration: std::time::Duration,
-    total_cpu_time: std::time::Duration,
-}
-
-impl From<ydb_grpc::ydb_proto::table_stats::QueryStats> for RawQueryStats {
-    fn from(value: ydb_grpc::ydb_proto::table_stats::QueryStats) -> Self {
-        Self {
-            process_cpu_time: std::time::Duration::from_micros(value.process_cpu_time_us),
-            query_plan: value.query_plan,
-            query_ast: value.query_ast,
-            total_duration: std::time::Duration::from_micros(value.total_duration_us),
-            total_cpu_time: std::time::Duration::from_micros(value.total_cpu_time_us),
-        }
-    }
-}
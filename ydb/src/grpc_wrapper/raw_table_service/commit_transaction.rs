@@ -1,5 +1,6 @@
 use crate::grpc_wrapper::raw_errors::RawError;
-use crate::grpc_wrapper::raw_table_service::client::{CollectStatsMode, RawQueryStats};
+use crate::grpc_wrapper::raw_table_service::client::CollectStatsMode;
+use crate::grpc_wrapper::raw_table_service::query_stats::RawQueryStats;
 use crate::grpc_wrapper::raw_ydb_operation::RawOperationParams;
 
 pub(crate) struct RawCommitTransactionRequest {
@@ -0,0 +1,45 @@
+use crate::grpc_wrapper::raw_table_service::table_description::{
+    RawColumnFamily, RawColumnMeta, RawPartitioningSettings, RawReadReplicasSettings,
+    RawTableIndex, RawTtlSettings,
+};
+use crate::grpc_wrapper::raw_ydb_operation::RawOperationParams;
+use itertools::Itertools;
+use std::collections::HashMap;
+
+#[derive(serde::Serialize)]
+pub(crate) struct RawCreateTableRequest {
+    pub session_id: String,
+    pub path: String,
+    pub operation_params: RawOperationParams,
+    pub columns: Vec<RawColumnMeta>,
+    pub primary_key: Vec<String>,
+    pub indexes: Vec<RawTableIndex>,
+    pub ttl_settings: Option<RawTtlSettings>,
+    pub column_families: Vec<RawColumnFamily>,
+    pub attributes: HashMap<String, String>,
+    pub partitioning_settings: Option<RawPartitioningSettings>,
+    pub uniform_partitions: Option<u64>,
+    pub read_replicas_settings: Option<RawReadReplicasSettings>,
+}
+
+impl From<RawCreateTableRequest> for ydb_grpc::ydb_proto::table::CreateTableRequest {
+    fn from(value: RawCreateTableRequest) -> Self {
+        use ydb_grpc::ydb_proto::table::create_table_request::Partitions;
+
+        Self {
+            session_id: value.session_id,
+            path: value.path,
+            operation_params: Some(value.operation_params.into()),
+            columns: value.columns.into_iter().map_into().collect(),
+            primary_key: value.primary_key,
+            indexes: value.indexes.into_iter().map_into().collect(),
+            ttl_settings: value.ttl_settings.map(Into::into),
+            column_families: value.column_families.into_iter().map_into().collect(),
+            attributes: value.attributes,
+            partitioning_settings: value.partitioning_settings.map(Into::into),
+            read_replicas_settings: value.read_replicas_settings.map(Into::into),
+            partitions: value.uniform_partitions.map(Partitions::UniformPartitions),
+            ..Self::default()
+        }
+    }
+}
@@ -0,0 +1,15 @@
+use crate::grpc_wrapper::raw_ydb_operation::RawOperationParams;
+
+pub(crate) struct RawDeleteSessionRequest {
+    pub session_id: String,
+    pub operation_params: RawOperationParams,
+}
+
+impl From<RawDeleteSessionRequest> for ydb_grpc::ydb_proto::table::DeleteSessionRequest {
+    fn from(value: RawDeleteSessionRequest) -> Self {
+        Self {
+            session_id: value.session_id,
+            operation_params: Some(value.operation_params.into()),
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use crate::grpc_wrapper::raw_errors::RawError;
+use crate::grpc_wrapper::raw_table_service::table_description::{
+    RawChangefeed, RawColumnFamily, RawColumnMeta, RawPartitioningSettings,
+    RawReadReplicasSettings, RawTableIndex, RawTableStats, RawTtlSettings,
+};
+use crate::grpc_wrapper::raw_table_service::value::RawTypedValue;
+use crate::grpc_wrapper::raw_ydb_operation::RawOperationParams;
+use itertools::Itertools;
+use std::collections::HashMap;
+
+pub(crate) struct RawDescribeTableRequest {
+    pub session_id: String,
+    pub path: String,
+    pub operation_params: RawOperationParams,
+    pub include_shard_key_bounds: bool,
+    pub include_table_stats: bool,
+    pub include_partition_stats: bool,
+}
+
+impl From<RawDescribeTableRequest> for ydb_grpc::ydb_proto::table::DescribeTableRequest {
+    fn from(value: RawDescribeTableRequest) -> Self {
+        Self {
+            session_id: value.session_id,
+            path: value.path,
+            operation_params: Some(value.operation_params.into()),
+            include_shard_key_bounds: value.include_shard_key_bounds,
+            include_table_stats: value.include_table_stats,
+            include_partition_stats: value.include_partition_stats,
+            include_shard_nodes_info: false,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct RawDescribeTableResult {
+    pub columns: Vec<RawColumnMeta>,
+    pub primary_key: Vec<String>,
+    pub shard_key_bounds: Vec<RawTypedValue>,
+    pub indexes: Vec<RawTableIndex>,
+    pub table_stats: Option<RawTableStats>,
+    pub ttl_settings: Option<RawTtlSettings>,
+    pub column_families: Vec<RawColumnFamily>,
+    pub attributes: HashMap<String, String>,
+    pub partitioning_settings: Option<RawPartitioningSettings>,
+    pub read_replicas_settings: Option<RawReadReplicasSettings>,
+    pub changefeeds: Vec<RawChangefeed>,
+}
+
+impl TryFrom<ydb_grpc::ydb_proto::table::DescribeTableResult> for RawDescribeTableResult {
+    type Error = RawError;
+
+    fn try_from(
+        value: ydb_grpc::ydb_proto::table::DescribeTableResult,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            columns: value
+                .columns
+                .into_iter()
+                .map(RawColumnMeta::try_from)
+                .try_collect()?,
+            primary_key: value.primary_key,
+            shard_key_bounds: value
+                .shard_key_bounds
+                .into_iter()
+                .map(RawTypedValue::try_from)
+                .try_collect()?,
+            indexes: value.indexes.into_iter().map_into().collect(),
+            table_stats: value.table_stats.map(Into::into),
+            ttl_settings: value
+                .ttl_settings
+                .map(RawTtlSettings::try_from)
+                .transpose()?,
+            column_families: value.column_families.into_iter().map_into().collect(),
+            attributes: value.attributes,
+            partitioning_settings: value.partitioning_settings.map(Into::into),
+            read_replicas_settings: value
+                .read_replicas_settings
+                .and_then(|settings| settings.settings)
+                .map(Into::into),
+            changefeeds: value.changefeeds.into_iter().map_into().collect(),
+        })
+    }
+}
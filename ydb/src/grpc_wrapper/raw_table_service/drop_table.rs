@@ -0,0 +1,17 @@
+use crate::grpc_wrapper::raw_ydb_operation::RawOperationParams;
+
+pub(crate) struct RawDropTableRequest {
+    pub session_id: String,
+    pub path: String,
+    pub operation_params: RawOperationParams,
+}
+
+impl From<RawDropTableRequest> for ydb_grpc::ydb_proto::table::DropTableRequest {
+    fn from(value: RawDropTableRequest) -> Self {
+        Self {
+            session_id: value.session_id,
+            path: value.path,
+            operation_params: Some(value.operation_params.into()),
+        }
+    }
+}
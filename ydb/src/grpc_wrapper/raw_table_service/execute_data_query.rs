@@ -1,5 +1,5 @@
 use crate::grpc_wrapper::raw_errors::RawError;
-use crate::grpc_wrapper::raw_table_service::query_stats::RawQueryStatMode;
+use crate::grpc_wrapper::raw_table_service::query_stats::{RawQueryStatMode, RawQueryStats};
 use crate::grpc_wrapper::raw_table_service::transaction_control::RawTransactionControl;
 use crate::grpc_wrapper::raw_table_service::value::r#type::RawType;
 use crate::grpc_wrapper::raw_table_service::value::{RawResultSet, RawTypedValue};
@@ -10,7 +10,7 @@ use std::collections::HashMap;
 pub(crate) struct RawExecuteDataQueryRequest {
     pub session_id: String,
     pub tx_control: RawTransactionControl,
-    pub yql_text: String,
+    pub query: RawDataQuery,
     pub operation_params: RawOperationParams,
     pub params: HashMap<String, RawTypedValue>,
     pub keep_in_cache: bool,
@@ -22,11 +22,7 @@ impl From<RawExecuteDataQueryRequest> for ydb_grpc::ydb_proto::table::ExecuteDat
         Self {
             session_id: v.session_id,
             tx_control: Some(v.tx_control.into()),
-            query: Some(ydb_grpc::ydb_proto::table::Query {
-                query: Some(ydb_grpc::ydb_proto::table::query::Query::YqlText(
-                    v.yql_text,
-                )),
-            }),
+            query: Some(v.query.into()),
             parameters: v.params.into_iter().map(|(k, v)| (k, v.into())).collect(),
             query_cache_policy: Some(ydb_grpc::ydb_proto::table::QueryCachePolicy {
                 keep_in_cache: v.keep_in_cache,
@@ -39,12 +35,30 @@ impl From<RawExecuteDataQueryRequest> for ydb_grpc::ydb_proto::table::ExecuteDat
     }
 }
 
+#[derive(serde::Serialize)]
+pub(crate) enum RawDataQuery {
+    YqlText(String),
+    Id(String),
+}
+
+impl From<RawDataQuery> for ydb_grpc::ydb_proto::table::Query {
+    fn from(value: RawDataQuery) -> Self {
+        use ydb_grpc::ydb_proto::table::query::Query;
+
+        let query = match value {
+            RawDataQuery::YqlText(text) => Query::YqlText(text),
+            RawDataQuery::Id(id) => Query::Id(id),
+        };
+        Self { query: Some(query) }
+    }
+}
+
 #[derive(serde::Serialize)]
 pub(crate) struct RawExecuteDataQueryResult {
     pub result_sets: Vec<RawResultSet>,
     pub tx_meta: RawTransactionMeta,
     pub query_meta: Option<RawQueryMeta>,
-    // query_stats: Option<RawQueryStats>, // todo
+    pub query_stats: Option<RawQueryStats>,
 }
 
 impl TryFrom<ydb_grpc::ydb_proto::table::ExecuteQueryResult> for RawExecuteDataQueryResult {
@@ -71,6 +85,7 @@ impl TryFrom<ydb_grpc::ydb_proto::table::ExecuteQueryResult> for RawExecuteDataQ
                 .ok_or_else(|| RawError::custom("no tx_meta at ExecuteQueryResult"))?
                 .into(),
             query_meta,
+            query_stats: value.query_stats.map(Into::into),
         })
     }
 }
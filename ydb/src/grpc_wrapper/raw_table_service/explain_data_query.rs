@@ -0,0 +1,38 @@
+use crate::grpc_wrapper::raw_errors::RawError;
+use crate::grpc_wrapper::raw_ydb_operation::RawOperationParams;
+
+pub(crate) struct RawExplainDataQueryRequest {
+    pub session_id: String,
+    pub yql_text: String,
+    pub operation_params: RawOperationParams,
+}
+
+impl From<RawExplainDataQueryRequest> for ydb_grpc::ydb_proto::table::ExplainDataQueryRequest {
+    fn from(value: RawExplainDataQueryRequest) -> Self {
+        Self {
+            session_id: value.session_id,
+            yql_text: value.yql_text,
+            operation_params: Some(value.operation_params.into()),
+            collect_full_diagnostics: false,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct RawExplainDataQueryResult {
+    pub query_ast: String,
+    pub query_plan: String,
+}
+
+impl TryFrom<ydb_grpc::ydb_proto::table::ExplainQueryResult> for RawExplainDataQueryResult {
+    type Error = RawError;
+
+    fn try_from(
+        value: ydb_grpc::ydb_proto::table::ExplainQueryResult,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            query_ast: value.query_ast,
+            query_plan: value.query_plan,
+        })
+    }
+}
@@ -1,12 +1,24 @@
+pub(crate) mod alter_table;
+pub(crate) mod begin_transaction;
 pub(crate) mod bulk_upsert;
 pub(crate) mod client;
 pub(crate) mod commit_transaction;
 pub(crate) mod copy_table;
 pub(crate) mod create_session;
+pub(crate) mod create_table;
+pub(crate) mod delete_session;
+pub(crate) mod describe_table;
+pub(crate) mod drop_table;
 pub(crate) mod execute_data_query;
 pub(crate) mod execute_scheme_query;
+pub(crate) mod explain_data_query;
 pub(crate) mod keepalive;
+pub(crate) mod prepare_data_query;
 pub(crate) mod query_stats;
+pub(crate) mod read_rows;
+pub(crate) mod read_table;
+pub(crate) mod rename_tables;
 pub(crate) mod rollback_transaction;
+pub(crate) mod table_description;
 pub(crate) mod transaction_control;
 pub(crate) mod value;
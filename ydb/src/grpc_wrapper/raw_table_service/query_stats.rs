@@ -1,4 +1,6 @@
-#[derive(serde::Serialize)]
+use std::time::Duration;
+
+#[derive(Clone, Copy, serde::Serialize)]
 pub(crate) enum RawQueryStatMode {
     None,
     Basic,
@@ -17,3 +19,102 @@ impl From<RawQueryStatMode> for ydb_grpc::ydb_proto::table::query_stats_collecti
         }
     }
 }
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct RawQueryStats {
+    pub query_phases: Vec<RawQueryPhaseStats>,
+    pub compilation: Option<RawCompilationStats>,
+    pub process_cpu_time: Duration,
+    pub query_plan: String,
+    pub query_ast: String,
+    pub total_duration: Duration,
+    pub total_cpu_time: Duration,
+}
+
+impl From<ydb_grpc::ydb_proto::table_stats::QueryStats> for RawQueryStats {
+    fn from(value: ydb_grpc::ydb_proto::table_stats::QueryStats) -> Self {
+        Self {
+            query_phases: value.query_phases.into_iter().map(Into::into).collect(),
+            compilation: value.compilation.map(Into::into),
+            process_cpu_time: Duration::from_micros(value.process_cpu_time_us),
+            query_plan: value.query_plan,
+            query_ast: value.query_ast,
+            total_duration: Duration::from_micros(value.total_duration_us),
+            total_cpu_time: Duration::from_micros(value.total_cpu_time_us),
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct RawQueryPhaseStats {
+    pub duration: Duration,
+    pub table_access: Vec<RawTableAccessStats>,
+    pub cpu_time: Duration,
+    pub affected_shards: u64,
+    pub literal_phase: bool,
+}
+
+impl From<ydb_grpc::ydb_proto::table_stats::QueryPhaseStats> for RawQueryPhaseStats {
+    fn from(value: ydb_grpc::ydb_proto::table_stats::QueryPhaseStats) -> Self {
+        Self {
+            duration: Duration::from_micros(value.duration_us),
+            table_access: value.table_access.into_iter().map(Into::into).collect(),
+            cpu_time: Duration::from_micros(value.cpu_time_us),
+            affected_shards: value.affected_shards,
+            literal_phase: value.literal_phase,
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct RawTableAccessStats {
+    pub name: String,
+    pub reads: RawOperationStats,
+    pub updates: RawOperationStats,
+    pub deletes: RawOperationStats,
+    pub partitions_count: u64,
+}
+
+impl From<ydb_grpc::ydb_proto::table_stats::TableAccessStats> for RawTableAccessStats {
+    fn from(value: ydb_grpc::ydb_proto::table_stats::TableAccessStats) -> Self {
+        Self {
+            name: value.name,
+            reads: value.reads.unwrap_or_default().into(),
+            updates: value.updates.unwrap_or_default().into(),
+            deletes: value.deletes.unwrap_or_default().into(),
+            partitions_count: value.partitions_count,
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct RawOperationStats {
+    pub rows: u64,
+    pub bytes: u64,
+}
+
+impl From<ydb_grpc::ydb_proto::table_stats::OperationStats> for RawOperationStats {
+    fn from(value: ydb_grpc::ydb_proto::table_stats::OperationStats) -> Self {
+        Self {
+            rows: value.rows,
+            bytes: value.bytes,
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct RawCompilationStats {
+    pub from_cache: bool,
+    pub duration: Duration,
+    pub cpu_time: Duration,
+}
+
+impl From<ydb_grpc::ydb_proto::table_stats::CompilationStats> for RawCompilationStats {
+    fn from(value: ydb_grpc::ydb_proto::table_stats::CompilationStats) -> Self {
+        Self {
+            from_cache: value.from_cache,
+            duration: Duration::from_micros(value.duration_us),
+            cpu_time: Duration::from_micros(value.cpu_time_us),
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use crate::grpc_wrapper::grpc::grpc_read_status;
+use crate::grpc_wrapper::raw_errors::RawError;
+use crate::grpc_wrapper::raw_table_service::value::{RawResultSet, RawTypedValue};
+use ydb_grpc::ydb_proto::table::{ReadRowsRequest, ReadRowsResponse};
+
+pub(crate) struct RawReadRowsRequest {
+    pub session_id: String,
+    pub path: String,
+    pub keys: RawTypedValue,
+    pub columns: Vec<String>,
+}
+
+impl From<RawReadRowsRequest> for ReadRowsRequest {
+    fn from(value: RawReadRowsRequest) -> Self {
+        Self {
+            session_id: value.session_id,
+            path: value.path,
+            keys: Some(value.keys.into()),
+            columns: value.columns,
+        }
+    }
+}
+
+pub(crate) struct RawReadRowsResult {
+    pub result_set: RawResultSet,
+}
+
+impl TryFrom<ReadRowsResponse> for RawReadRowsResult {
+    type Error = RawError;
+
+    fn try_from(value: ReadRowsResponse) -> Result<Self, Self::Error> {
+        grpc_read_status(value.status, value.issues)?;
+        let result_set = value
+            .result_set
+            .ok_or_else(|| RawError::custom("no result set at ReadRowsResponse"))?;
+        Ok(Self {
+            result_set: RawResultSet::try_from(result_set)?,
+        })
+    }
+}
@@ -0,0 +1,109 @@
+use crate::grpc_wrapper::grpc::grpc_read_status;
+use crate::grpc_wrapper::raw_errors::{RawError, RawResult};
+use crate::grpc_wrapper::raw_table_service::table_description::feature_flag_to_proto;
+use crate::grpc_wrapper::raw_table_service::value::{RawResultSet, RawTypedValue};
+use futures_util::Stream;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use ydb_grpc::ydb_proto::table::{key_range, ReadTableRequest, ReadTableResponse};
+
+#[derive(Clone, Debug)]
+pub(crate) struct RawKeyBound {
+    pub value: RawTypedValue,
+    pub inclusive: bool,
+}
+
+#[derive(Clone, Debug, Default)]
+pub(crate) struct RawKeyRange {
+    pub from: Option<RawKeyBound>,
+    pub to: Option<RawKeyBound>,
+}
+
+impl From<RawKeyRange> for ydb_grpc::ydb_proto::table::KeyRange {
+    fn from(value: RawKeyRange) -> Self {
+        Self {
+            from_bound: value.from.map(|bound| {
+                if bound.inclusive {
+                    key_range::FromBound::GreaterOrEqual(bound.value.into())
+                } else {
+                    key_range::FromBound::Greater(bound.value.into())
+                }
+            }),
+            to_bound: value.to.map(|bound| {
+                if bound.inclusive {
+                    key_range::ToBound::LessOrEqual(bound.value.into())
+                } else {
+                    key_range::ToBound::Less(bound.value.into())
+                }
+            }),
+        }
+    }
+}
+
+pub(crate) struct RawReadTableRequest {
+    pub session_id: String,
+    pub path: String,
+    pub key_range: Option<RawKeyRange>,
+    pub columns: Vec<String>,
+    pub ordered: bool,
+    pub row_limit: u64,
+    pub use_snapshot: Option<bool>,
+}
+
+impl From<RawReadTableRequest> for ReadTableRequest {
+    fn from(value: RawReadTableRequest) -> Self {
+        Self {
+            session_id: value.session_id,
+            path: value.path,
+            key_range: value.key_range.map(Into::into),
+            columns: value.columns,
+            ordered: value.ordered,
+            row_limit: value.row_limit,
+            use_snapshot: feature_flag_to_proto(value.use_snapshot),
+            ..Self::default()
+        }
+    }
+}
+
+pub(crate) struct RawReadTablePart {
+    pub result_set: Option<RawResultSet>,
+}
+
+impl TryFrom<ReadTableResponse> for RawReadTablePart {
+    type Error = RawError;
+
+    fn try_from(value: ReadTableResponse) -> Result<Self, Self::Error> {
+        grpc_read_status(value.status, value.issues)?;
+
+        let result_set =
+            if let Some(proto_result_set) = value.result.and_then(|result| result.result_set) {
+                Some(RawResultSet::try_from(proto_result_set)?)
+            } else {
+                None
+            };
+        Ok(Self { result_set })
+    }
+}
+
+pub(crate) struct RawReadTableStream {
+    stream: tonic::codec::Streaming<ReadTableResponse>,
+}
+
+impl RawReadTableStream {
+    pub(crate) fn new(stream: tonic::codec::Streaming<ReadTableResponse>) -> Self {
+        Self { stream }
+    }
+}
+
+impl Stream for RawReadTableStream {
+    type Item = RawResult<RawReadTablePart>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.stream).poll_next(cx).map(|item| {
+            item.map(|res| {
+                res.map_err(RawError::from)
+                    .and_then(RawReadTablePart::try_from)
+            })
+        })
+    }
+}
@@ -0,0 +1,525 @@
+use crate::grpc_wrapper::raw_common_types::{Duration, Timestamp};
+use crate::grpc_wrapper::raw_errors::RawError;
+use crate::grpc_wrapper::raw_table_service::value::r#type::RawType;
+use std::collections::HashMap;
+use ydb_grpc::ydb_proto::feature_flag;
+use ydb_grpc::ydb_proto::table;
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub(crate) struct RawColumnMeta {
+    pub name: String,
+    pub column_type: RawType,
+    pub family: String,
+    pub not_null: Option<bool>,
+}
+
+impl From<RawColumnMeta> for table::ColumnMeta {
+    fn from(value: RawColumnMeta) -> Self {
+        Self {
+            name: value.name,
+            r#type: Some(value.column_type.into()),
+            family: value.family,
+            not_null: value.not_null,
+            default_value: None,
+        }
+    }
+}
+
+impl TryFrom<table::ColumnMeta> for RawColumnMeta {
+    type Error = RawError;
+
+    fn try_from(value: table::ColumnMeta) -> Result<Self, Self::Error> {
+        let column_type = if let Some(t) = value.r#type {
+            RawType::try_from(t)?
+        } else {
+            return Err(RawError::decode_error(format!(
+                "empty type of column: {}",
+                value.name
+            )));
+        };
+
+        Ok(Self {
+            name: value.name,
+            column_type,
+            family: value.family,
+            not_null: value.not_null,
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, serde::Serialize)]
+pub(crate) enum RawIndexType {
+    Global,
+    GlobalAsync,
+    GlobalUnique,
+
+    // index without type or with type, unknown for the SDK
+    Unknown,
+}
+
+impl From<RawIndexType> for Option<table::table_index::Type> {
+    fn from(value: RawIndexType) -> Self {
+        use table::table_index::Type;
+
+        match value {
+            RawIndexType::Global => Some(Type::GlobalIndex(table::GlobalIndex {})),
+            RawIndexType::GlobalAsync => Some(Type::GlobalAsyncIndex(table::GlobalAsyncIndex {})),
+            RawIndexType::GlobalUnique => {
+                Some(Type::GlobalUniqueIndex(table::GlobalUniqueIndex {}))
+            }
+            RawIndexType::Unknown => None,
+        }
+    }
+}
+
+impl From<table::table_index_description::Type> for RawIndexType {
+    fn from(value: table::table_index_description::Type) -> Self {
+        use table::table_index_description::Type;
+
+        match value {
+            Type::GlobalIndex(_) => RawIndexType::Global,
+            Type::GlobalAsyncIndex(_) => RawIndexType::GlobalAsync,
+            Type::GlobalUniqueIndex(_) => RawIndexType::GlobalUnique,
+        }
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub(crate) struct RawTableIndex {
+    pub name: String,
+    pub index_columns: Vec<String>,
+    pub data_columns: Vec<String>,
+    pub index_type: RawIndexType,
+}
+
+impl From<RawTableIndex> for table::TableIndex {
+    fn from(value: RawTableIndex) -> Self {
+        Self {
+            name: value.name,
+            index_columns: value.index_columns,
+            data_columns: value.data_columns,
+            r#type: value.index_type.into(),
+        }
+    }
+}
+
+impl From<table::TableIndexDescription> for RawTableIndex {
+    fn from(value: table::TableIndexDescription) -> Self {
+        Self {
+            name: value.name,
+            index_columns: value.index_columns,
+            data_columns: value.data_columns,
+            index_type: value
+                .r#type
+                .map(RawIndexType::from)
+                .unwrap_or(RawIndexType::Unknown),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, serde::Serialize)]
+pub(crate) enum RawTtlUnit {
+    Seconds,
+    Milliseconds,
+    Microseconds,
+    Nanoseconds,
+}
+
+impl From<RawTtlUnit> for table::value_since_unix_epoch_mode_settings::Unit {
+    fn from(value: RawTtlUnit) -> Self {
+        match value {
+            RawTtlUnit::Seconds => Self::Seconds,
+            RawTtlUnit::Milliseconds => Self::Milliseconds,
+            RawTtlUnit::Microseconds => Self::Microseconds,
+            RawTtlUnit::Nanoseconds => Self::Nanoseconds,
+        }
+    }
+}
+
+impl TryFrom<i32> for RawTtlUnit {
+    type Error = RawError;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        use table::value_since_unix_epoch_mode_settings::Unit;
+
+        match Unit::try_from(value) {
+            Ok(Unit::Seconds) => Ok(RawTtlUnit::Seconds),
+            Ok(Unit::Milliseconds) => Ok(RawTtlUnit::Milliseconds),
+            Ok(Unit::Microseconds) => Ok(RawTtlUnit::Microseconds),
+            Ok(Unit::Nanoseconds) => Ok(RawTtlUnit::Nanoseconds),
+            _ => Err(RawError::decode_error(format!(
+                "unexpected ttl column unit: {value}"
+            ))),
+        }
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub(crate) struct RawTtlSettings {
+    pub column_name: String,
+    pub expire_after_seconds: u32,
+
+    // None for column with date type
+    pub column_unit: Option<RawTtlUnit>,
+    pub run_interval_seconds: u32,
+}
+
+impl From<RawTtlSettings> for table::TtlSettings {
+    fn from(value: RawTtlSettings) -> Self {
+        use table::ttl_settings::Mode;
+
+        let mode = if let Some(unit) = value.column_unit {
+            Mode::ValueSinceUnixEpoch(table::ValueSinceUnixEpochModeSettings {
+                column_name: value.column_name,
+                column_unit: table::value_since_unix_epoch_mode_settings::Unit::from(unit) as i32,
+                expire_after_seconds: value.expire_after_seconds,
+            })
+        } else {
+            Mode::DateTypeColumn(table::DateTypeColumnModeSettings {
+                column_name: value.column_name,
+                expire_after_seconds: value.expire_after_seconds,
+            })
+        };
+
+        Self {
+            run_interval_seconds: value.run_interval_seconds,
+            mode: Some(mode),
+        }
+    }
+}
+
+impl TryFrom<table::TtlSettings> for RawTtlSettings {
+    type Error = RawError;
+
+    fn try_from(value: table::TtlSettings) -> Result<Self, Self::Error> {
+        use table::ttl_settings::Mode;
+
+        let (column_name, expire_after_seconds, column_unit) = match value.mode {
+            Some(Mode::DateTypeColumn(settings)) => {
+                (settings.column_name, settings.expire_after_seconds, None)
+            }
+            Some(Mode::ValueSinceUnixEpoch(settings)) => (
+                settings.column_name,
+                settings.expire_after_seconds,
+                Some(RawTtlUnit::try_from(settings.column_unit)?),
+            ),
+            None => return Err(RawError::decode_error("empty ttl mode")),
+        };
+
+        Ok(Self {
+            column_name,
+            expire_after_seconds,
+            column_unit,
+            run_interval_seconds: value.run_interval_seconds,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Default, serde::Serialize)]
+pub(crate) struct RawPartitioningSettings {
+    pub partition_by: Vec<String>,
+    pub partitioning_by_size: Option<bool>,
+    pub partition_size_mb: u64,
+    pub partitioning_by_load: Option<bool>,
+    pub min_partitions_count: u64,
+    pub max_partitions_count: u64,
+}
+
+impl From<RawPartitioningSettings> for table::PartitioningSettings {
+    fn from(value: RawPartitioningSettings) -> Self {
+        Self {
+            partition_by: value.partition_by,
+            partitioning_by_size: feature_flag_to_proto(value.partitioning_by_size),
+            partition_size_mb: value.partition_size_mb,
+            partitioning_by_load: feature_flag_to_proto(value.partitioning_by_load),
+            min_partitions_count: value.min_partitions_count,
+            max_partitions_count: value.max_partitions_count,
+        }
+    }
+}
+
+impl From<table::PartitioningSettings> for RawPartitioningSettings {
+    fn from(value: table::PartitioningSettings) -> Self {
+        Self {
+            partition_by: value.partition_by,
+            partitioning_by_size: feature_flag_from_proto(value.partitioning_by_size),
+            partition_size_mb: value.partition_size_mb,
+            partitioning_by_load: feature_flag_from_proto(value.partitioning_by_load),
+            min_partitions_count: value.min_partitions_count,
+            max_partitions_count: value.max_partitions_count,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, serde::Serialize)]
+pub(crate) enum RawCompression {
+    Unspecified,
+    None,
+    Lz4,
+}
+
+impl From<RawCompression> for table::column_family::Compression {
+    fn from(value: RawCompression) -> Self {
+        match value {
+            RawCompression::Unspecified => Self::Unspecified,
+            RawCompression::None => Self::None,
+            RawCompression::Lz4 => Self::Lz4,
+        }
+    }
+}
+
+impl From<i32> for RawCompression {
+    fn from(value: i32) -> Self {
+        use table::column_family::Compression;
+
+        match Compression::try_from(value) {
+            Ok(Compression::None) => RawCompression::None,
+            Ok(Compression::Lz4) => RawCompression::Lz4,
+            _ => RawCompression::Unspecified,
+        }
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub(crate) struct RawColumnFamily {
+    pub name: String,
+    pub data_media: Option<String>,
+    pub compression: RawCompression,
+}
+
+impl From<RawColumnFamily> for table::ColumnFamily {
+    fn from(value: RawColumnFamily) -> Self {
+        Self {
+            name: value.name,
+            data: value.data_media.map(|media| table::StoragePool { media }),
+            compression: table::column_family::Compression::from(value.compression) as i32,
+            keep_in_memory: feature_flag::Status::Unspecified as i32,
+        }
+    }
+}
+
+impl From<table::ColumnFamily> for RawColumnFamily {
+    fn from(value: table::ColumnFamily) -> Self {
+        Self {
+            name: value.name,
+            data_media: value.data.map(|pool| pool.media),
+            compression: RawCompression::from(value.compression),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, serde::Serialize)]
+pub(crate) enum RawReadReplicasSettings {
+    PerAzReadReplicasCount(u64),
+    AnyAzReadReplicasCount(u64),
+}
+
+impl From<RawReadReplicasSettings> for table::ReadReplicasSettings {
+    fn from(value: RawReadReplicasSettings) -> Self {
+        use table::read_replicas_settings::Settings;
+
+        let settings = match value {
+            RawReadReplicasSettings::PerAzReadReplicasCount(count) => {
+                Settings::PerAzReadReplicasCount(count)
+            }
+            RawReadReplicasSettings::AnyAzReadReplicasCount(count) => {
+                Settings::AnyAzReadReplicasCount(count)
+            }
+        };
+        Self {
+            settings: Some(settings),
+        }
+    }
+}
+
+impl From<table::read_replicas_settings::Settings> for RawReadReplicasSettings {
+    fn from(value: table::read_replicas_settings::Settings) -> Self {
+        use table::read_replicas_settings::Settings;
+
+        match value {
+            Settings::PerAzReadReplicasCount(count) => {
+                RawReadReplicasSettings::PerAzReadReplicasCount(count)
+            }
+            Settings::AnyAzReadReplicasCount(count) => {
+                RawReadReplicasSettings::AnyAzReadReplicasCount(count)
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, serde::Serialize)]
+pub(crate) enum RawChangefeedMode {
+    KeysOnly,
+    Updates,
+    NewImage,
+    OldImage,
+    NewAndOldImages,
+    Unknown(i32),
+}
+
+impl From<RawChangefeedMode> for i32 {
+    fn from(value: RawChangefeedMode) -> Self {
+        use table::changefeed_mode::Mode;
+
+        match value {
+            RawChangefeedMode::KeysOnly => Mode::KeysOnly as i32,
+            RawChangefeedMode::Updates => Mode::Updates as i32,
+            RawChangefeedMode::NewImage => Mode::NewImage as i32,
+            RawChangefeedMode::OldImage => Mode::OldImage as i32,
+            RawChangefeedMode::NewAndOldImages => Mode::NewAndOldImages as i32,
+            RawChangefeedMode::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<i32> for RawChangefeedMode {
+    fn from(value: i32) -> Self {
+        use table::changefeed_mode::Mode;
+
+        match Mode::try_from(value) {
+            Ok(Mode::KeysOnly) => RawChangefeedMode::KeysOnly,
+            Ok(Mode::Updates) => RawChangefeedMode::Updates,
+            Ok(Mode::NewImage) => RawChangefeedMode::NewImage,
+            Ok(Mode::OldImage) => RawChangefeedMode::OldImage,
+            Ok(Mode::NewAndOldImages) => RawChangefeedMode::NewAndOldImages,
+            _ => RawChangefeedMode::Unknown(value),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, serde::Serialize)]
+pub(crate) enum RawChangefeedFormat {
+    Json,
+    DynamodbStreamsJson,
+    DebeziumJson,
+    Unknown(i32),
+}
+
+impl From<RawChangefeedFormat> for i32 {
+    fn from(value: RawChangefeedFormat) -> Self {
+        use table::changefeed_format::Format;
+
+        match value {
+            RawChangefeedFormat::Json => Format::Json as i32,
+            RawChangefeedFormat::DynamodbStreamsJson => Format::DynamodbStreamsJson as i32,
+            RawChangefeedFormat::DebeziumJson => Format::DebeziumJson as i32,
+            RawChangefeedFormat::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<i32> for RawChangefeedFormat {
+    fn from(value: i32) -> Self {
+        use table::changefeed_format::Format;
+
+        match Format::try_from(value) {
+            Ok(Format::Json) => RawChangefeedFormat::Json,
+            Ok(Format::DynamodbStreamsJson) => RawChangefeedFormat::DynamodbStreamsJson,
+            Ok(Format::DebeziumJson) => RawChangefeedFormat::DebeziumJson,
+            _ => RawChangefeedFormat::Unknown(value),
+        }
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub(crate) struct RawChangefeed {
+    pub name: String,
+    pub mode: RawChangefeedMode,
+    pub format: RawChangefeedFormat,
+    pub retention_period: Option<Duration>,
+    pub virtual_timestamps: bool,
+    pub initial_scan: bool,
+    pub attributes: HashMap<String, String>,
+    pub resolved_timestamps_interval: Option<Duration>,
+}
+
+impl From<RawChangefeed> for table::Changefeed {
+    fn from(value: RawChangefeed) -> Self {
+        Self {
+            name: value.name,
+            mode: value.mode.into(),
+            format: value.format.into(),
+            retention_period: value.retention_period.map(Into::into),
+            virtual_timestamps: value.virtual_timestamps,
+            initial_scan: value.initial_scan,
+            attributes: value.attributes,
+            aws_region: String::new(),
+            resolved_timestamps_interval: value.resolved_timestamps_interval.map(Into::into),
+            topic_partitioning_settings: None,
+        }
+    }
+}
+
+impl From<table::ChangefeedDescription> for RawChangefeed {
+    fn from(value: table::ChangefeedDescription) -> Self {
+        Self {
+            name: value.name,
+            mode: value.mode.into(),
+            format: value.format.into(),
+            retention_period: None,
+            virtual_timestamps: value.virtual_timestamps,
+            initial_scan: false,
+            attributes: value.attributes,
+            resolved_timestamps_interval: value.resolved_timestamps_interval.map(Into::into),
+        }
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub(crate) struct RawPartitionStats {
+    pub rows_estimate: u64,
+    pub store_size: u64,
+}
+
+impl From<table::PartitionStats> for RawPartitionStats {
+    fn from(value: table::PartitionStats) -> Self {
+        Self {
+            rows_estimate: value.rows_estimate,
+            store_size: value.store_size,
+        }
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub(crate) struct RawTableStats {
+    pub partition_stats: Vec<RawPartitionStats>,
+    pub rows_estimate: u64,
+    pub store_size: u64,
+    pub partitions: u64,
+    pub creation_time: Option<Timestamp>,
+    pub modification_time: Option<Timestamp>,
+}
+
+impl From<table::TableStats> for RawTableStats {
+    fn from(value: table::TableStats) -> Self {
+        Self {
+            partition_stats: value
+                .partition_stats
+                .into_iter()
+                .map(RawPartitionStats::from)
+                .collect(),
+            rows_estimate: value.rows_estimate,
+            store_size: value.store_size,
+            partitions: value.partitions,
+            creation_time: value.creation_time.map(Into::into),
+            modification_time: value.modification_time.map(Into::into),
+        }
+    }
+}
+
+pub(crate) fn feature_flag_to_proto(value: Option<bool>) -> i32 {
+    let status = match value {
+        None => feature_flag::Status::Unspecified,
+        Some(true) => feature_flag::Status::Enabled,
+        Some(false) => feature_flag::Status::Disabled,
+    };
+    status as i32
+}
+
+pub(crate) fn feature_flag_from_proto(value: i32) -> Option<bool> {
+    match feature_flag::Status::try_from(value) {
+        Ok(feature_flag::Status::Enabled) => Some(true),
+        Ok(feature_flag::Status::Disabled) => Some(false),
+        _ => None,
+    }
+}
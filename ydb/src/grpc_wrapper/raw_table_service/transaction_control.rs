@@ -38,6 +38,7 @@ pub(crate) enum RawTxMode {
     SerializableReadWrite,
     OnlineReadOnly(RawOnlineReadonlySettings),
     StaleReadOnly,
+    SnapshotReadOnly,
 }
 
 impl From<RawTxMode> for ydb_grpc::ydb_proto::table::transaction_settings::TxMode {
@@ -54,6 +55,7 @@ impl From<RawTxMode> for ydb_grpc::ydb_proto::table::transaction_settings::TxMod
                 allow_inconsistent_reads,
             }),
             RawTxMode::StaleReadOnly => TxMode::StaleReadOnly(table::StaleModeSettings {}),
+            RawTxMode::SnapshotReadOnly => TxMode::SnapshotReadOnly(table::SnapshotModeSettings {}),
         }
     }
 }
@@ -28,7 +28,7 @@ pub(crate) enum RawValue {
     Bytes(Vec<u8>),
     Text(String),
     NullFlag,
-    // NestedValue(Box<Value>), return as Variant with 0 index
+    // NestedValue(Box<Value>), return as Variant with 0 index, used for variants and nested optionals
     Items(Vec<RawValue>),
     Pairs(Vec<RawValuePair>),
     Variant(Box<RawVariantValue>),
@@ -55,7 +55,8 @@ impl TryFrom<ProtoValue> for RawValue {
             return Ok(Pairs(pairs?));
         };
 
-        decode_err("empty value item")
+        // empty list, tuple or dict
+        Ok(Items(Vec::new()))
     }
 }
 
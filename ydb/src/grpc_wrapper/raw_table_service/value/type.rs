@@ -1,4 +1,6 @@
 use crate::grpc_wrapper::raw_errors::{RawError, RawResult};
+use crate::types::{DyNumber, ValueDict, ValueTagged, ValueTz, ValueVariant};
+use crate::types_pg::{PgValue, ValuePg};
 use crate::{Bytes, SignedInterval, Value, ValueList, ValueOptional, ValueStruct};
 use std::time::SystemTime;
 use ydb_grpc::ydb_proto::r#type::{PrimitiveTypeId, Type as ProtoType};
@@ -28,6 +30,10 @@ pub(crate) enum RawType {
     TzDate,
     TzDatetime,
     TzTimestamp,
+    Date32,
+    Datetime64,
+    Timestamp64,
+    Interval64,
     Bytes, // String
     UTF8,
     Yson,
@@ -43,6 +49,7 @@ pub(crate) enum RawType {
     Dict(Box<DictType>),
     Variant(VariantType),
     Tagged(Box<TaggedType>),
+    Pg(PgType),
     Void,
     Null,
     EmptyList,
@@ -89,6 +96,15 @@ pub(crate) struct TaggedType {
     pub item_type: RawType,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize)]
+pub(crate) struct PgType {
+    pub type_name: String,
+    pub type_modifier: String,
+    pub oid: u32,
+    pub typlen: i32,
+    pub typmod: i32,
+}
+
 impl RawType {
     fn try_from_primitive_type_id(int_type_id: i32) -> RawResult<Self> {
         let type_id = PrimitiveTypeId::try_from(int_type_id)
@@ -116,10 +132,10 @@ impl RawType {
             PrimitiveTypeId::TzDate => RawType::TzDate,
             PrimitiveTypeId::TzDatetime => RawType::TzDatetime,
             PrimitiveTypeId::TzTimestamp => RawType::TzTimestamp,
-            PrimitiveTypeId::Date32 => RawType::Date,
-            PrimitiveTypeId::Datetime64 => RawType::DateTime,
-            PrimitiveTypeId::Interval64 => RawType::Interval,
-            PrimitiveTypeId::Timestamp64 => RawType::Timestamp,
+            PrimitiveTypeId::Date32 => RawType::Date32,
+            PrimitiveTypeId::Datetime64 => RawType::Datetime64,
+            PrimitiveTypeId::Interval64 => RawType::Interval64,
+            PrimitiveTypeId::Timestamp64 => RawType::Timestamp64,
             PrimitiveTypeId::String => RawType::Bytes,
             PrimitiveTypeId::Utf8 => RawType::UTF8,
             PrimitiveTypeId::Yson => RawType::Yson,
@@ -133,12 +149,6 @@ impl RawType {
     }
 
     pub fn into_value_example(self) -> RawResult<Value> {
-        fn unimplemented_type(t: RawType) -> RawResult<Value> {
-            Err(RawError::custom(format!(
-                "unimplemented example value for type: {t:?}"
-            )))
-        }
-
         let res = match self {
             RawType::Bool => Value::Bool(false),
             RawType::Int8 => Value::Int8(0),
@@ -155,16 +165,22 @@ impl RawType {
             RawType::DateTime => Value::DateTime(SystemTime::UNIX_EPOCH),
             RawType::Timestamp => Value::Timestamp(SystemTime::UNIX_EPOCH),
             RawType::Interval => Value::Interval(SignedInterval::default()),
-            t @ RawType::TzDate => return unimplemented_type(t),
-            t @ RawType::TzDatetime => return unimplemented_type(t),
-            t @ RawType::TzTimestamp => return unimplemented_type(t),
+            RawType::TzDate => Value::TzDate(ValueTz::new("1970-01-01", "GMT")),
+            RawType::TzDatetime => Value::TzDatetime(ValueTz::new("1970-01-01T00:00:00", "GMT")),
+            RawType::TzTimestamp => {
+                Value::TzTimestamp(ValueTz::new("1970-01-01T00:00:00.000000", "GMT"))
+            }
+            RawType::Date32 => Value::Date32(SystemTime::UNIX_EPOCH),
+            RawType::Datetime64 => Value::Datetime64(SystemTime::UNIX_EPOCH),
+            RawType::Timestamp64 => Value::Timestamp64(SystemTime::UNIX_EPOCH),
+            RawType::Interval64 => Value::Interval64(SignedInterval::default()),
             RawType::Bytes => Value::Bytes(Bytes::default()),
             RawType::UTF8 => Value::Text(String::default()),
             RawType::Yson => Value::Yson(Bytes::default()),
             RawType::Json => Value::Json(String::default()),
             RawType::Uuid => Value::Uuid(uuid::Uuid::nil()),
             RawType::JSONDocument => Value::JsonDocument(String::default()),
-            t @ RawType::DyNumber => return unimplemented_type(t),
+            RawType::DyNumber => Value::DyNumber(DyNumber::default()),
             RawType::Decimal(_) => Value::Decimal(decimal_rs::Decimal::default()),
             RawType::Optional(inner_type) => Value::Optional(Box::new(ValueOptional {
                 t: (*inner_type).into_value_example()?,
@@ -174,7 +190,13 @@ impl RawType {
                 t: inner_type.into_value_example()?,
                 values: Vec::default(),
             })),
-            t @ RawType::Tuple(_) => return unimplemented_type(t),
+            RawType::Tuple(tuple) => Value::Tuple(
+                tuple
+                    .elements
+                    .into_iter()
+                    .map(RawType::into_value_example)
+                    .collect::<RawResult<_>>()?,
+            ),
             RawType::Struct(fields) => {
                 let mut value_struct = ValueStruct::with_capacity(fields.members.len());
                 for field in fields.members.into_iter() {
@@ -182,13 +204,43 @@ impl RawType {
                 }
                 Value::Struct(value_struct)
             }
-            t @ RawType::Dict(_) => return unimplemented_type(t),
-            t @ RawType::Variant(_) => return unimplemented_type(t),
-            t @ RawType::Tagged(_) => return unimplemented_type(t),
-            t @ RawType::Void => return unimplemented_type(t),
+            RawType::Dict(dict) => Value::Dict(Box::new(ValueDict {
+                key_t: dict.key.into_value_example()?,
+                payload_t: dict.payload.into_value_example()?,
+                values: Vec::default(),
+            })),
+            RawType::Variant(variant) => {
+                let t = match variant {
+                    VariantType::Tuple(tuple) => RawType::Tuple(tuple),
+                    VariantType::Struct(struct_t) => RawType::Struct(struct_t),
+                }
+                .into_value_example()?;
+
+                // example of variant is first alternative
+                let value = match &t {
+                    Value::Tuple(items) => items.first().cloned(),
+                    Value::Struct(fields) => fields.values.first().cloned(),
+                    _ => None,
+                };
+                let Some(value) = value else {
+                    return Err(RawError::custom("variant type without alternatives"));
+                };
+
+                Value::Variant(Box::new(ValueVariant { t, index: 0, value }))
+            }
+            RawType::Tagged(tagged) => Value::Tagged(Box::new(ValueTagged {
+                tag: tagged.tag,
+                value: tagged.item_type.into_value_example()?,
+            })),
+            RawType::Pg(pg_type) => Value::Pg(ValuePg::with_typmod(
+                pg_type.oid,
+                pg_type.typmod,
+                PgValue::Null,
+            )),
+            RawType::Void => Value::Void,
             RawType::Null => Value::Null,
-            t @ RawType::EmptyList => return unimplemented_type(t),
-            t @ RawType::EmptyDict => return unimplemented_type(t),
+            RawType::EmptyList => Value::EmptyList,
+            RawType::EmptyDict => Value::EmptyDict,
         };
         Ok(res)
     }
@@ -279,9 +331,13 @@ impl TryFrom<ydb_grpc::ydb_proto::Type> for RawType {
                     item_type: RawType::try_from(*t)?,
                 }))
             }
-            ProtoType::PgType(_pg_type) => {
-                return decode_err("pg type unimplemented yet");
-            }
+            ProtoType::PgType(pg_type) => RawType::Pg(PgType {
+                type_name: pg_type.type_name,
+                type_modifier: pg_type.type_modifier,
+                oid: pg_type.oid,
+                typlen: pg_type.typlen,
+                typmod: pg_type.typmod,
+            }),
             ProtoType::VoidType(_) => RawType::Void,
             ProtoType::NullType(_) => RawType::Null,
             ProtoType::EmptyListType(_) => RawType::EmptyList,
@@ -359,6 +415,10 @@ impl From<RawType> for ydb_grpc::ydb_proto::Type {
             RawType::TzDate => ProtoType::TypeId(PrimitiveTypeId::TzDate as i32),
             RawType::TzDatetime => ProtoType::TypeId(PrimitiveTypeId::TzDatetime as i32),
             RawType::TzTimestamp => ProtoType::TypeId(PrimitiveTypeId::TzTimestamp as i32),
+            RawType::Date32 => ProtoType::TypeId(PrimitiveTypeId::Date32 as i32),
+            RawType::Datetime64 => ProtoType::TypeId(PrimitiveTypeId::Datetime64 as i32),
+            RawType::Timestamp64 => ProtoType::TypeId(PrimitiveTypeId::Timestamp64 as i32),
+            RawType::Interval64 => ProtoType::TypeId(PrimitiveTypeId::Interval64 as i32),
             RawType::Bytes => ProtoType::TypeId(PrimitiveTypeId::String as i32),
             RawType::UTF8 => ProtoType::TypeId(PrimitiveTypeId::Utf8 as i32),
             RawType::Yson => ProtoType::TypeId(PrimitiveTypeId::Yson as i32),
@@ -404,6 +464,13 @@ impl From<RawType> for ydb_grpc::ydb_proto::Type {
                     r#type: Some(Box::new(tagged.item_type.into())),
                 }))
             }
+            RawType::Pg(pg_type) => ProtoType::PgType(ydb_grpc::ydb_proto::PgType {
+                type_name: pg_type.type_name,
+                type_modifier: pg_type.type_modifier,
+                oid: pg_type.oid,
+                typlen: pg_type.typlen,
+                typmod: pg_type.typmod,
+            }),
             RawType::Void => ProtoType::VoidType(0),
             RawType::Null => ProtoType::NullType(0),
             RawType::EmptyList => ProtoType::EmptyListType(0),
@@ -25,6 +25,10 @@ fn consistent_conversion() -> RawResult<()> {
         TzDate,
         TzDatetime,
         TzTimestamp,
+        Date32,
+        Datetime64,
+        Timestamp64,
+        Interval64,
         Bytes, // String
         UTF8,
         Yson,
@@ -78,6 +82,13 @@ fn consistent_conversion() -> RawResult<()> {
             tag: "tag_name".to_string(),
             item_type: Uint32,
         })),
+        Pg(PgType {
+            type_name: "numeric".to_string(),
+            type_modifier: "10,2".to_string(),
+            oid: 1700,
+            typlen: -1,
+            typmod: 655366,
+        }),
     ];
     let mut discriminants = HashSet::new();
 
@@ -9,9 +9,14 @@ use uuid::Uuid;
 
 use super::r#type::DecimalType;
 use crate::grpc_wrapper::raw_errors::{RawError, RawResult};
-use crate::grpc_wrapper::raw_table_service::value::r#type::{RawType, StructMember, StructType};
-use crate::grpc_wrapper::raw_table_service::value::{RawTypedValue, RawValue};
-use crate::types::SECONDS_PER_DAY;
+use crate::grpc_wrapper::raw_table_service::value::r#type::{
+    DictType, PgType, RawType, StructMember, StructType, TaggedType, TupleType, VariantType,
+};
+use crate::grpc_wrapper::raw_table_service::value::{
+    RawTypedValue, RawValue, RawValuePair, RawVariantValue,
+};
+use crate::types::{DyNumber, ValueDict, ValueTagged, ValueTz, ValueVariant, SECONDS_PER_DAY};
+use crate::types_pg::{pg_type_name_and_len, PgValue, ValuePg};
 use crate::{Bytes, SignedInterval, Value};
 
 impl TryFrom<crate::Value> for RawTypedValue {
@@ -74,32 +79,54 @@ impl TryFrom<crate::Value> for RawTypedValue {
             Value::Date(v) => RawTypedValue {
                 r#type: RawType::Date,
                 value: RawValue::UInt32(
-                    (v.duration_since(SystemTime::UNIX_EPOCH).unwrap().as_secs() / SECONDS_PER_DAY)
-                        .try_into()?,
+                    (duration_since_unix_epoch(v)?.as_secs() / SECONDS_PER_DAY).try_into()?,
                 ),
             },
             Value::DateTime(v) => RawTypedValue {
                 r#type: RawType::DateTime,
-                value: RawValue::UInt32(
-                    v.duration_since(SystemTime::UNIX_EPOCH)
-                        .unwrap()
-                        .as_secs()
-                        .try_into()?,
-                ),
+                value: RawValue::UInt32(duration_since_unix_epoch(v)?.as_secs().try_into()?),
             },
             Value::Timestamp(v) => RawTypedValue {
                 r#type: RawType::Timestamp,
-                value: RawValue::UInt64(
-                    v.duration_since(SystemTime::UNIX_EPOCH)
-                        .unwrap()
-                        .as_micros()
-                        .try_into()?,
-                ),
+                value: RawValue::UInt64(duration_since_unix_epoch(v)?.as_micros().try_into()?),
             },
             Value::Interval(v) => RawTypedValue {
                 r#type: RawType::Interval,
                 value: RawValue::Int64(v.as_nanos()?),
             },
+            Value::Date32(v) => RawTypedValue {
+                r#type: RawType::Date32,
+                value: RawValue::Int32(
+                    SignedInterval::since_unix_epoch(v)
+                        .as_secs_floor()
+                        .div_euclid(SECONDS_PER_DAY as i64)
+                        .try_into()?,
+                ),
+            },
+            Value::Datetime64(v) => RawTypedValue {
+                r#type: RawType::Datetime64,
+                value: RawValue::Int64(SignedInterval::since_unix_epoch(v).as_secs_floor()),
+            },
+            Value::Timestamp64(v) => RawTypedValue {
+                r#type: RawType::Timestamp64,
+                value: RawValue::Int64(SignedInterval::since_unix_epoch(v).as_micros()?),
+            },
+            Value::Interval64(v) => RawTypedValue {
+                r#type: RawType::Interval64,
+                value: RawValue::Int64(v.as_micros()?),
+            },
+            Value::TzDate(v) => RawTypedValue {
+                r#type: RawType::TzDate,
+                value: RawValue::Text(v.to_text()),
+            },
+            Value::TzDatetime(v) => RawTypedValue {
+                r#type: RawType::TzDatetime,
+                value: RawValue::Text(v.to_text()),
+            },
+            Value::TzTimestamp(v) => RawTypedValue {
+                r#type: RawType::TzTimestamp,
+                value: RawValue::Text(v.to_text()),
+            },
             Value::Bytes(v) => RawTypedValue {
                 r#type: RawType::Bytes,
                 value: RawValue::Bytes(v.into()),
@@ -144,13 +171,45 @@ impl TryFrom<crate::Value> for RawTypedValue {
                     value: RawValue::HighLow128(high, low),
                 }
             }
+            Value::DyNumber(v) => RawTypedValue {
+                r#type: RawType::DyNumber,
+                value: RawValue::Text(v.value),
+            },
+            Value::Pg(v) => {
+                // type name and length unknown for custom types
+                let (type_name, typlen) = pg_type_name_and_len(v.oid).unwrap_or_default();
+                RawTypedValue {
+                    r#type: RawType::Pg(PgType {
+                        type_name: type_name.to_string(),
+                        type_modifier: String::new(),
+                        oid: v.oid,
+                        typlen,
+                        typmod: v.typmod,
+                    }),
+                    value: match v.value {
+                        PgValue::Null => RawValue::NullFlag,
+                        PgValue::Text(text) => RawValue::Text(text),
+                        PgValue::Bytes(bytes) => RawValue::Bytes(bytes.into()),
+                    },
+                }
+            }
             Value::Optional(v) => {
+                let is_nested = v.t.is_optional();
                 let type_example: RawTypedValue = v.t.try_into()?;
                 if let Some(v) = v.value {
                     let value: RawTypedValue = v.try_into()?;
+                    let value = if is_nested {
+                        // value of nested optional wrapped for distinguish Just(Null) from Null
+                        RawValue::Variant(Box::new(RawVariantValue {
+                            value: value.value,
+                            index: 0,
+                        }))
+                    } else {
+                        value.value
+                    };
                     RawTypedValue {
                         r#type: RawType::Optional(Box::new(type_example.r#type)),
-                        value: value.value,
+                        value,
                     }
                 } else {
                     RawTypedValue {
@@ -200,11 +259,99 @@ impl TryFrom<crate::Value> for RawTypedValue {
                     value: RawValue::Items(raw_items),
                 }
             }
+            Value::Tuple(v) => {
+                let mut elements = Vec::with_capacity(v.len());
+                let mut raw_items = Vec::with_capacity(v.len());
+                for item in v.into_iter() {
+                    let raw_typed_value = RawTypedValue::try_from(item)?;
+                    elements.push(raw_typed_value.r#type);
+                    raw_items.push(raw_typed_value.value);
+                }
+
+                RawTypedValue {
+                    r#type: RawType::Tuple(TupleType { elements }),
+                    value: RawValue::Items(raw_items),
+                }
+            }
+            Value::Dict(v) => {
+                let key_type = RawTypedValue::try_from(v.key_t)?.r#type;
+                let payload_type = RawTypedValue::try_from(v.payload_t)?.r#type;
+                let pairs: Vec<RawValuePair> = v
+                    .values
+                    .into_iter()
+                    .map(|(key, payload)| {
+                        Ok::<_, RawError>(RawValuePair {
+                            key: RawTypedValue::try_from(key)?.value,
+                            payload: RawTypedValue::try_from(payload)?.value,
+                        })
+                    })
+                    .try_collect()?;
+
+                RawTypedValue {
+                    r#type: RawType::Dict(Box::new(DictType {
+                        key: key_type,
+                        payload: payload_type,
+                    })),
+                    value: RawValue::Pairs(pairs),
+                }
+            }
+            Value::Variant(v) => {
+                let variant_type = match RawTypedValue::try_from(v.t)?.r#type {
+                    RawType::Tuple(t) => VariantType::Tuple(t),
+                    RawType::Struct(t) => VariantType::Struct(t),
+                    t => {
+                        return Err(RawError::custom(format!(
+                            "variant alternatives must be tuple or struct, got: {t:?}"
+                        )))
+                    }
+                };
+
+                RawTypedValue {
+                    r#type: RawType::Variant(variant_type),
+                    value: RawValue::Variant(Box::new(RawVariantValue {
+                        value: RawTypedValue::try_from(v.value)?.value,
+                        index: v.index,
+                    })),
+                }
+            }
+            Value::Tagged(v) => {
+                let value = RawTypedValue::try_from(v.value)?;
+                RawTypedValue {
+                    r#type: RawType::Tagged(Box::new(TaggedType {
+                        tag: v.tag,
+                        item_type: value.r#type,
+                    })),
+                    value: value.value,
+                }
+            }
+            Value::EmptyList => RawTypedValue {
+                r#type: RawType::EmptyList,
+                value: RawValue::Items(Vec::new()),
+            },
+            Value::EmptyDict => RawTypedValue {
+                r#type: RawType::EmptyDict,
+                value: RawValue::Pairs(Vec::new()),
+            },
         };
         Ok(res)
     }
 }
 
+fn duration_since_unix_epoch(t: SystemTime) -> RawResult<Duration> {
+    crate::types::duration_since_unix_epoch(t).map_err(|err| RawError::custom(err.to_string()))
+}
+
+fn unix_time_from_micros(micros: i64) -> RawResult<SystemTime> {
+    SignedInterval::from_micros(micros)
+        .to_unix_time()
+        .ok_or_else(|| RawError::custom(format!("time out of range: {micros} microseconds")))
+}
+
+fn tz_from_text(text: String) -> RawResult<ValueTz> {
+    ValueTz::from_text(&text)
+        .ok_or_else(|| RawError::decode_error(format!("bad value with timezone: '{text}'")))
+}
+
 pub(crate) fn split_to_parts(v: u128) -> (u64, u64) {
     let high = (v >> 64) as u64;
     let low = v as u64;
@@ -225,12 +372,6 @@ impl TryFrom<RawTypedValue> for Value {
             )))
         }
 
-        fn type_unimplemented(t: RawType) -> Result<Value, RawError> {
-            Err(RawError::custom(format!(
-                "unimplemented raw to internal rust type conversion for type: {t:?}"
-            )))
-        }
-
         let res = match (value.r#type, value.value) {
             (RawType::Bool, RawValue::Bool(v)) => Value::Bool(v),
             (t @ RawType::Bool, v) => return types_mismatch(t, v),
@@ -270,9 +411,31 @@ impl TryFrom<RawTypedValue> for Value {
                 Value::Interval(SignedInterval::from_nanos(v))
             }
             (t @ RawType::Interval, v) => return types_mismatch(t, v),
-            (t @ RawType::TzDate, _) => return type_unimplemented(t),
-            (t @ RawType::TzDatetime, _) => return type_unimplemented(t),
-            (t @ RawType::TzTimestamp, _) => return type_unimplemented(t),
+            (RawType::Date32, RawValue::Int32(v)) => Value::Date32(unix_time_from_micros(
+                (v as i64) * (SECONDS_PER_DAY as i64) * 1_000_000,
+            )?),
+            (t @ RawType::Date32, v) => return types_mismatch(t, v),
+            (RawType::Datetime64, RawValue::Int64(v)) => {
+                let micros = v.checked_mul(1_000_000).ok_or_else(|| {
+                    RawError::custom(format!("datetime64 out of range: {v} seconds"))
+                })?;
+                Value::Datetime64(unix_time_from_micros(micros)?)
+            }
+            (t @ RawType::Datetime64, v) => return types_mismatch(t, v),
+            (RawType::Timestamp64, RawValue::Int64(v)) => {
+                Value::Timestamp64(unix_time_from_micros(v)?)
+            }
+            (t @ RawType::Timestamp64, v) => return types_mismatch(t, v),
+            (RawType::Interval64, RawValue::Int64(v)) => {
+                Value::Interval64(SignedInterval::from_micros(v))
+            }
+            (t @ RawType::Interval64, v) => return types_mismatch(t, v),
+            (RawType::TzDate, RawValue::Text(v)) => Value::TzDate(tz_from_text(v)?),
+            (t @ RawType::TzDate, v) => return types_mismatch(t, v),
+            (RawType::TzDatetime, RawValue::Text(v)) => Value::TzDatetime(tz_from_text(v)?),
+            (t @ RawType::TzDatetime, v) => return types_mismatch(t, v),
+            (RawType::TzTimestamp, RawValue::Text(v)) => Value::TzTimestamp(tz_from_text(v)?),
+            (t @ RawType::TzTimestamp, v) => return types_mismatch(t, v),
             (RawType::Bytes, RawValue::Bytes(v)) => Value::Bytes(Bytes::from(v)),
             (t @ RawType::Bytes, v) => return types_mismatch(t, v),
             (RawType::UTF8, RawValue::Text(v)) => Value::Text(v),
@@ -293,7 +456,8 @@ impl TryFrom<RawTypedValue> for Value {
             (t @ RawType::Uuid, v) => return types_mismatch(t, v),
             (RawType::JSONDocument, RawValue::Text(v)) => Value::JsonDocument(v),
             (t @ RawType::JSONDocument, v) => return types_mismatch(t, v),
-            (t @ RawType::DyNumber, _) => return type_unimplemented(t),
+            (RawType::DyNumber, RawValue::Text(v)) => Value::DyNumber(DyNumber::new(v)),
+            (t @ RawType::DyNumber, v) => return types_mismatch(t, v),
             (RawType::Decimal(t), RawValue::HighLow128(high, low)) => {
                 let int_val = merge_parts(high, low) as i128;
 
@@ -307,6 +471,12 @@ impl TryFrom<RawTypedValue> for Value {
                 let opt_value: Option<Value> = if let RawValue::NullFlag = v {
                     None
                 } else {
+                    let v = match (inner_type.as_ref(), v) {
+                        (RawType::Optional(_), RawValue::Variant(nested)) if nested.index == 0 => {
+                            nested.value
+                        }
+                        (_, v) => v,
+                    };
                     let val: Value = RawTypedValue {
                         r#type: (*inner_type).clone(),
                         value: v,
@@ -355,7 +525,30 @@ impl TryFrom<RawTypedValue> for Value {
                     }
                 }
             }
-            (t @ RawType::Tuple(_), _) => return type_unimplemented(t),
+            (RawType::Tuple(tuple_type), RawValue::Items(items)) => {
+                if tuple_type.elements.len() != items.len() {
+                    return Err(RawError::custom(format!(
+                        "mismatch tuple elements len description: '{:?}' and value items while decode raw types values with len: {}",
+                        tuple_type,
+                        items.len()
+                    )));
+                };
+
+                let values: Vec<Value> = tuple_type
+                    .elements
+                    .into_iter()
+                    .zip_eq(items)
+                    .map(|(t, item)| {
+                        RawTypedValue {
+                            r#type: t,
+                            value: item,
+                        }
+                        .try_into()
+                    })
+                    .try_collect()?;
+                Value::Tuple(values)
+            }
+            (t @ RawType::Tuple(_), v) => return types_mismatch(t, v),
             (RawType::Struct(struct_name_types), RawValue::Items(items)) => {
                 if struct_name_types.members.len() != items.len() {
                     return Err(RawError::custom(format!(
@@ -385,14 +578,99 @@ impl TryFrom<RawTypedValue> for Value {
                 Value::struct_from_fields(fields)
             }
             (t @ RawType::Struct(_), v) => return types_mismatch(t, v),
-            (t @ RawType::Dict(_), _) => return type_unimplemented(t),
-            (t @ RawType::Variant(_), _) => return type_unimplemented(t),
-            (t @ RawType::Tagged(_), _) => return type_unimplemented(t),
+            (RawType::Dict(dict_type), v) => {
+                let pairs = match v {
+                    RawValue::Pairs(pairs) => pairs,
+                    // empty dict has no pairs in protobuf
+                    RawValue::Items(items) if items.is_empty() => Vec::new(),
+                    RawValue::NullFlag => Vec::new(),
+                    v => return types_mismatch(RawType::Dict(dict_type), v),
+                };
+
+                let values: Vec<(Value, Value)> = pairs
+                    .into_iter()
+                    .map(|pair| {
+                        let key: Value = RawTypedValue {
+                            r#type: dict_type.key.clone(),
+                            value: pair.key,
+                        }
+                        .try_into()?;
+                        let payload: Value = RawTypedValue {
+                            r#type: dict_type.payload.clone(),
+                            value: pair.payload,
+                        }
+                        .try_into()?;
+                        Ok::<_, RawError>((key, payload))
+                    })
+                    .try_collect()?;
+
+                let DictType { key, payload } = *dict_type;
+                Value::Dict(Box::new(ValueDict {
+                    key_t: key.into_value_example()?,
+                    payload_t: payload.into_value_example()?,
+                    values,
+                }))
+            }
+            (RawType::Variant(variant_type), RawValue::Variant(v)) => {
+                let item_type = match &variant_type {
+                    VariantType::Tuple(t) => t.elements.get(v.index as usize).cloned(),
+                    VariantType::Struct(t) => t
+                        .members
+                        .get(v.index as usize)
+                        .map(|member| member.member_type.clone()),
+                };
+                let Some(item_type) = item_type else {
+                    return Err(RawError::custom(format!(
+                        "variant index {} out of range for type: {variant_type:?}",
+                        v.index
+                    )));
+                };
+
+                let value: Value = RawTypedValue {
+                    r#type: item_type,
+                    value: v.value,
+                }
+                .try_into()?;
+
+                let t = match variant_type {
+                    VariantType::Tuple(t) => RawType::Tuple(t),
+                    VariantType::Struct(t) => RawType::Struct(t),
+                };
+                Value::Variant(Box::new(ValueVariant {
+                    t: t.into_value_example()?,
+                    index: v.index,
+                    value,
+                }))
+            }
+            (t @ RawType::Variant(_), v) => return types_mismatch(t, v),
+            (RawType::Tagged(tagged_type), v) => {
+                let TaggedType { tag, item_type } = *tagged_type;
+                let value: Value = RawTypedValue {
+                    r#type: item_type,
+                    value: v,
+                }
+                .try_into()?;
+                Value::Tagged(Box::new(ValueTagged { tag, value }))
+            }
+            (RawType::Pg(t), v) => {
+                let value = match v {
+                    RawValue::NullFlag => PgValue::Null,
+                    RawValue::Text(text) => PgValue::Text(text),
+                    RawValue::Bytes(bytes) => PgValue::Bytes(bytes.into()),
+                    v => return types_mismatch(RawType::Pg(t), v),
+                };
+                Value::Pg(ValuePg::with_typmod(t.oid, t.typmod, value))
+            }
             (RawType::Null, RawValue::NullFlag) => Value::Null,
             (t @ RawType::Null, v) => return types_mismatch(t, v),
             (RawType::Void, RawValue::NullFlag) => Value::Void,
             (t @ RawType::Void, v) => return types_mismatch(t, v),
+            (RawType::EmptyList, RawValue::Items(items)) if items.is_empty() => Value::EmptyList,
+            (RawType::EmptyList, RawValue::NullFlag) => Value::EmptyList,
             (t @ RawType::EmptyList, v) => return types_mismatch(t, v),
+            (RawType::EmptyDict, RawValue::Items(items)) if items.is_empty() => Value::EmptyDict,
+            (RawType::EmptyDict, RawValue::Pairs(pairs)) if pairs.is_empty() => Value::EmptyDict,
+            (RawType::EmptyDict, RawValue::NullFlag) => Value::EmptyDict,
             (t @ RawType::EmptyDict, v) => return types_mismatch(t, v),
         };
         Ok(res)
@@ -1,7 +1,9 @@
 use crate::grpc_wrapper::raw_errors::{RawError, RawResult};
 use crate::grpc_wrapper::raw_table_service::value::RawTypedValue;
 use crate::types::Value;
-use ydb_grpc::ydb_proto::TypedValue;
+use crate::types_pg::ValuePg;
+use ydb_grpc::ydb_proto::r#type::Type as ProtoType;
+use ydb_grpc::ydb_proto::{Type, TypedValue};
 
 #[test]
 fn convert_ydb_raw_grpc() -> RawResult<()> {
@@ -26,3 +28,26 @@ fn convert_ydb_raw_grpc() -> RawResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn pg_type_name_and_len() -> RawResult<()> {
+    let check = |value: ValuePg, type_name: &str, typlen: i32| -> RawResult<()> {
+        let proto = TypedValue::from(RawTypedValue::try_from(Value::Pg(value))?);
+        let Some(Type {
+            r#type: Some(ProtoType::PgType(pg_type)),
+        }) = proto.r#type
+        else {
+            return Err(RawError::custom("expected pg type"));
+        };
+        assert_eq!(type_name, pg_type.type_name);
+        assert_eq!(typlen, pg_type.typlen);
+        Ok(())
+    };
+
+    check(ValuePg::new(ValuePg::INT4, "1"), "int4", 4)?;
+    check(ValuePg::new(ValuePg::TEXT, "text"), "text", -1)?;
+    check(ValuePg::null(ValuePg::UUID), "uuid", 16)?;
+    // custom type
+    check(ValuePg::new(100_000, "value"), "", 0)?;
+    Ok(())
+}
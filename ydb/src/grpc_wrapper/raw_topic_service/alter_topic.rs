@@ -10,7 +10,7 @@ use crate::grpc_wrapper::raw_topic_service::common::consumer::RawAlterConsumer;
 use crate::grpc_wrapper::raw_ydb_operation::RawOperationParams;
 use ydb_grpc::ydb_proto::topic::{AlterConsumer, AlterTopicRequest, Consumer, MeteringMode};
 
-#[derive(serde::Serialize)]
+#[derive(Clone, serde::Serialize)]
 pub(crate) struct RawAlterTopicRequest {
     pub operation_params: RawOperationParams,
     pub path: String,
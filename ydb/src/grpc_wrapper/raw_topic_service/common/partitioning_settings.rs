@@ -1,6 +1,6 @@
 use ydb_grpc::ydb_proto::topic::{AlterPartitioningSettings, PartitioningSettings};
 
-#[derive(Debug, serde::Serialize)]
+#[derive(Clone, Debug, serde::Serialize)]
 pub(crate) struct RawPartitioningSettings {
     pub min_active_partitions: i64,
     pub partition_count_limit: i64,
@@ -27,7 +27,7 @@ impl From<RawPartitioningSettings> for PartitioningSettings {
     }
 }
 
-#[derive(Debug, serde::Serialize)]
+#[derive(Clone, Debug, serde::Serialize)]
 pub(crate) struct RawAlterPartitioningSettings {
     pub set_min_active_partitions: Option<i64>,
     pub set_partition_count_limit: Option<i64>,
@@ -12,7 +12,7 @@ use ydb_grpc::ydb_proto::topic::{
     Consumer, CreateTopicRequest, MeteringMode, PartitioningSettings, SupportedCodecs,
 };
 
-#[derive(serde::Serialize)]
+#[derive(Clone, serde::Serialize)]
 pub(crate) struct RawCreateTopicRequest {
     pub operation_params: RawOperationParams,
     pub path: String,
@@ -9,7 +9,7 @@ use crate::{
 };
 use ydb_grpc::ydb_proto::topic::{DescribeConsumerRequest, DescribeConsumerResult};
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub(crate) struct RawDescribeConsumerRequest {
     pub path: String,
     pub consumer: String,
@@ -15,7 +15,7 @@ use crate::{
 use std::collections::HashMap;
 use ydb_grpc::ydb_proto::topic::{DescribeTopicRequest, DescribeTopicResult};
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub(crate) struct RawDescribeTopicRequest {
     pub path: String,
     pub operation_params: RawOperationParams,
@@ -2,7 +2,7 @@ use crate::grpc_wrapper::raw_ydb_operation::RawOperationParams;
 use ydb_grpc::ydb_proto::operations::OperationParams;
 use ydb_grpc::ydb_proto::topic::DropTopicRequest;
 
-#[derive(serde::Serialize)]
+#[derive(Clone, serde::Serialize)]
 pub(crate) struct RawDropTopicRequest {
     pub operation_params: RawOperationParams,
     pub path: String,
@@ -20,6 +20,16 @@ impl RawOperationParams {
             labels: Default::default(),
         }
     }
+
+    // for long-running operations, which can't be limited by request timeouts
+    pub fn new_async() -> Self {
+        Self {
+            operation_mode: OperationMode::Async,
+            operation_timeout: None,
+            cancel_after: None,
+            labels: Default::default(),
+        }
+    }
 }
 
 impl From<RawOperationParams> for ydb_grpc::ydb_proto::operations::OperationParams {
@@ -38,7 +48,7 @@ impl From<RawOperationParams> for ydb_grpc::ydb_proto::operations::OperationPara
 pub(crate) enum OperationMode {
     _Unspecified,
     Sync,
-    _Async,
+    Async,
 }
 
 use ydb_grpc::ydb_proto::operations::operation_params::OperationMode as GrpcOperationMode;
@@ -47,7 +57,7 @@ impl From<OperationMode> for i32 {
         let val = match mode {
             OperationMode::_Unspecified => GrpcOperationMode::Unspecified,
             OperationMode::Sync => GrpcOperationMode::Sync,
-            OperationMode::_Async => GrpcOperationMode::Async,
+            OperationMode::Async => GrpcOperationMode::Async,
         };
         val as i32
     }
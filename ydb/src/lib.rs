@@ -46,12 +46,18 @@
 //!
 extern crate core;
 
+// generated code of derive macros use ::ydb path, it must work inside the crate too
+extern crate self as ydb;
+
 pub(crate) mod client;
 mod client_builder;
 pub(crate) mod client_common;
 pub(crate) mod client_coordination;
 #[cfg(test)]
 mod client_directory_test_integration;
+pub(crate) mod client_query;
+#[cfg(test)]
+mod client_query_test_integration;
 pub(crate) mod client_scheme;
 pub(crate) mod client_table;
 #[cfg(test)]
@@ -59,6 +65,7 @@ mod client_table_test_integration;
 pub(crate) mod client_topic;
 pub(crate) mod connection_pool;
 mod credentials;
+mod derive;
 pub(crate) mod discovery;
 mod errors;
 mod grpc;
@@ -67,9 +74,13 @@ mod grpc_wrapper;
 mod load_balancer;
 mod pub_traits;
 pub(crate) mod query;
+mod query_plan;
+mod query_stats;
 pub(crate) mod result;
+mod retry;
 mod session;
 mod session_pool;
+mod shutdown;
 mod sugar;
 
 #[cfg(test)]
@@ -93,7 +104,15 @@ mod trace_helpers;
 mod trait_operation;
 pub(crate) mod transaction;
 mod types;
+#[cfg(feature = "arrow")]
+mod types_arrow;
+#[cfg(feature = "chrono")]
+mod types_chrono;
 mod types_converters;
+mod types_pg;
+mod types_serde;
+#[cfg(feature = "time")]
+mod types_time;
 pub(crate) mod waiter;
 
 #[cfg(test)]
@@ -146,8 +165,27 @@ pub use client::{Client, TimeoutSettings};
 // full enum pub types
 pub use client_builder::ClientBuilder;
 
+// full enum pub types
+pub use client_query::client::QueryClient;
+pub use client_query::script::{
+    FetchScriptResultsOptions, FetchScriptResultsOptionsBuilder, ScriptExecStatus, ScriptOperation,
+    ScriptResultSetPage,
+};
+pub use client_query::session::QuerySession;
+
 // full enum pub types
 pub use client_table::{RetryOptions, TableClient, TransactionOptions};
+pub use session::Session;
+pub use session_pool::{SessionPoolOptions, SessionPoolStats};
+pub use table_service_types::{
+    AlterTableOptions, AlterTableOptionsBuilder, Changefeed, ChangefeedBuilder, ChangefeedFormat,
+    ChangefeedMode, ColumnFamily, ColumnFamilyBuilder, ColumnFamilyCompression,
+    DescribeTableOptions, DescribeTableOptionsBuilder, KeyBound, KeyRange, KeyRangeBuilder,
+    ReadReplicasSettings, ReadTableOptions, ReadTableOptionsBuilder, RenameTableItem, TableColumn,
+    TableColumnBuilder, TableDescription, TableDescriptionBuilder, TableIndex, TableIndexBuilder,
+    TableIndexType, TablePartitionStats, TablePartitioningSettings,
+    TablePartitioningSettingsBuilder, TableStats, TtlColumnUnit, TtlSettings, TtlSettingsBuilder,
+};
 
 // full enum pub types
 pub use client_scheme::client::SchemeClient;
@@ -156,11 +194,21 @@ pub use client_scheme::list_types::{SchemeEntry, SchemeEntryType, SchemePermissi
 // full enum pub types
 pub use discovery::{Discovery, DiscoveryState, StaticDiscovery};
 // full enum pub types
-pub use query::Query;
+pub use query::{PreparedQuery, Query};
+pub use query_plan::{PlanNode, PlanOperator, PlanTable, PlanTableAccess, QueryPlan};
+pub use query_stats::{
+    CompilationStats, OperationStats, QueryPhaseStats, QueryStats, StatsMode, TableAccessStats,
+};
 // full enum pub types
-pub use result::{QueryResult, ResultSet, ResultSetRowsIter, Row, StreamResult};
+pub use result::{
+    QueryResult, QueryResultStream, ReadTableStream, ResultSet, ResultSetRowsIter, Row, RowIndex,
+    StreamResult,
+};
+pub use retry::{
+    Backoff, BackoffRetrier, BackoffType, NoRetrier, Retry, RetryBudget, RetryDecision, RetryParams,
+};
 // full enum pub types
-pub use transaction::{Mode, Transaction, TransactionInfo};
+pub use transaction::{Mode, Transaction, TransactionInfo, TxSettings};
 // full enum pub types
 pub use waiter::Waiter;
 // full enum pub types
@@ -169,14 +217,25 @@ pub use crate::{
         AccessTokenCredentials, AnonymousCredentials, CommandLineCredentials, FromEnvCredentials,
         GCEMetadata, MetadataUrlCredentials, ServiceAccountCredentials, StaticCredentials,
     },
+    derive::YdbStruct,
     errors::{
         YdbError, YdbIssue, YdbIssueSeverity, YdbOrCustomerError, YdbResult,
         YdbResultWithCustomerErr, YdbStatusError,
     },
     pub_traits::{Credentials, TokenInfo},
-    types::{Bytes, Sign, SignedInterval, Value, ValueList, ValueOptional, ValueStruct},
+    types::{
+        Bytes, Column, DyNumber, Sign, SignedInterval, Value, ValueDict, ValueList, ValueOptional,
+        ValueStruct, ValueTagged, ValueTz, ValueVariant,
+    },
+    types_pg::{PgValue, ValuePg},
+    types_serde::{from_row, from_value, to_value, to_value_with_example},
 };
 
+pub use ydb_derive::{YdbRow, YdbStruct};
+
+#[doc(hidden)]
+pub use crate::derive::derive_helpers;
+
 // deprecated types
 
 #[allow(deprecated)]
@@ -353,7 +353,7 @@ async fn detect_fastest_addr_timeout() -> YdbResult<()> {
         Err(err) => {
             assert_eq!(
                 err.to_string(),
-                "Custom(\"timeout while detecting fastest address\")"
+                "Timeout(\"timeout while detecting fastest address\")"
             );
         }
     }
@@ -368,7 +368,7 @@ async fn no_addr_timeout() -> YdbResult<()> {
         Err(err) => {
             assert_eq!(
                 err.to_string(),
-                "Custom(\"timeout while detecting fastest address\")"
+                "Timeout(\"timeout while detecting fastest address\")"
             );
         }
     }
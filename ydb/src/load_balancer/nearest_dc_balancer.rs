@@ -375,7 +375,9 @@ impl NearestDCBalancer {
             Ok(address_option) => address_option,
             Err(_) => {
                 interrupt_via_timeout.cancel();
-                YdbResult::Err("timeout while detecting fastest address".into())
+                Err(YdbError::Timeout(
+                    "timeout while detecting fastest address".to_string(),
+                ))
             }
         }
     }
@@ -1,4 +1,5 @@
 use crate::errors::YdbResult;
+use crate::query_stats::StatsMode;
 use crate::types::Value;
 use std::collections::HashMap;
 use std::str::FromStr;
@@ -12,6 +13,7 @@ pub struct Query {
     pub(crate) text: String,
     pub(crate) parameters: HashMap<String, Value>,
     pub(crate) keep_in_cache: bool,
+    pub(crate) stats_mode: StatsMode,
     force_keep_in_cache: bool,
 }
 
@@ -22,6 +24,7 @@ impl Query {
             text: query.into(),
             parameters: HashMap::new(),
             keep_in_cache: false,
+            stats_mode: StatsMode::None,
             force_keep_in_cache: false,
         }
     }
@@ -83,6 +86,23 @@ impl Query {
         self
     }
 
+    /// Set level of execution statistics, collected for the query
+    ///
+    /// Statistics returned in [QueryResult::stats](crate::QueryResult::stats) of data query result
+    /// for table and query services, and in [QueryResultStream::stats](crate::QueryResultStream::stats)
+    /// for streaming query. Stats mode of query, prepared by [Session::prepare](crate::Session::prepare),
+    /// moved to the prepared query.
+    ///
+    /// Example:
+    /// ```
+    /// # use ydb::{Query, StatsMode};
+    /// let query = Query::new("SELECT 1").with_stats(StatsMode::Basic);
+    /// ```
+    pub fn with_stats(mut self, mode: StatsMode) -> Self {
+        self.stats_mode = mode;
+        self
+    }
+
     pub(crate) fn query_to_proto(&self) -> ydb_grpc::ydb_proto::table::Query {
         ydb_grpc::ydb_proto::table::Query {
             query: Some(ydb_grpc::ydb_proto::table::query::Query::YqlText(
@@ -101,6 +121,71 @@ impl Query {
     }
 }
 
+/// Query, prepared on server side by [Session::prepare](crate::Session::prepare)
+///
+/// Prepared query bound to the session, execute it by [Session::execute_prepared](crate::Session::execute_prepared).
+/// The query will be prepared again on other session or if server forgot it.
+///
+/// Example:
+/// ```no_run
+/// # use ydb::YdbResult;
+/// #
+/// # #[tokio::main]
+/// # async fn main()->YdbResult<()>{
+/// #   use ydb::{ydb_params, Query};
+/// #   let table_client = ydb::ClientBuilder::new_from_connection_string("")?.client()?.table_client();
+///     let mut session = table_client.session().await?;
+///     let prepared = session
+///         .prepare(Query::new("DECLARE $id AS Int64; SELECT * FROM my_table WHERE id = $id"))
+///         .await?;
+///     assert!(prepared.parameter_types().contains_key("$id"));
+///     for id in 0..10_i64 {
+///         session
+///             .execute_prepared(prepared.clone().with_params(ydb_params!("$id" => id)))
+///             .await?;
+///     }
+/// #     return Ok(());
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct PreparedQuery {
+    pub(crate) session_id: String,
+    pub(crate) text: String,
+    pub(crate) query_id: String,
+    pub(crate) parameter_types: HashMap<String, Value>,
+    pub(crate) parameters: HashMap<String, Value>,
+    pub(crate) stats_mode: StatsMode,
+}
+
+impl PreparedQuery {
+    /// Text of the prepared query
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Id of the query on server side
+    pub fn query_id(&self) -> &str {
+        &self.query_id
+    }
+
+    /// Types of declared query parameters as example values, same as [Value::list_from] example
+    pub fn parameter_types(&self) -> &HashMap<String, Value> {
+        &self.parameter_types
+    }
+
+    /// Set query parameters for execute
+    pub fn with_params(mut self, params: HashMap<String, Value>) -> Self {
+        self.parameters = params;
+        self
+    }
+
+    /// Set level of execution statistics, same as [Query::with_stats]
+    pub fn with_stats(mut self, mode: StatsMode) -> Self {
+        self.stats_mode = mode;
+        self
+    }
+}
+
 impl From<&str> for Query {
     fn from(s: &str) -> Self {
         Query::new(s)
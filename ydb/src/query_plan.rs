@@ -0,0 +1,178 @@
+#[cfg(test)]
+#[path = "query_plan_test.rs"]
+mod query_plan_test;
+
+use crate::errors::YdbResult;
+use serde::Deserialize;
+
+/// Plan of data query, result of explain
+///
+/// Example:
+/// ```no_run
+/// # use ydb::YdbResult;
+/// #
+/// # #[tokio::main]
+/// # async fn main()->YdbResult<()>{
+/// #   use ydb::Query;
+/// #   let table_client = ydb::ClientBuilder::new_from_connection_string("")?.client()?.table_client();
+///     let plan = table_client
+///         .explain(Query::new("SELECT * FROM my_table WHERE name = 'test'"))
+///         .await?;
+///     for table in plan.tables.iter() {
+///         for read in table.reads.iter() {
+///             println!("table: {}, read: {}", table.name, read.access_type);
+///         }
+///     }
+///     assert!(!plan.has_full_scan());
+/// #     return Ok(());
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+#[cfg_attr(not(feature = "force-exhaustive-all"), non_exhaustive)]
+pub struct QueryPlan {
+    /// Abstract syntax tree of the query
+    pub ast: String,
+
+    /// Plan as is, in json format
+    pub plan_json: String,
+
+    /// Tables, used by the query
+    pub tables: Vec<PlanTable>,
+
+    /// Root node of plan tree
+    pub root: Option<PlanNode>,
+}
+
+impl QueryPlan {
+    pub(crate) fn parse(ast: String, plan_json: String) -> YdbResult<Self> {
+        #[derive(Deserialize)]
+        struct Plan {
+            #[serde(default)]
+            tables: Vec<PlanTable>,
+
+            #[serde(rename = "Plan")]
+            plan: Option<PlanNode>,
+        }
+
+        let plan: Plan = if plan_json.trim().is_empty() {
+            Plan {
+                tables: Vec::new(),
+                plan: None,
+            }
+        } else {
+            serde_json::from_str(&plan_json)?
+        };
+
+        Ok(Self {
+            ast,
+            plan_json,
+            tables: plan.tables,
+            root: plan.plan,
+        })
+    }
+
+    /// All nodes of plan tree, parents before children
+    pub fn nodes(&self) -> Vec<&PlanNode> {
+        let mut res = Vec::new();
+        let mut stack: Vec<&PlanNode> = self.root.iter().collect();
+        while let Some(node) = stack.pop() {
+            res.push(node);
+            stack.extend(node.children.iter().rev());
+        }
+        res
+    }
+
+    /// Query read whole table
+    pub fn has_full_scan(&self) -> bool {
+        let full_read = self
+            .tables
+            .iter()
+            .flat_map(|table| table.reads.iter())
+            .any(|read| read.access_type == "FullScan");
+
+        full_read
+            || self.nodes().iter().any(|node| {
+                node.operators
+                    .iter()
+                    .any(|operator| operator.name == "TableFullScan")
+            })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(not(feature = "force-exhaustive-all"), non_exhaustive)]
+pub struct PlanTable {
+    pub name: String,
+
+    #[serde(default)]
+    pub reads: Vec<PlanTableAccess>,
+
+    #[serde(default)]
+    pub writes: Vec<PlanTableAccess>,
+}
+
+/// Read or write of table
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(not(feature = "force-exhaustive-all"), non_exhaustive)]
+pub struct PlanTableAccess {
+    /// Type of access, for example "FullScan", "Scan", "Lookup", "MultiLookup" or "MultiUpsert"
+    #[serde(rename = "type")]
+    pub access_type: String,
+
+    /// Key columns with ranges of scan or lookup
+    #[serde(default, alias = "scan_by", alias = "lookup_by")]
+    pub key_columns: Vec<String>,
+
+    #[serde(default)]
+    pub columns: Vec<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(not(feature = "force-exhaustive-all"), non_exhaustive)]
+pub struct PlanNode {
+    /// Type of the node, for example "Query", "ResultSet", "Limit" or "TableFullScan"
+    #[serde(rename = "Node Type")]
+    pub node_type: String,
+
+    #[serde(rename = "PlanNodeId")]
+    pub id: Option<u64>,
+
+    #[serde(rename = "Tables", default)]
+    pub tables: Vec<String>,
+
+    #[serde(rename = "Operators", default)]
+    pub operators: Vec<PlanOperator>,
+
+    #[serde(rename = "Plans", default)]
+    pub children: Vec<PlanNode>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(from = "serde_json::Map<String, serde_json::Value>")]
+#[cfg_attr(not(feature = "force-exhaustive-all"), non_exhaustive)]
+pub struct PlanOperator {
+    /// Name of operator, for example "TableFullScan", "TablePointLookup" or "Filter"
+    pub name: String,
+
+    /// Other properties of operator as is, for example "Table", "ReadColumns" or "ReadRanges"
+    pub properties: serde_json::Map<String, serde_json::Value>,
+}
+
+impl PlanOperator {
+    /// Name of table, read by the operator
+    pub fn table(&self) -> Option<&str> {
+        self.properties
+            .get("Table")
+            .and_then(|table| table.as_str())
+    }
+}
+
+impl From<serde_json::Map<String, serde_json::Value>> for PlanOperator {
+    fn from(mut properties: serde_json::Map<String, serde_json::Value>) -> Self {
+        let name = match properties.remove("Name") {
+            Some(serde_json::Value::String(name)) => name,
+            _ => String::new(),
+        };
+        Self { name, properties }
+    }
+}
@@ -0,0 +1,85 @@
+use crate::errors::YdbResult;
+use crate::query_plan::QueryPlan;
+
+const PLAN: &str = r#"{
+    "meta": {"version": "0.2", "type": "query"},
+    "tables": [
+        {
+            "name": "/local/series",
+            "reads": [
+                {"type": "FullScan", "scan_by": ["series_id (-∞, +∞)"], "columns": ["title"]}
+            ]
+        },
+        {
+            "name": "/local/episodes",
+            "reads": [{"type": "Lookup", "lookup_by": ["series_id"], "columns": ["title"]}],
+            "writes": [{"type": "MultiUpsert", "key": ["series_id"], "columns": ["title"]}]
+        }
+    ],
+    "Plan": {
+        "Node Type": "Query",
+        "PlanNodeType": "Query",
+        "Plans": [
+            {
+                "Node Type": "ResultSet",
+                "PlanNodeId": 2,
+                "Plans": [
+                    {
+                        "Node Type": "TableFullScan",
+                        "PlanNodeId": 1,
+                        "Tables": ["series"],
+                        "Operators": [
+                            {
+                                "Name": "TableFullScan",
+                                "Table": "series",
+                                "ReadColumns": ["title"]
+                            }
+                        ]
+                    }
+                ]
+            }
+        ]
+    }
+}"#;
+
+#[test]
+fn parse_plan() -> YdbResult<()> {
+    let plan = QueryPlan::parse("(ast)".to_string(), PLAN.to_string())?;
+    assert_eq!("(ast)", plan.ast);
+
+    assert_eq!(2, plan.tables.len());
+    let series = &plan.tables[0];
+    assert_eq!("/local/series", series.name);
+    assert_eq!("FullScan", series.reads[0].access_type);
+    assert_eq!(vec!["series_id (-∞, +∞)"], series.reads[0].key_columns);
+    let episodes = &plan.tables[1];
+    assert_eq!("Lookup", episodes.reads[0].access_type);
+    assert_eq!(vec!["series_id"], episodes.reads[0].key_columns);
+    assert_eq!("MultiUpsert", episodes.writes[0].access_type);
+
+    let node_types: Vec<&str> = plan
+        .nodes()
+        .iter()
+        .map(|node| node.node_type.as_str())
+        .collect();
+    assert_eq!(vec!["Query", "ResultSet", "TableFullScan"], node_types);
+
+    let scan = plan.nodes()[2];
+    assert_eq!(Some(1), scan.id);
+    assert_eq!(vec!["series"], scan.tables);
+    assert_eq!("TableFullScan", scan.operators[0].name);
+    assert_eq!(Some("series"), scan.operators[0].table());
+    assert!(!scan.operators[0].properties.contains_key("Name"));
+
+    assert!(plan.has_full_scan());
+    Ok(())
+}
+
+#[test]
+fn parse_empty_plan() -> YdbResult<()> {
+    let plan = QueryPlan::parse(String::new(), String::new())?;
+    assert!(plan.root.is_none());
+    assert!(plan.nodes().is_empty());
+    assert!(!plan.has_full_scan());
+    Ok(())
+}
@@ -0,0 +1,193 @@
+#[cfg(test)]
+#[path = "query_stats_test.rs"]
+mod query_stats_test;
+
+use crate::errors::YdbResult;
+use crate::grpc_wrapper::raw_table_service::query_stats::{
+    RawCompilationStats, RawOperationStats, RawQueryPhaseStats, RawQueryStatMode, RawQueryStats,
+    RawTableAccessStats,
+};
+use crate::query_plan::QueryPlan;
+use std::time::Duration;
+
+/// Level of execution statistics, collected by server for the query
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(not(feature = "force-exhaustive-all"), non_exhaustive)]
+pub enum StatsMode {
+    /// Do not collect statistics
+    #[default]
+    None,
+
+    /// Aggregated statistics of query phases and tables access
+    Basic,
+
+    /// Basic statistics with query plan and ast
+    Full,
+
+    /// Full statistics with detailed execution profile in the plan
+    Profile,
+}
+
+impl From<StatsMode> for RawQueryStatMode {
+    fn from(value: StatsMode) -> Self {
+        match value {
+            StatsMode::None => RawQueryStatMode::None,
+            StatsMode::Basic => RawQueryStatMode::Basic,
+            StatsMode::Full => RawQueryStatMode::Full,
+            StatsMode::Profile => RawQueryStatMode::Profile,
+        }
+    }
+}
+
+/// Execution statistics of the query, collected when query executed with [StatsMode] other than None
+///
+/// Example:
+/// ```no_run
+/// # use ydb::YdbResult;
+/// #
+/// # #[tokio::main]
+/// # async fn main()->YdbResult<()>{
+/// #   use std::time::Duration;
+/// #   use ydb::{Query, StatsMode};
+/// #   let table_client = ydb::ClientBuilder::new_from_connection_string("")?.client()?.table_client();
+///     let res = table_client
+///         .retry_transaction(|mut t| async move {
+///             let query = Query::new("SELECT * FROM my_table").with_stats(StatsMode::Full);
+///             Ok(t.query(query).await?)
+///         })
+///         .await?;
+///     if let Some(stats) = res.stats() {
+///         if stats.total_duration > Duration::from_secs(1) {
+///             for phase in stats.query_phases.iter() {
+///                 for table in phase.table_access.iter() {
+///                     println!("table: {}, rows read: {}", table.name, table.reads.rows);
+///                 }
+///             }
+///             println!("plan: {:?}", stats.plan()?);
+///         }
+///     }
+/// #     return Ok(());
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+#[cfg_attr(not(feature = "force-exhaustive-all"), non_exhaustive)]
+pub struct QueryStats {
+    /// Statistics of query execution phases
+    pub query_phases: Vec<QueryPhaseStats>,
+
+    /// Statistics of query compilation, if the query was compiled
+    pub compilation: Option<CompilationStats>,
+
+    pub process_cpu_time: Duration,
+    pub total_duration: Duration,
+    pub total_cpu_time: Duration,
+
+    /// Plan of the query in json format, collected with [StatsMode::Full] and [StatsMode::Profile]
+    pub query_plan: String,
+
+    /// Abstract syntax tree of the query, collected with [StatsMode::Full] and [StatsMode::Profile]
+    pub query_ast: String,
+}
+
+impl QueryStats {
+    /// Parse query plan from the statistics
+    pub fn plan(&self) -> YdbResult<QueryPlan> {
+        QueryPlan::parse(self.query_ast.clone(), self.query_plan.clone())
+    }
+}
+
+impl From<RawQueryStats> for QueryStats {
+    fn from(value: RawQueryStats) -> Self {
+        Self {
+            query_phases: value.query_phases.into_iter().map(Into::into).collect(),
+            compilation: value.compilation.map(Into::into),
+            process_cpu_time: value.process_cpu_time,
+            total_duration: value.total_duration,
+            total_cpu_time: value.total_cpu_time,
+            query_plan: value.query_plan,
+            query_ast: value.query_ast,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(not(feature = "force-exhaustive-all"), non_exhaustive)]
+pub struct QueryPhaseStats {
+    pub duration: Duration,
+    pub cpu_time: Duration,
+
+    /// Tables, accessed in the phase
+    pub table_access: Vec<TableAccessStats>,
+    pub affected_shards: u64,
+    pub literal_phase: bool,
+}
+
+impl From<RawQueryPhaseStats> for QueryPhaseStats {
+    fn from(value: RawQueryPhaseStats) -> Self {
+        Self {
+            duration: value.duration,
+            cpu_time: value.cpu_time,
+            table_access: value.table_access.into_iter().map(Into::into).collect(),
+            affected_shards: value.affected_shards,
+            literal_phase: value.literal_phase,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(not(feature = "force-exhaustive-all"), non_exhaustive)]
+pub struct TableAccessStats {
+    /// Table path
+    pub name: String,
+    pub reads: OperationStats,
+    pub updates: OperationStats,
+    pub deletes: OperationStats,
+    pub partitions_count: u64,
+}
+
+impl From<RawTableAccessStats> for TableAccessStats {
+    fn from(value: RawTableAccessStats) -> Self {
+        Self {
+            name: value.name,
+            reads: value.reads.into(),
+            updates: value.updates.into(),
+            deletes: value.deletes.into(),
+            partitions_count: value.partitions_count,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(not(feature = "force-exhaustive-all"), non_exhaustive)]
+pub struct OperationStats {
+    pub rows: u64,
+    pub bytes: u64,
+}
+
+impl From<RawOperationStats> for OperationStats {
+    fn from(value: RawOperationStats) -> Self {
+        Self {
+            rows: value.rows,
+            bytes: value.bytes,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(not(feature = "force-exhaustive-all"), non_exhaustive)]
+pub struct CompilationStats {
+    /// Query got from compilation cache
+    pub from_cache: bool,
+    pub duration: Duration,
+    pub cpu_time: Duration,
+}
+
+impl From<RawCompilationStats> for CompilationStats {
+    fn from(value: RawCompilationStats) -> Self {
+        Self {
+            from_cache: value.from_cache,
+            duration: value.duration,
+            cpu_time: value.cpu_time,
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use crate::errors::YdbResult;
+use crate::grpc_wrapper::raw_table_service::query_stats::RawQueryStats;
+use crate::query_stats::QueryStats;
+use std::time::Duration;
+use ydb_grpc::ydb_proto::table_stats;
+
+#[test]
+fn query_stats_from_proto() -> YdbResult<()> {
+    let proto = table_stats::QueryStats {
+        query_phases: vec![table_stats::QueryPhaseStats {
+            duration_us: 1500,
+            table_access: vec![table_stats::TableAccessStats {
+                name: "/local/series".to_string(),
+                reads: Some(table_stats::OperationStats {
+                    rows: 10,
+                    bytes: 100,
+                }),
+                updates: None,
+                deletes: Some(table_stats::OperationStats { rows: 1, bytes: 8 }),
+                partitions_count: 2,
+            }],
+            cpu_time_us: 700,
+            affected_shards: 2,
+            literal_phase: false,
+        }],
+        compilation: Some(table_stats::CompilationStats {
+            from_cache: true,
+            duration_us: 20,
+            cpu_time_us: 10,
+        }),
+        process_cpu_time_us: 300,
+        query_plan: r#"{"tables": [{"name": "/local/series"}]}"#.to_string(),
+        query_ast: "(return)".to_string(),
+        total_duration_us: 2000,
+        total_cpu_time_us: 1000,
+    };
+
+    let stats = QueryStats::from(RawQueryStats::from(proto));
+    assert_eq!(Duration::from_millis(2), stats.total_duration);
+    assert_eq!(Duration::from_millis(1), stats.total_cpu_time);
+    assert_eq!(Duration::from_micros(300), stats.process_cpu_time);
+
+    let phase = &stats.query_phases[0];
+    assert_eq!(Duration::from_micros(1500), phase.duration);
+    assert_eq!(Duration::from_micros(700), phase.cpu_time);
+    assert_eq!(2, phase.affected_shards);
+
+    let table = &phase.table_access[0];
+    assert_eq!("/local/series", table.name);
+    assert_eq!(10, table.reads.rows);
+    assert_eq!(100, table.reads.bytes);
+    assert_eq!(0, table.updates.rows);
+    assert_eq!(1, table.deletes.rows);
+    assert_eq!(2, table.partitions_count);
+
+    let compilation = stats.compilation.as_ref().unwrap();
+    assert!(compilation.from_cache);
+    assert_eq!(Duration::from_micros(20), compilation.duration);
+
+    let plan = stats.plan()?;
+    assert_eq!("(return)", plan.ast);
+    assert_eq!("/local/series", plan.tables[0].name);
+    Ok(())
+}
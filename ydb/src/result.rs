@@ -1,13 +1,29 @@
+#[cfg(test)]
+#[path = "result_test.rs"]
+mod result_test;
+
+use crate::client_query::session::QuerySession;
 use crate::errors;
 use crate::errors::{YdbError, YdbResult, YdbStatusError};
 use crate::grpc::proto_issues_to_ydb_issues;
+use crate::grpc_wrapper::raw_query_service::execute_query::{
+    RawExecuteQueryResponsePart, RawExecuteQueryStream,
+};
 use crate::grpc_wrapper::raw_table_service::execute_data_query::RawExecuteDataQueryResult;
-use crate::grpc_wrapper::raw_table_service::value::{RawResultSet, RawTypedValue, RawValue};
+use crate::grpc_wrapper::raw_table_service::read_table::RawReadTableStream;
+use crate::grpc_wrapper::raw_table_service::value::{
+    RawColumn, RawResultSet, RawTypedValue, RawValue,
+};
+use crate::query_stats::QueryStats;
+use crate::session::Session;
 use crate::trace_helpers::ensure_len_string;
-use crate::types::Value;
+use crate::types::{Column, Value, ValueStruct};
+use futures_util::Stream;
 use itertools::Itertools;
 use std::collections::HashMap;
+use std::pin::Pin;
 use std::sync::Arc;
+use std::task::{ready, Context, Poll};
 use std::vec::IntoIter;
 use tracing::trace;
 use ydb_grpc::ydb_proto::status_ids::StatusCode;
@@ -17,6 +33,7 @@ use ydb_grpc::ydb_proto::table::ExecuteScanQueryPartialResponse;
 pub struct QueryResult {
     pub(crate) results: Vec<ResultSet>,
     pub(crate) tx_id: String,
+    pub(crate) stats: Option<QueryStats>,
 }
 
 impl QueryResult {
@@ -45,9 +62,15 @@ impl QueryResult {
         Ok(QueryResult {
             results,
             tx_id: raw_res.tx_meta.id,
+            stats: raw_res.query_stats.map(Into::into),
         })
     }
 
+    /// Execution statistics of the query, if it was requested by [Query::with_stats](crate::Query::with_stats)
+    pub fn stats(&self) -> Option<QueryStats> {
+        self.stats.clone()
+    }
+
     pub fn into_only_result(self) -> YdbResult<ResultSet> {
         let mut iter = self.results.into_iter();
         match iter.next() {
@@ -80,14 +103,14 @@ impl QueryResult {
 
 #[derive(Debug)]
 pub struct ResultSet {
-    columns: Vec<crate::types::Column>,
+    columns: Vec<Column>,
     columns_by_name: HashMap<String, usize>,
     raw_result_set: RawResultSet,
 }
 
 impl ResultSet {
-    #[allow(dead_code)]
-    pub(crate) fn columns(&self) -> &Vec<crate::types::Column> {
+    /// Columns of the result set with names and types
+    pub fn columns(&self) -> &[Column] {
         &self.columns
     }
 
@@ -99,6 +122,87 @@ impl ResultSet {
         }
     }
 
+    /// Rows of the result set, converted to T, for example struct with `#[derive(YdbRow)]`
+    ///
+    /// Example:
+    /// ```no_run
+    /// # use ydb::{YdbResult, YdbRow};
+    /// # #[tokio::main]
+    /// # async fn main() -> YdbResult<()> {
+    /// # let client = ydb::ClientBuilder::new_from_connection_string("grpc://localhost:2136?database=/local")?.client()?;
+    /// #[derive(YdbRow)]
+    /// struct Series {
+    ///     id: u64,
+    ///     #[ydb(rename = "title")]
+    ///     name: String,
+    ///     info: Option<String>,
+    /// }
+    ///
+    /// let result_set = client
+    ///     .table_client()
+    ///     .retry_transaction(|mut t| async move {
+    ///         Ok(t.query("SELECT id, title, info FROM series".into()).await?)
+    ///     })
+    ///     .await?
+    ///     .into_only_result()?;
+    ///
+    /// for series in result_set.into_typed::<Series>() {
+    ///     let series = series?;
+    ///     println!("{}: {}", series.id, series.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn into_typed<T>(self) -> impl Iterator<Item = YdbResult<T>>
+    where
+        T: TryFrom<Row, Error = YdbError>,
+    {
+        self.rows().map(T::try_from)
+    }
+
+    /// Rows of the result set, deserialized by serde to T, see [crate::from_row]
+    ///
+    /// Example:
+    /// ```no_run
+    /// # use ydb::YdbResult;
+    /// # fn example(result_set: ydb::ResultSet) -> YdbResult<()> {
+    /// #[derive(serde::Deserialize)]
+    /// struct Series {
+    ///     id: u64,
+    ///     title: String,
+    ///     info: Option<String>,
+    /// }
+    ///
+    /// let series: Vec<Series> = result_set.into_deserialized().collect::<YdbResult<_>>()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn into_deserialized<T>(self) -> impl Iterator<Item = YdbResult<T>>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        self.rows().map(crate::from_row)
+    }
+
+    /// Convert the result set to Arrow RecordBatch, available with `arrow` feature
+    ///
+    /// Columns of primitive types converted to Arrow arrays of same types, Optional columns
+    /// converted to nullable arrays:
+    /// - Date, Date32 - Date32
+    /// - Datetime, Datetime64 - Timestamp with seconds
+    /// - Timestamp, Timestamp64 - Timestamp with microseconds
+    /// - Interval, Interval64 - Duration with microseconds
+    /// - Decimal - Decimal128
+    /// - Uuid - FixedSizeBinary(16)
+    /// - Text, Json, JsonDocument, DyNumber and types with timezone - Utf8
+    /// - Bytes, Yson - Binary
+    ///
+    /// Containers (List, Struct, Dict, etc.) are unsupported and return error.
+    #[cfg(feature = "arrow")]
+    pub fn to_record_batch(&self) -> YdbResult<arrow_array::RecordBatch> {
+        crate::types_arrow::record_batch_from_rows(&self.columns, &self.raw_result_set.rows)
+    }
+
     #[allow(dead_code)]
     pub(crate) fn truncated(&self) -> bool {
         self.raw_result_set.truncated
@@ -138,12 +242,74 @@ impl IntoIterator for ResultSet {
 
 #[derive(Debug)]
 pub struct Row {
-    columns: Arc<Vec<crate::types::Column>>,
+    columns: Arc<Vec<Column>>,
     columns_by_name: Arc<HashMap<String, usize>>,
     raw_values: HashMap<usize, RawValue>,
 }
 
 impl Row {
+    /// Columns of the row, include removed fields
+    pub fn columns(&self) -> &[Column] {
+        &self.columns
+    }
+
+    /// Count of columns of the row
+    pub fn len(&self) -> usize {
+        self.columns.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.columns.is_empty()
+    }
+
+    /// Get field by column name or index, without remove it from the row
+    ///
+    /// Panics if the row has no the field or the field can't be converted to T, use [Row::try_get]
+    /// for handle errors.
+    ///
+    /// Example:
+    /// ```no_run
+    /// # fn example(row: ydb::Row) {
+    /// let id: u64 = row.get("id");
+    /// let title: Option<String> = row.get(1);
+    /// # }
+    /// ```
+    pub fn get<T, I>(&self, index: I) -> T
+    where
+        T: TryFrom<Value, Error = YdbError>,
+        I: RowIndex,
+    {
+        match self.try_get(index) {
+            Ok(value) => value,
+            Err(err) => panic!("failed to get field from row: {err}"),
+        }
+    }
+
+    /// Get field by column name or index, without remove it from the row
+    pub fn try_get<T, I>(&self, index: I) -> YdbResult<T>
+    where
+        T: TryFrom<Value, Error = YdbError>,
+        I: RowIndex,
+    {
+        let index = index.column_index(self)?;
+        match self.raw_values.get(&index) {
+            Some(val) => T::try_from(Value::try_from(RawTypedValue {
+                r#type: self.columns[index].v_type.clone(),
+                value: val.clone(),
+            })?)
+            .map_err(|err| {
+                YdbError::Convert(format!(
+                    "failed to convert field '{}': {err}",
+                    self.columns[index].name
+                ))
+            }),
+            None => Err(YdbError::Custom(format!(
+                "field '{}' was removed from the row",
+                self.columns[index].name
+            ))),
+        }
+    }
+
     pub fn remove_field_by_name(&mut self, name: &str) -> errors::YdbResult<Value> {
         if let Some(&index) = self.columns_by_name.get(name) {
             return self.remove_field(index);
@@ -151,6 +317,12 @@ impl Row {
         Err(YdbError::Custom("field not found".into()))
     }
 
+    pub(crate) fn has_field(&self, name: &str) -> bool {
+        self.columns_by_name
+            .get(name)
+            .is_some_and(|index| self.raw_values.contains_key(index))
+    }
+
     pub fn remove_field(&mut self, index: usize) -> errors::YdbResult<Value> {
         match self.raw_values.remove(&index) {
             Some(val) => Ok(Value::try_from(RawTypedValue {
@@ -160,10 +332,56 @@ impl Row {
             None => Err(YdbError::Custom("it has no the field".into())),
         }
     }
+
+    // all present fields of the row in columns order
+    pub(crate) fn into_value_struct(mut self) -> YdbResult<ValueStruct> {
+        let mut value = ValueStruct::with_capacity(self.raw_values.len());
+        for index in 0..self.columns.len() {
+            if self.raw_values.contains_key(&index) {
+                let field = self.remove_field(index)?;
+                value.insert(self.columns[index].name.clone(), field);
+            }
+        }
+        Ok(value)
+    }
+}
+
+/// Index of field in [Row]: column name or number of column
+pub trait RowIndex {
+    #[doc(hidden)]
+    fn column_index(&self, row: &Row) -> YdbResult<usize>;
+}
+
+impl RowIndex for usize {
+    fn column_index(&self, row: &Row) -> YdbResult<usize> {
+        if *self < row.columns.len() {
+            Ok(*self)
+        } else {
+            Err(YdbError::Custom(format!(
+                "column index {self} out of range, row has {} columns",
+                row.columns.len()
+            )))
+        }
+    }
+}
+
+impl RowIndex for &str {
+    fn column_index(&self, row: &Row) -> YdbResult<usize> {
+        match row.columns_by_name.get(*self) {
+            Some(&index) => Ok(index),
+            None => Err(YdbError::Custom(format!("row has no column '{self}'"))),
+        }
+    }
+}
+
+impl RowIndex for &String {
+    fn column_index(&self, row: &Row) -> YdbResult<usize> {
+        self.as_str().column_index(row)
+    }
 }
 
 pub struct ResultSetRowsIter {
-    columns: Arc<Vec<crate::types::Column>>,
+    columns: Arc<Vec<Column>>,
     columns_by_name: Arc<HashMap<String, usize>>,
     row_iter: IntoIter<Vec<RawValue>>,
 }
@@ -215,3 +433,226 @@ impl StreamResult {
         Ok(Some(result_set))
     }
 }
+
+/// Result of query service, which read from server by parts
+///
+/// The stream yields `(result_set_index, ResultSet)` for every part of result as it arrives from server.
+/// Next part read from network only when previous was consumed, so memory usage doesn't depend
+/// on size of result.
+///
+/// Example:
+/// ```no_run
+/// # use ydb::YdbResult;
+/// #
+/// # #[tokio::main]
+/// # async fn main()->YdbResult<()>{
+/// #   use futures_util::StreamExt;
+/// #   use ydb::Query;
+/// #   let query_client = ydb::ClientBuilder::new_from_connection_string("")?.client()?.query_client();
+///     let mut stream = query_client
+///         .retry_query_stream(Query::new("SELECT * FROM big_table"))
+///         .await?;
+///     while let Some(part) = stream.next().await {
+///         let (result_set_index, result_set) = part?;
+///         for row in result_set.rows() {
+///             println!("result set: {result_set_index}, row: {row:?}");
+///         }
+///     }
+/// #     return Ok(());
+/// # }
+/// ```
+pub struct QueryResultStream {
+    session: QuerySession,
+    stream: RawExecuteQueryStream,
+
+    // server send columns with first part of every result set only
+    columns: HashMap<usize, Vec<RawColumn>>,
+    finished: bool,
+    stats: Option<QueryStats>,
+}
+
+impl QueryResultStream {
+    pub(crate) fn new(session: QuerySession, stream: RawExecuteQueryStream) -> Self {
+        Self {
+            session,
+            stream,
+            columns: HashMap::new(),
+            finished: false,
+            stats: None,
+        }
+    }
+
+    /// Execution statistics of the query, if it was requested by [Query::with_stats](crate::Query::with_stats)
+    ///
+    /// Server send statistics with last part of result, so it available after the stream finished.
+    pub fn stats(&self) -> Option<QueryStats> {
+        self.stats.clone()
+    }
+
+    fn handle_part(
+        &mut self,
+        part: RawExecuteQueryResponsePart,
+    ) -> YdbResult<Option<(usize, ResultSet)>> {
+        if let Some(exec_stats) = part.exec_stats {
+            self.stats = Some(exec_stats.into());
+        }
+
+        let Some(mut raw_result_set) = part.result_set else {
+            return Ok(None);
+        };
+
+        let index = part.result_set_index;
+        if raw_result_set.columns.is_empty() {
+            if let Some(columns) = self.columns.get(&index) {
+                raw_result_set.columns.clone_from(columns);
+            }
+        } else {
+            self.columns.insert(index, raw_result_set.columns.clone());
+        }
+
+        Ok(Some((index, ResultSet::try_from(raw_result_set)?)))
+    }
+}
+
+impl Stream for QueryResultStream {
+    type Item = YdbResult<(usize, ResultSet)>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            if this.finished {
+                return Poll::Ready(None);
+            }
+
+            let part = match ready!(Pin::new(&mut this.stream).poll_next(cx)) {
+                Some(Ok(part)) => part,
+                Some(Err(err)) => {
+                    this.finished = true;
+                    let err = YdbError::from(err);
+                    this.session.handle_error(&err);
+                    return Poll::Ready(Some(Err(err)));
+                }
+                None => {
+                    this.finished = true;
+                    return Poll::Ready(None);
+                }
+            };
+
+            match this.handle_part(part) {
+                Ok(Some(item)) => return Poll::Ready(Some(Ok(item))),
+                Ok(None) => continue,
+                Err(err) => return Poll::Ready(Some(Err(err))),
+            }
+        }
+    }
+}
+
+impl Drop for QueryResultStream {
+    fn drop(&mut self) {
+        // query may be still in progress on server side, the session can't be reused
+        if !self.finished {
+            self.session.can_pooled = false;
+        }
+    }
+}
+
+/// Rows of table, which read from server by parts
+///
+/// The stream yields [ResultSet] for every part of the table as it arrives from server.
+/// Next part read from network only when previous was consumed.
+///
+/// Example:
+/// ```no_run
+/// # use ydb::YdbResult;
+/// #
+/// # #[tokio::main]
+/// # async fn main()->YdbResult<()>{
+/// #   use futures_util::StreamExt;
+/// #   use ydb::{KeyBound, KeyRangeBuilder, ReadTableOptionsBuilder, Value};
+/// #   let table_client = ydb::ClientBuilder::new_from_connection_string("")?.client()?.table_client();
+///     let mut stream = table_client
+///         .read_table(
+///             "my_table".to_string(),
+///             ReadTableOptionsBuilder::default()
+///                 .column("id")
+///                 .key_range(
+///                     KeyRangeBuilder::default()
+///                         // key column `id Int64` is nullable, so bound has type Optional<Int64>
+///                         .from(KeyBound::inclusive(vec![Value::from(Some(100_i64))]))
+///                         .build()?,
+///                 )
+///                 .ordered(true)
+///                 .build()?,
+///         )
+///         .await?;
+///     while let Some(result_set) = stream.next().await {
+///         for row in result_set?.rows() {
+///             println!("row: {row:?}");
+///         }
+///     }
+/// #     return Ok(());
+/// # }
+/// ```
+pub struct ReadTableStream {
+    session: Session,
+    stream: RawReadTableStream,
+    columns: Vec<RawColumn>,
+    finished: bool,
+}
+
+impl ReadTableStream {
+    pub(crate) fn new(session: Session, stream: RawReadTableStream) -> Self {
+        Self {
+            session,
+            stream,
+            columns: Vec::new(),
+            finished: false,
+        }
+    }
+}
+
+impl Stream for ReadTableStream {
+    type Item = YdbResult<ResultSet>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            if this.finished {
+                return Poll::Ready(None);
+            }
+
+            let part = match ready!(Pin::new(&mut this.stream).poll_next(cx)) {
+                Some(Ok(part)) => part,
+                Some(Err(err)) => {
+                    this.finished = true;
+                    let err = YdbError::from(err);
+                    this.session.handle_error(&err);
+                    return Poll::Ready(Some(Err(err)));
+                }
+                None => {
+                    this.finished = true;
+                    return Poll::Ready(None);
+                }
+            };
+
+            let Some(mut raw_result_set) = part.result_set else {
+                continue;
+            };
+            if raw_result_set.columns.is_empty() {
+                raw_result_set.columns.clone_from(&this.columns);
+            } else {
+                this.columns.clone_from(&raw_result_set.columns);
+            }
+            return Poll::Ready(Some(ResultSet::try_from(raw_result_set)));
+        }
+    }
+}
+
+impl Drop for ReadTableStream {
+    fn drop(&mut self) {
+        // read may be still in progress on server side, the session can't be reused
+        if !self.finished {
+            self.session.can_pooled = false;
+        }
+    }
+}
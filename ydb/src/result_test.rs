@@ -0,0 +1,72 @@
+use crate::errors::YdbResult;
+use crate::test_helpers::make_result_set;
+use crate::Value;
+
+#[test]
+fn columns() -> YdbResult<()> {
+    let result_set = make_result_set(
+        vec![
+            ("id", Value::Uint64(0)),
+            ("title", Value::from(None::<String>)),
+        ],
+        vec![],
+    )?;
+
+    let columns = result_set.columns();
+    assert_eq!(
+        vec!["id", "title"],
+        columns.iter().map(|c| c.name()).collect::<Vec<_>>()
+    );
+    assert_eq!(Value::Uint64(0), columns[0].type_example()?);
+    assert_eq!(Value::from(None::<String>), columns[1].type_example()?);
+    Ok(())
+}
+
+#[test]
+fn row_get() -> YdbResult<()> {
+    let result_set = make_result_set(
+        vec![
+            ("id", Value::Uint64(0)),
+            ("title", Value::from(None::<String>)),
+        ],
+        vec![vec![
+            Value::Uint64(1),
+            Value::from(Some("first".to_string())),
+        ]],
+    )?;
+    let mut row = result_set.rows().next().unwrap();
+
+    assert_eq!(2, row.len());
+    assert_eq!("title", row.columns()[1].name());
+
+    // fields can be read many times
+    assert_eq!(1_u64, row.get::<u64, _>("id"));
+    assert_eq!(1_u64, row.get::<u64, _>(0));
+    assert_eq!(
+        Some("first".to_string()),
+        row.try_get::<Option<String>, _>(1)?
+    );
+    assert_eq!(
+        Some("first".to_string()),
+        row.try_get::<Option<String>, _>(&"title".to_string())?
+    );
+
+    assert!(row.try_get::<u64, _>("unknown").is_err());
+    assert!(row.try_get::<u64, _>(2).is_err());
+    let err = row.try_get::<String, _>("id").unwrap_err();
+    assert!(err.to_string().contains("'id'"), "{err}");
+
+    assert_eq!(Value::Uint64(1), row.remove_field_by_name("id")?);
+    assert!(row.try_get::<u64, _>("id").is_err());
+    assert_eq!(2, row.len());
+    Ok(())
+}
+
+#[test]
+#[should_panic(expected = "unknown")]
+fn row_get_panics() {
+    let result_set =
+        make_result_set(vec![("id", Value::Uint64(0))], vec![vec![Value::Uint64(1)]]).unwrap();
+    let row = result_set.rows().next().unwrap();
+    let _: u64 = row.get("unknown");
+}
@@ -0,0 +1,388 @@
+#[cfg(test)]
+#[path = "retry_test.rs"]
+mod retry_test;
+
+use crate::errors::{YdbError, YdbOrCustomerError, YdbResult, YdbResultWithCustomerErr};
+use rand::Rng;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::time::sleep;
+use tracing::{instrument, trace};
+
+pub(crate) const DEFAULT_RETRY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Kind of backoff for retriable error
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BackoffType {
+    /// Temporary error, which can be resolved quickly: Aborted, BadSession, Unavailable, etc.
+    Fast,
+
+    /// Server is overloaded: Overloaded, ResourceExhausted
+    Slow,
+}
+
+/// Information about failed attempt for retry decision
+#[derive(Clone, Debug)]
+#[cfg_attr(not(feature = "force-exhaustive-all"), non_exhaustive)]
+pub struct RetryParams {
+    /// Number of failed attempts, start from 1
+    pub attempt: usize,
+
+    /// Time from start of first attempt
+    pub time_from_start: Duration,
+
+    /// Backoff kind for the attempt error
+    pub backoff: BackoffType,
+}
+
+/// Retry decision, returned by [Retry] policy
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(not(feature = "force-exhaustive-all"), non_exhaustive)]
+pub struct RetryDecision {
+    pub allow_retry: bool,
+    pub wait_timeout: Duration,
+}
+
+impl RetryDecision {
+    /// Retry after wait timeout
+    pub fn retry_after(wait_timeout: Duration) -> Self {
+        Self {
+            allow_retry: true,
+            wait_timeout,
+        }
+    }
+
+    /// Stop retries and return the error
+    pub fn stop() -> Self {
+        Self::default()
+    }
+}
+
+/// Retry policy
+///
+/// The policy called after every failed attempt with retriable error and decide - retry
+/// the operation or return the error. SDK checks if the error is retriable before the call,
+/// non retriable errors returned without call the policy.
+///
+/// Built-in policies: [BackoffRetrier] (default) and [NoRetrier].
+///
+/// Example:
+/// ```
+/// # use std::time::Duration;
+/// # use ydb::{Retry, RetryDecision, RetryParams};
+/// // Retry three times with constant delay
+/// struct ConstantRetrier;
+///
+/// impl Retry for ConstantRetrier {
+///     fn wait_duration(&self, params: RetryParams) -> RetryDecision {
+///         if params.attempt < 3 {
+///             RetryDecision::retry_after(Duration::from_millis(100))
+///         } else {
+///             RetryDecision::stop()
+///         }
+///     }
+/// }
+/// ```
+pub trait Retry: Send + Sync {
+    fn wait_duration(&self, params: RetryParams) -> RetryDecision;
+}
+
+/// Exponential backoff: wait between attempts is random in range from zero to
+/// `min(cap, base * 2^(attempt-1))` (full jitter)
+#[derive(Clone, Copy, Debug)]
+pub struct Backoff {
+    base: Duration,
+    cap: Duration,
+}
+
+impl Backoff {
+    pub fn new(base: Duration, cap: Duration) -> Self {
+        Self { base, cap }
+    }
+
+    // max wait before the attempt, without jitter
+    fn ceiling(&self, attempt: usize) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(31) as u32;
+        self.base
+            .checked_mul(1 << exponent)
+            .map_or(self.cap, |wait| wait.min(self.cap))
+    }
+
+    fn wait_duration(&self, attempt: usize) -> Duration {
+        let ceiling = self.ceiling(attempt);
+        if ceiling.is_zero() {
+            return ceiling;
+        }
+        rand::thread_rng().gen_range(Duration::ZERO..=ceiling)
+    }
+}
+
+/// Default retry policy: exponential backoff with full jitter, max attempts and deadline
+///
+/// Fast backoff used for temporary errors (Aborted, BadSession, etc.), slow backoff - when
+/// server is overloaded (Overloaded, ResourceExhausted).
+///
+/// Example:
+/// ```no_run
+/// # use std::time::Duration;
+/// # use ydb::{BackoffRetrier, YdbResult};
+/// # fn main() -> YdbResult<()> {
+/// # let client = ydb::ClientBuilder::new_from_connection_string("grpc://localhost:2136?database=/local")?.client()?;
+/// let table_client = client.table_client().clone_with_retry_policy(
+///     BackoffRetrier::new()
+///         .with_deadline(Duration::from_secs(30))
+///         .with_max_attempts(10),
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct BackoffRetrier {
+    fast: Backoff,
+    slow: Backoff,
+    max_attempts: Option<usize>,
+    deadline: Duration,
+}
+
+impl BackoffRetrier {
+    /// Policy with fast backoff from 5ms to 500ms, slow backoff from 50ms to 5s,
+    /// deadline 5s and without attempts limit
+    pub fn new() -> Self {
+        Self {
+            fast: Backoff::new(Duration::from_millis(5), Duration::from_millis(500)),
+            slow: Backoff::new(Duration::from_millis(50), Duration::from_secs(5)),
+            max_attempts: None,
+            deadline: DEFAULT_RETRY_TIMEOUT,
+        }
+    }
+
+    /// Set backoff for temporary errors
+    pub fn with_fast_backoff(mut self, backoff: Backoff) -> Self {
+        self.fast = backoff;
+        self
+    }
+
+    /// Set backoff for overloaded server errors
+    pub fn with_slow_backoff(mut self, backoff: Backoff) -> Self {
+        self.slow = backoff;
+        self
+    }
+
+    /// Set max count of attempts, include first
+    pub fn with_max_attempts(mut self, max_attempts: usize) -> Self {
+        self.max_attempts = Some(max_attempts);
+        self
+    }
+
+    /// Set max time from start of first attempt, next attempt doesn't start after the deadline
+    pub fn with_deadline(mut self, deadline: Duration) -> Self {
+        self.deadline = deadline;
+        self
+    }
+}
+
+impl Default for BackoffRetrier {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Retry for BackoffRetrier {
+    #[instrument(ret)]
+    fn wait_duration(&self, params: RetryParams) -> RetryDecision {
+        if self
+            .max_attempts
+            .is_some_and(|max_attempts| params.attempt >= max_attempts)
+        {
+            return RetryDecision::stop();
+        }
+
+        let backoff = match params.backoff {
+            BackoffType::Fast => &self.fast,
+            BackoffType::Slow => &self.slow,
+        };
+        let wait_timeout = backoff.wait_duration(params.attempt);
+        if params.time_from_start + wait_timeout < self.deadline {
+            RetryDecision::retry_after(wait_timeout)
+        } else {
+            RetryDecision::stop()
+        }
+    }
+}
+
+/// Policy without retries
+#[derive(Clone, Debug, Default)]
+pub struct NoRetrier {}
+
+impl Retry for NoRetrier {
+    #[instrument(skip_all)]
+    fn wait_duration(&self, _: RetryParams) -> RetryDecision {
+        RetryDecision::stop()
+    }
+}
+
+/// Retry budget, shared by all retry loops of a [Client](crate::Client)
+///
+/// The budget is token bucket: every operation deposit `retry_ratio` tokens, every retry
+/// of any error withdraw one token. Bucket starts full and contains no more than `max_tokens`,
+/// so retries limited by `retry_ratio` part of operations plus burst of `max_tokens` retries.
+/// When the budget is exhausted - operation return last error without retries and doesn't
+/// amplify load of overloaded or unavailable cluster.
+///
+/// Default budget allow 10% of extra requests with burst of 10 retries.
+///
+/// Example:
+/// ```no_run
+/// # use ydb::{ClientBuilder, RetryBudget, YdbResult};
+/// # fn main() -> YdbResult<()> {
+/// let client = ClientBuilder::new_from_connection_string("grpc://localhost:2136?database=/local")?
+///     .with_retry_budget(RetryBudget::new(0.2, 100.0)?)
+///     .client()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct RetryBudget {
+    // None for unlimited budget
+    bucket: Option<Arc<Mutex<TokenBucket>>>,
+}
+
+#[derive(Debug)]
+struct TokenBucket {
+    tokens: f64,
+    max_tokens: f64,
+    retry_ratio: f64,
+}
+
+impl RetryBudget {
+    /// Budget with `retry_ratio` retries per operation and burst up to `max_tokens` retries
+    ///
+    /// Both arguments must be finite and non-negative.
+    pub fn new(retry_ratio: f64, max_tokens: f64) -> YdbResult<Self> {
+        for (name, value) in [("retry_ratio", retry_ratio), ("max_tokens", max_tokens)] {
+            if !value.is_finite() || value < 0.0 {
+                return Err(YdbError::Custom(format!(
+                    "retry budget {name} must be finite and non-negative, got: {value}"
+                )));
+            }
+        }
+        Ok(Self::new_unchecked(retry_ratio, max_tokens))
+    }
+
+    fn new_unchecked(retry_ratio: f64, max_tokens: f64) -> Self {
+        Self {
+            bucket: Some(Arc::new(Mutex::new(TokenBucket {
+                tokens: max_tokens,
+                max_tokens,
+                retry_ratio,
+            }))),
+        }
+    }
+
+    /// Budget without limits, retries limited by retry policies only
+    pub fn unlimited() -> Self {
+        Self { bucket: None }
+    }
+
+    // called once per operation, before first attempt
+    pub(crate) fn deposit(&self) {
+        if let Some(bucket) = &self.bucket {
+            let mut bucket = bucket.lock().unwrap();
+            bucket.tokens = (bucket.tokens + bucket.retry_ratio).min(bucket.max_tokens);
+        }
+    }
+
+    // called before every retry, return false if the budget is exhausted
+    pub(crate) fn try_withdraw(&self) -> bool {
+        let Some(bucket) = &self.bucket else {
+            return true;
+        };
+
+        let mut bucket = bucket.lock().unwrap();
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+impl Default for RetryBudget {
+    fn default() -> Self {
+        Self::new_unchecked(0.1, 10.0)
+    }
+}
+
+// Call callback while it return retriable errors and the policy and the budget allow retry
+pub(crate) async fn retry_with_policy<CallbackFuture, CallbackResult>(
+    policy: &dyn Retry,
+    budget: &RetryBudget,
+    idempotent_operation: bool,
+    callback: impl Fn() -> CallbackFuture,
+) -> YdbResultWithCustomerErr<CallbackResult>
+where
+    CallbackFuture: Future<Output = YdbResultWithCustomerErr<CallbackResult>>,
+{
+    let mut attempts: usize = 0;
+    let start = Instant::now();
+    budget.deposit();
+    loop {
+        attempts += 1;
+        trace!("attempt: {}", attempts);
+
+        let err = match callback().await {
+            Ok(res) => {
+                trace!("return successfully after '{}' attempts", attempts);
+                return Ok(res);
+            }
+            Err(err) => err,
+        };
+
+        let backoff = match &err {
+            YdbOrCustomerError::YDB(e) if e.is_retryable(idempotent_operation) => e.backoff_type(),
+            _ => return Err(err),
+        };
+
+        let decision = policy.wait_duration(RetryParams {
+            attempt: attempts,
+            time_from_start: start.elapsed(),
+            backoff,
+        });
+        if !decision.allow_retry {
+            trace!(
+                "return with ydb error after '{}' attempts by retry decision: {}",
+                attempts,
+                err
+            );
+            return Err(err);
+        }
+        if !budget.try_withdraw() {
+            trace!(
+                "return with ydb error after '{}' attempts, retry budget exhausted: {}",
+                attempts,
+                err
+            );
+            return Err(err);
+        }
+        sleep(decision.wait_timeout).await;
+    }
+}
+
+// Same as retry_with_policy, for callbacks without customer errors
+pub(crate) async fn retry_ydb_with_policy<CallbackFuture, CallbackResult>(
+    policy: &dyn Retry,
+    budget: &RetryBudget,
+    idempotent_operation: bool,
+    callback: impl Fn() -> CallbackFuture,
+) -> YdbResult<CallbackResult>
+where
+    CallbackFuture: Future<Output = YdbResult<CallbackResult>>,
+{
+    retry_with_policy(policy, budget, idempotent_operation, || async {
+        callback().await.map_err(YdbOrCustomerError::YDB)
+    })
+    .await
+    .map_err(YdbOrCustomerError::to_ydb_error)
+}
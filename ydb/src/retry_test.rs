@@ -0,0 +1,269 @@
+use super::*;
+use crate::errors::{YdbError, YdbStatusError};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use ydb_grpc::ydb_proto::status_ids::StatusCode;
+
+fn status_error(status: StatusCode) -> YdbError {
+    YdbError::YdbStatusError(YdbStatusError {
+        operation_status: status as i32,
+        ..YdbStatusError::default()
+    })
+}
+
+fn params(attempt: usize, time_from_start: Duration, backoff: BackoffType) -> RetryParams {
+    RetryParams {
+        attempt,
+        time_from_start,
+        backoff,
+    }
+}
+
+#[test]
+fn backoff_ceiling() {
+    let backoff = Backoff::new(Duration::from_millis(10), Duration::from_millis(100));
+    assert_eq!(Duration::from_millis(10), backoff.ceiling(1));
+    assert_eq!(Duration::from_millis(20), backoff.ceiling(2));
+    assert_eq!(Duration::from_millis(80), backoff.ceiling(4));
+    assert_eq!(Duration::from_millis(100), backoff.ceiling(5));
+    assert_eq!(Duration::from_millis(100), backoff.ceiling(1000));
+
+    for attempt in 1..10 {
+        assert!(backoff.wait_duration(attempt) <= backoff.ceiling(attempt));
+    }
+}
+
+#[test]
+fn backoff_retrier() {
+    let hour = Duration::from_secs(3600);
+    let retrier = BackoffRetrier::new()
+        .with_fast_backoff(Backoff::new(
+            Duration::from_millis(1),
+            Duration::from_millis(1),
+        ))
+        .with_slow_backoff(Backoff::new(hour, hour))
+        .with_deadline(hour * 2)
+        .with_max_attempts(3);
+
+    let decision = retrier.wait_duration(params(1, Duration::ZERO, BackoffType::Fast));
+    assert!(decision.allow_retry);
+    assert!(decision.wait_timeout <= Duration::from_millis(1));
+
+    let decision = retrier.wait_duration(params(2, Duration::ZERO, BackoffType::Slow));
+    assert!(decision.allow_retry);
+    assert!(decision.wait_timeout <= hour);
+
+    // max attempts
+    assert!(
+        !retrier
+            .wait_duration(params(3, Duration::ZERO, BackoffType::Fast))
+            .allow_retry
+    );
+
+    // deadline
+    assert!(
+        !retrier
+            .wait_duration(params(1, hour * 2, BackoffType::Fast))
+            .allow_retry
+    );
+
+    assert!(
+        !NoRetrier {}
+            .wait_duration(params(1, Duration::ZERO, BackoffType::Fast))
+            .allow_retry
+    );
+}
+
+#[test]
+fn backoff_type_by_status() {
+    assert_eq!(
+        BackoffType::Fast,
+        status_error(StatusCode::Aborted).backoff_type()
+    );
+    assert_eq!(
+        BackoffType::Fast,
+        status_error(StatusCode::BadSession).backoff_type()
+    );
+    assert_eq!(
+        BackoffType::Slow,
+        status_error(StatusCode::Overloaded).backoff_type()
+    );
+    assert_eq!(
+        BackoffType::Slow,
+        YdbError::from(tonic::Status::resource_exhausted("")).backoff_type()
+    );
+}
+
+#[tokio::test]
+async fn retry_attempts() {
+    let policy = BackoffRetrier::new().with_max_attempts(3);
+    let budget = RetryBudget::unlimited();
+
+    // retriable error retried up to max attempts
+    let attempts = AtomicUsize::new(0);
+    let res: YdbResultWithCustomerErr<()> = retry_with_policy(&policy, &budget, false, || async {
+        attempts.fetch_add(1, Ordering::Relaxed);
+        Err(YdbOrCustomerError::YDB(status_error(StatusCode::Aborted)))
+    })
+    .await;
+    assert!(res.is_err());
+    assert_eq!(3, attempts.load(Ordering::Relaxed));
+
+    // success after retry
+    let attempts = AtomicUsize::new(0);
+    let res = retry_with_policy(&policy, &budget, false, || async {
+        if attempts.fetch_add(1, Ordering::Relaxed) == 0 {
+            Err(YdbOrCustomerError::YDB(status_error(
+                StatusCode::BadSession,
+            )))
+        } else {
+            Ok(1)
+        }
+    })
+    .await;
+    assert_eq!(1, res.unwrap());
+    assert_eq!(2, attempts.load(Ordering::Relaxed));
+
+    // undetermined retried for idempotent operations only
+    for (idempotent, expected_attempts) in [(false, 1), (true, 3)] {
+        let attempts = AtomicUsize::new(0);
+        let res: YdbResultWithCustomerErr<()> =
+            retry_with_policy(&policy, &budget, idempotent, || async {
+                attempts.fetch_add(1, Ordering::Relaxed);
+                Err(YdbOrCustomerError::YDB(status_error(
+                    StatusCode::Undetermined,
+                )))
+            })
+            .await;
+        assert!(res.is_err());
+        assert_eq!(expected_attempts, attempts.load(Ordering::Relaxed));
+    }
+
+    // non retriable errors
+    for err in [
+        YdbOrCustomerError::YDB(status_error(StatusCode::SchemeError)),
+        YdbOrCustomerError::from_err(YdbError::Custom("customer".to_string())),
+    ] {
+        let attempts = AtomicUsize::new(0);
+        let res: YdbResultWithCustomerErr<()> =
+            retry_with_policy(&policy, &budget, true, || async {
+                attempts.fetch_add(1, Ordering::Relaxed);
+                Err(err.clone())
+            })
+            .await;
+        assert!(res.is_err());
+        assert_eq!(1, attempts.load(Ordering::Relaxed));
+    }
+}
+
+#[test]
+fn retry_budget() {
+    let budget = RetryBudget::new(0.5, 2.0).unwrap();
+
+    // starts with full bucket
+    assert!(budget.try_withdraw());
+    assert!(budget.try_withdraw());
+    assert!(!budget.try_withdraw());
+
+    budget.deposit();
+    assert!(!budget.try_withdraw());
+    budget.deposit();
+    assert!(budget.try_withdraw());
+
+    // bucket limited by max tokens
+    for _ in 0..10 {
+        budget.deposit();
+    }
+    assert!(budget.try_withdraw());
+    assert!(budget.try_withdraw());
+    assert!(!budget.try_withdraw());
+
+    // budget shared between clones
+    let cloned = budget.clone();
+    cloned.deposit();
+    cloned.deposit();
+    assert!(budget.try_withdraw());
+
+    let unlimited = RetryBudget::unlimited();
+    for _ in 0..100 {
+        assert!(unlimited.try_withdraw());
+    }
+}
+
+#[tokio::test]
+async fn retry_budget_exhausted() {
+    let policy = BackoffRetrier::new().with_max_attempts(10);
+    let budget = RetryBudget::new(0.0, 2.0).unwrap();
+
+    // retries of all operations limited by the shared budget
+    for expected_attempts in [3, 1] {
+        let attempts = AtomicUsize::new(0);
+        let res: YdbResultWithCustomerErr<()> =
+            retry_with_policy(&policy, &budget, false, || async {
+                attempts.fetch_add(1, Ordering::Relaxed);
+                Err(YdbOrCustomerError::YDB(status_error(
+                    StatusCode::Overloaded,
+                )))
+            })
+            .await;
+        assert!(res.is_err());
+        assert_eq!(expected_attempts, attempts.load(Ordering::Relaxed));
+    }
+}
+
+#[tokio::test]
+async fn retry_budget_limits_all_errors() {
+    let policy = BackoffRetrier::new()
+        .with_max_attempts(3)
+        .with_fast_backoff(Backoff::new(Duration::ZERO, Duration::ZERO));
+    let budget = RetryBudget::new(0.0, 0.0).unwrap();
+
+    let attempts = AtomicUsize::new(0);
+    let res: YdbResultWithCustomerErr<()> = retry_with_policy(&policy, &budget, false, || async {
+        attempts.fetch_add(1, Ordering::Relaxed);
+        Err(YdbOrCustomerError::YDB(status_error(StatusCode::Aborted)))
+    })
+    .await;
+    assert!(res.is_err());
+    assert_eq!(1, attempts.load(Ordering::Relaxed));
+}
+
+#[tokio::test]
+async fn retry_budget_stops_unavailable_storm() {
+    let policy = BackoffRetrier::new()
+        .with_max_attempts(100)
+        .with_fast_backoff(Backoff::new(Duration::ZERO, Duration::ZERO));
+    let budget = RetryBudget::new(0.5, 5.0).unwrap();
+
+    // first operation spend the burst, next operations retry once per two operations
+    let mut total_attempts = 0;
+    for _ in 0..10 {
+        let attempts = AtomicUsize::new(0);
+        let res: YdbResultWithCustomerErr<()> =
+            retry_with_policy(&policy, &budget, false, || async {
+                attempts.fetch_add(1, Ordering::Relaxed);
+                Err(YdbOrCustomerError::YDB(status_error(
+                    StatusCode::Unavailable,
+                )))
+            })
+            .await;
+        assert!(res.is_err());
+        total_attempts += attempts.load(Ordering::Relaxed);
+    }
+    assert_eq!(10 + 5 + 4, total_attempts);
+}
+
+#[test]
+fn retry_budget_validation() {
+    for (retry_ratio, max_tokens) in [
+        (-0.1, 10.0),
+        (f64::NAN, 10.0),
+        (0.1, f64::INFINITY),
+        (0.1, -1.0),
+    ] {
+        assert!(matches!(
+            RetryBudget::new(retry_ratio, max_tokens),
+            Err(YdbError::Custom(_))
+        ));
+    }
+    assert!(RetryBudget::new(0.0, 0.0).is_ok());
+}
@@ -1,8 +1,15 @@
+#[cfg(test)]
+#[path = "session_test.rs"]
+mod session_test;
+
 use crate::client::TimeoutSettings;
 use crate::client_table::TableServiceClientType;
 use crate::errors::{YdbError, YdbResult};
-use crate::query::Query;
-use crate::result::{QueryResult, StreamResult};
+use crate::query::{PreparedQuery, Query};
+use crate::query_plan::QueryPlan;
+use crate::query_stats::StatsMode;
+use crate::result::{QueryResult, ResultSet, StreamResult};
+use crate::session_pool::{PreparedQueryCache, DEFAULT_PREPARED_QUERY_CACHE_SIZE};
 use crate::types::Value;
 use derivative::Derivative;
 use itertools::Itertools;
@@ -15,18 +22,43 @@ use crate::grpc_wrapper::raw_table_service::client::{
 use crate::grpc_wrapper::runtime_interceptors::InterceptedChannel;
 
 use crate::grpc_wrapper::raw_errors::RawResult;
-use crate::grpc_wrapper::raw_table_service::bulk_upsert::RawBulkUpsertRequest;
+use crate::grpc_wrapper::raw_table_service::alter_table::{RawAlterTableRequest, RawTtlAction};
+use crate::grpc_wrapper::raw_table_service::begin_transaction::RawBeginTransactionRequest;
+use crate::grpc_wrapper::raw_table_service::bulk_upsert::{
+    RawBulkUpsertData, RawBulkUpsertRequest,
+};
 use crate::grpc_wrapper::raw_table_service::commit_transaction::RawCommitTransactionRequest;
 use crate::grpc_wrapper::raw_table_service::copy_table::{
     RawCopyTableRequest, RawCopyTablesRequest,
 };
-use crate::grpc_wrapper::raw_table_service::execute_data_query::RawExecuteDataQueryRequest;
+use crate::grpc_wrapper::raw_table_service::create_table::RawCreateTableRequest;
+use crate::grpc_wrapper::raw_table_service::delete_session::RawDeleteSessionRequest;
+use crate::grpc_wrapper::raw_table_service::describe_table::RawDescribeTableRequest;
+use crate::grpc_wrapper::raw_table_service::drop_table::RawDropTableRequest;
+use crate::grpc_wrapper::raw_table_service::execute_data_query::{
+    RawDataQuery, RawExecuteDataQueryRequest,
+};
 use crate::grpc_wrapper::raw_table_service::execute_scheme_query::RawExecuteSchemeQueryRequest;
+use crate::grpc_wrapper::raw_table_service::explain_data_query::RawExplainDataQueryRequest;
 use crate::grpc_wrapper::raw_table_service::keepalive::RawKeepAliveRequest;
+use crate::grpc_wrapper::raw_table_service::prepare_data_query::RawPrepareDataQueryRequest;
+use crate::grpc_wrapper::raw_table_service::read_rows::RawReadRowsRequest;
+use crate::grpc_wrapper::raw_table_service::read_table::{RawReadTableRequest, RawReadTableStream};
+use crate::grpc_wrapper::raw_table_service::rename_tables::RawRenameTablesRequest;
 use crate::grpc_wrapper::raw_table_service::rollback_transaction::RawRollbackTransactionRequest;
-use crate::table_service_types::CopyTableItem;
+use crate::grpc_wrapper::raw_table_service::transaction_control::{
+    RawTransactionControl, RawTxMode, RawTxSelector, RawTxSettings,
+};
+use crate::table_service_types::{
+    convert_vec, AlterTableOptions, CopyTableItem, DescribeTableOptions, ReadTableOptions,
+    RenameTableItem, TableDescription,
+};
 use crate::trace_helpers::ensure_len_string;
+use crate::transaction::{TransactionInfo, TxSettings};
+use std::collections::HashMap;
+use std::future::Future;
 use tracing::{debug, trace};
+use ydb_grpc::ydb_proto::status_ids::StatusCode;
 use ydb_grpc::ydb_proto::table::v1::table_service_client::TableServiceClient;
 use ydb_grpc::ydb_proto::table::{execute_scan_query_request, ExecuteScanQueryRequest};
 
@@ -39,19 +71,53 @@ fn req_number() -> i64 {
 
 type DropSessionCallback = dyn FnOnce(&mut Session) + Send + Sync;
 
+/// Session of table service
+///
+/// Session got from [TableClient::session](crate::TableClient::session) and returned to the
+/// session pool on drop.
+/// It allow to manage transaction explicitly: begin, execute queries, commit or rollback, for
+/// integrate with frameworks, which manage unit of work by self.
+/// Unfinished transaction will be rolled back on drop.
+///
+/// The session doesn't retry any operations, use [TableClient::retry_transaction](crate::TableClient::retry_transaction)
+/// if possible.
+///
+/// Example:
+/// ```no_run
+/// # use ydb::YdbResult;
+/// #
+/// # #[tokio::main]
+/// # async fn main()->YdbResult<()>{
+/// #   use ydb::{Mode, Query, TxSettings};
+/// #   let table_client = ydb::ClientBuilder::new_from_connection_string("")?.client()?.table_client();
+///     let mut session = table_client.session().await?;
+///     session.begin_transaction(TxSettings::new(Mode::SerializableReadWrite)).await?;
+///     session.execute_data_query(Query::new("SELECT 1")).await?;
+///     session.commit().await?;
+/// #     return Ok(());
+/// # }
+/// ```
 #[derive(Derivative)]
 #[derivative(Debug)]
-pub(crate) struct Session {
+pub struct Session {
     pub(crate) id: String,
 
     pub(crate) can_pooled: bool,
 
+    // server doesn't know the session anymore: BadSession or SessionExpired received
+    pub(crate) lost_on_server: bool,
+
+    tx_id: Option<String>,
+
     #[derivative(Debug = "ignore")]
     on_drop_callbacks: Vec<Box<DropSessionCallback>>,
 
     #[derivative(Debug = "ignore")]
     channel_pool: Box<dyn CreateTableClient>,
 
+    #[derivative(Debug = "ignore")]
+    prepared_queries: PreparedQueryCache,
+
     timeouts: TimeoutSettings,
 }
 
@@ -64,18 +130,218 @@ impl Session {
         Self {
             id,
             can_pooled: true,
+            lost_on_server: false,
+            tx_id: None,
             on_drop_callbacks: Vec::new(),
             channel_pool: Box::new(channel_pool),
+            prepared_queries: PreparedQueryCache::new(DEFAULT_PREPARED_QUERY_CACHE_SIZE),
             timeouts,
         }
     }
 
+    /// Session id
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Info of active transaction, started by [Session::begin_transaction]
+    pub fn transaction_info(&self) -> Option<TransactionInfo> {
+        self.tx_id.as_ref().map(|tx_id| TransactionInfo {
+            transaction_id: tx_id.clone(),
+            session_id: self.id.clone(),
+        })
+    }
+
+    /// Begin explicit transaction
+    ///
+    /// Next calls of [Session::execute_data_query] will be executed within the transaction until
+    /// [Session::commit] or [Session::rollback].
+    pub async fn begin_transaction(&mut self, settings: TxSettings) -> YdbResult<()> {
+        if let Some(tx_id) = &self.tx_id {
+            return Err(YdbError::Custom(format!(
+                "transaction already started in the session: {tx_id}"
+            )));
+        }
+
+        let mut table = self.get_table_client().await?;
+        let res = table
+            .begin_transaction(RawBeginTransactionRequest {
+                session_id: self.id.clone(),
+                tx_settings: RawTxSettings {
+                    mode: settings.mode.into(),
+                },
+                operation_params: self.timeouts.operation_params(),
+            })
+            .await;
+        let res = self.handle_raw_result(res)?;
+        trace!("begin transaction: {}", &res.tx_id);
+        self.tx_id = Some(res.tx_id);
+        Ok(())
+    }
+
+    /// Commit transaction, started by [Session::begin_transaction]
+    ///
+    /// If commit failed the transaction stays in the session: it may be rolled back by
+    /// [Session::rollback], otherwise it rolled back on drop and the session doesn't return to pool.
+    pub async fn commit(&mut self) -> YdbResult<()> {
+        let tx_id = self.active_tx_id()?;
+        self.commit_transaction(tx_id).await?;
+        self.tx_id = None;
+        Ok(())
+    }
+
+    /// Rollback transaction, started by [Session::begin_transaction]
+    pub async fn rollback(&mut self) -> YdbResult<()> {
+        let tx_id = self.active_tx_id()?;
+        self.rollback_transaction(tx_id).await?;
+        self.tx_id = None;
+        Ok(())
+    }
+
+    // transaction id stays in the session until successful commit or rollback
+    fn active_tx_id(&self) -> YdbResult<String> {
+        self.tx_id
+            .clone()
+            .ok_or_else(|| YdbError::Custom("no active transaction in the session".into()))
+    }
+
+    /// Execute data query
+    ///
+    /// The query executed within transaction, started by [Session::begin_transaction].
+    /// Without explicit transaction the query executed in own serializable read-write transaction
+    /// with commit.
+    pub async fn execute_data_query(&mut self, query: Query) -> YdbResult<QueryResult> {
+        let req = RawExecuteDataQueryRequest {
+            session_id: self.id.clone(),
+            tx_control: self.current_tx_control(),
+            query: RawDataQuery::YqlText(query.text),
+            operation_params: self.timeouts.operation_params(),
+            params: query
+                .parameters
+                .into_iter()
+                .map(|(k, v)| Ok((k, v.try_into()?)))
+                .collect::<YdbResult<_>>()?,
+            keep_in_cache: query.keep_in_cache,
+            collect_stats: query.stats_mode.into(),
+        };
+        self.execute_raw_data_query(req, false).await
+    }
+
+    /// Prepare data query on server side
+    ///
+    /// Prepared queries cached within the session, prepare same query text again return
+    /// the query from cache without request to server.
+    /// Parameters and stats mode of the query moved to the prepared query. See [PreparedQuery] for example.
+    pub async fn prepare(&mut self, query: Query) -> YdbResult<PreparedQuery> {
+        let prepared = if let Some(prepared) = self.prepared_queries.get(&query.text) {
+            prepared
+        } else {
+            self.prepare_data_query(query.text).await?
+        };
+        Ok(prepared
+            .with_params(query.parameters)
+            .with_stats(query.stats_mode))
+    }
+
+    /// Execute prepared query in current transaction, same as [Session::execute_data_query]
+    ///
+    /// The query prepared again if it was prepared on other session or server forgot it.
+    pub async fn execute_prepared(&mut self, query: PreparedQuery) -> YdbResult<QueryResult> {
+        let query = if query.session_id == self.id {
+            query
+        } else {
+            let parameters = query.parameters;
+            self.prepare(Query::new(query.text).with_stats(query.stats_mode))
+                .await?
+                .with_params(parameters)
+        };
+
+        match self.execute_prepared_once(query.clone()).await {
+            Err(YdbError::YdbStatusError(err))
+                if err.operation_status == StatusCode::NotFound as i32 =>
+            {
+                trace!(
+                    "prepared query not found, prepare it again: {}",
+                    query.query_id
+                );
+                self.prepared_queries.remove(&query.text);
+                let query = self
+                    .prepare_data_query(query.text)
+                    .await?
+                    .with_params(query.parameters)
+                    .with_stats(query.stats_mode);
+                self.execute_prepared_once(query).await
+            }
+            res => res,
+        }
+    }
+
+    async fn prepare_data_query(&mut self, text: String) -> YdbResult<PreparedQuery> {
+        let req = RawPrepareDataQueryRequest {
+            session_id: self.id.clone(),
+            yql_text: text.clone(),
+            operation_params: self.timeouts.operation_params(),
+        };
+        let res = self.get_table_client().await?.prepare_data_query(req).await;
+        let res = self.handle_raw_result(res)?;
+
+        let prepared = PreparedQuery {
+            session_id: self.id.clone(),
+            text,
+            query_id: res.query_id,
+            parameter_types: res
+                .parameters_types
+                .into_iter()
+                .map(|(name, t)| Ok((name, t.into_value_example()?)))
+                .collect::<YdbResult<_>>()?,
+            parameters: HashMap::new(),
+            stats_mode: StatsMode::None,
+        };
+        self.prepared_queries.insert(prepared.clone());
+        Ok(prepared)
+    }
+
+    async fn execute_prepared_once(&mut self, query: PreparedQuery) -> YdbResult<QueryResult> {
+        let req = RawExecuteDataQueryRequest {
+            session_id: self.id.clone(),
+            tx_control: self.current_tx_control(),
+            query: RawDataQuery::Id(query.query_id),
+            operation_params: self.timeouts.operation_params(),
+            params: query
+                .parameters
+                .into_iter()
+                .map(|(k, v)| Ok((k, v.try_into()?)))
+                .collect::<YdbResult<_>>()?,
+            // query already prepared, keep it in server cache
+            keep_in_cache: true,
+            collect_stats: query.stats_mode.into(),
+        };
+        self.execute_raw_data_query(req, false).await
+    }
+
+    // execute in explicit transaction if it started or in autocommit transaction
+    fn current_tx_control(&self) -> RawTransactionControl {
+        if let Some(tx_id) = &self.tx_id {
+            RawTransactionControl {
+                commit_tx: false,
+                tx_selector: RawTxSelector::Id(tx_id.clone()),
+            }
+        } else {
+            RawTransactionControl {
+                commit_tx: true,
+                tx_selector: RawTxSelector::Begin(RawTxSettings {
+                    mode: RawTxMode::SerializableReadWrite,
+                }),
+            }
+        }
+    }
+
     pub(crate) fn handle_error(&mut self, err: &YdbError) {
         if let YdbError::YdbStatusError(err) = err {
-            use ydb_grpc::ydb_proto::status_ids::StatusCode;
             if let Ok(status) = StatusCode::try_from(err.operation_status) {
                 if status == StatusCode::BadSession || status == StatusCode::SessionExpired {
                     self.can_pooled = false;
+                    self.lost_on_server = true;
                 }
             }
         }
@@ -124,7 +390,7 @@ impl Session {
     ) -> YdbResult<()> {
         let req = RawBulkUpsertRequest {
             table: table_path,
-            rows: rows.to_typed_value()?,
+            data: RawBulkUpsertData::Rows(rows.to_typed_value()?),
             operation_params: self.timeouts.operation_params(),
         };
         let res = self.get_table_client().await?.bulk_upsert(req).await;
@@ -132,8 +398,78 @@ impl Session {
         Ok(())
     }
 
+    #[cfg(feature = "arrow")]
+    pub(crate) async fn execute_bulk_upsert_arrow(
+        &mut self,
+        table_path: String,
+        schema: Vec<u8>,
+        data: Vec<u8>,
+    ) -> YdbResult<()> {
+        let req = RawBulkUpsertRequest {
+            table: table_path,
+            data: RawBulkUpsertData::ArrowBatch { schema, data },
+            operation_params: self.timeouts.operation_params(),
+        };
+        let res = self.get_table_client().await?.bulk_upsert(req).await;
+        self.handle_raw_result(res)?;
+        Ok(())
+    }
+
+    /// Explain data query: get plan and ast of the query without execute it
+    ///
+    /// Parameters of the query are ignored, declare their types in the query text.
+    pub async fn explain(&mut self, query: Query) -> YdbResult<QueryPlan> {
+        let req = RawExplainDataQueryRequest {
+            session_id: self.id.clone(),
+            yql_text: query.text,
+            operation_params: self.timeouts.operation_params(),
+        };
+        let res = self.get_table_client().await?.explain_data_query(req).await;
+        let res = self.handle_raw_result(res)?;
+        QueryPlan::parse(res.query_ast, res.query_plan)
+    }
+
+    /// Read rows by primary keys without query compilation and transaction
+    ///
+    /// keys - list of structs with all primary key columns.
+    /// columns - names of columns for read, empty for read all columns.
+    pub async fn read_rows(
+        &mut self,
+        table_path: String,
+        keys: Value,
+        columns: Vec<String>,
+    ) -> YdbResult<ResultSet> {
+        let req = RawReadRowsRequest {
+            session_id: self.id.clone(),
+            path: table_path,
+            keys: keys.try_into()?,
+            columns,
+        };
+        let res = self.get_table_client().await?.read_rows(req).await;
+        let res = self.handle_raw_result(res)?;
+        ResultSet::try_from(res.result_set)
+    }
+
+    pub(crate) async fn start_read_table(
+        &mut self,
+        table_path: String,
+        options: ReadTableOptions,
+    ) -> YdbResult<RawReadTableStream> {
+        let req = RawReadTableRequest {
+            session_id: self.id.clone(),
+            path: table_path,
+            key_range: options.key_range.map(TryInto::try_into).transpose()?,
+            columns: options.columns,
+            ordered: options.ordered,
+            row_limit: options.row_limit.unwrap_or_default(),
+            use_snapshot: options.use_snapshot,
+        };
+        let res = self.get_table_client().await?.read_table(req).await;
+        self.handle_raw_result(res)
+    }
+
     #[tracing::instrument(skip(self, req), fields(req_number=req_number()))]
-    pub(crate) async fn execute_data_query(
+    pub(crate) async fn execute_raw_data_query(
         &mut self,
         mut req: RawExecuteDataQueryRequest,
         error_on_truncated: bool,
@@ -220,7 +556,120 @@ impl Session {
         self.handle_raw_result(res)
     }
 
-    pub(crate) async fn keepalive(&mut self) -> YdbResult<()> {
+    /// Create table
+    ///
+    /// Create table request can't contain changefeeds: add them by [Session::alter_table]
+    /// after the table created.
+    pub async fn create_table(
+        &mut self,
+        path: String,
+        description: TableDescription,
+    ) -> YdbResult<()> {
+        if !description.changefeeds.is_empty() {
+            return Err(YdbError::Custom(
+                "changefeeds in create table request, add them by alter table".into(),
+            ));
+        }
+        let req = RawCreateTableRequest {
+            session_id: self.id.clone(),
+            path,
+            operation_params: self.timeouts.operation_params(),
+            columns: convert_vec(description.columns)?,
+            primary_key: description.primary_key,
+            indexes: description.indexes.into_iter().map_into().collect(),
+            ttl_settings: description
+                .ttl_settings
+                .map(TryInto::try_into)
+                .transpose()?,
+            column_families: description.column_families.into_iter().map_into().collect(),
+            attributes: description.attributes,
+            partitioning_settings: description.partitioning_settings.map(Into::into),
+            uniform_partitions: description.uniform_partitions,
+            read_replicas_settings: description.read_replicas_settings.map(Into::into),
+        };
+        let res = self.get_table_client().await?.create_table(req).await;
+        self.handle_raw_result(res)
+    }
+
+    pub async fn alter_table(&mut self, path: String, options: AlterTableOptions) -> YdbResult<()> {
+        let ttl_action = match (options.set_ttl_settings, options.drop_ttl_settings) {
+            (Some(_), true) => {
+                return Err(YdbError::Custom(
+                    "set and drop ttl settings in same alter table request".into(),
+                ))
+            }
+            (Some(settings), false) => Some(RawTtlAction::Set(settings.try_into()?)),
+            (None, true) => Some(RawTtlAction::Drop),
+            (None, false) => None,
+        };
+
+        let req = RawAlterTableRequest {
+            session_id: self.id.clone(),
+            path,
+            operation_params: self.timeouts.operation_params(),
+            add_columns: convert_vec(options.add_columns)?,
+            drop_columns: options.drop_columns,
+            add_indexes: options.add_indexes.into_iter().map_into().collect(),
+            drop_indexes: options.drop_indexes,
+            ttl_action,
+            add_column_families: options.add_column_families.into_iter().map_into().collect(),
+            alter_column_families: options
+                .alter_column_families
+                .into_iter()
+                .map_into()
+                .collect(),
+            alter_attributes: options.alter_attributes,
+            alter_partitioning_settings: options.set_partitioning_settings.map(Into::into),
+            set_read_replicas_settings: options.set_read_replicas_settings.map(Into::into),
+            add_changefeeds: options.add_changefeeds.into_iter().map_into().collect(),
+            drop_changefeeds: options.drop_changefeeds,
+        };
+        let res = self.get_table_client().await?.alter_table(req).await;
+        self.handle_raw_result(res)
+    }
+
+    /// Describe table: columns, primary key, indexes and other settings
+    pub async fn describe_table(
+        &mut self,
+        path: String,
+        options: DescribeTableOptions,
+    ) -> YdbResult<TableDescription> {
+        let req = RawDescribeTableRequest {
+            session_id: self.id.clone(),
+            path,
+            operation_params: self.timeouts.operation_params(),
+            include_shard_key_bounds: options.include_shard_key_bounds,
+            include_table_stats: options.include_table_stats,
+            include_partition_stats: options.include_partition_stats,
+        };
+        let res = self.get_table_client().await?.describe_table(req).await;
+        let res = self.handle_raw_result(res)?;
+        Ok(TableDescription::try_from(res)?)
+    }
+
+    pub async fn drop_table(&mut self, path: String) -> YdbResult<()> {
+        let req = RawDropTableRequest {
+            session_id: self.id.clone(),
+            path,
+            operation_params: self.timeouts.operation_params(),
+        };
+        let res = self.get_table_client().await?.drop_table(req).await;
+        self.handle_raw_result(res)
+    }
+
+    /// Rename (move) tables in one transaction
+    pub async fn rename_tables(&mut self, tables: Vec<RenameTableItem>) -> YdbResult<()> {
+        let req = RawRenameTablesRequest {
+            operation_params: self.timeouts.operation_params(),
+            session_id: self.id.clone(),
+            tables: tables.into_iter().map_into().collect(),
+        };
+        let res = self.get_table_client().await?.rename_tables(req).await;
+        self.handle_raw_result(res)
+    }
+
+    /// Check the session is alive on server side
+    pub async fn keep_alive(&mut self) -> YdbResult<()> {
         let mut table = self.get_table_client().await?;
         let res = table
             .keep_alive(RawKeepAliveRequest {
@@ -240,6 +689,29 @@ impl Session {
         }
     }
 
+    // Rollback active transaction and delete the session on server side,
+    // the session can't be used after it
+    pub(crate) async fn delete(&mut self) -> YdbResult<()> {
+        self.can_pooled = false;
+        if let Some(tx_id) = self.tx_id.take() {
+            if let Err(err) = self.rollback_transaction(tx_id).await {
+                trace!(
+                    "failed to rollback transaction before delete session: {}",
+                    err
+                );
+            }
+        }
+        let res = self
+            .get_table_client()
+            .await?
+            .delete_session(RawDeleteSessionRequest {
+                session_id: self.id.clone(),
+                operation_params: self.timeouts.operation_params(),
+            })
+            .await;
+        self.handle_raw_result(res)
+    }
+
     pub fn with_timeouts(mut self, timeouts: TimeoutSettings) -> Self {
         self.timeouts = timeouts;
         self
@@ -263,19 +735,52 @@ impl Session {
         Self {
             id: self.id.clone(),
             can_pooled: self.can_pooled,
+            lost_on_server: self.lost_on_server,
+            tx_id: None,
             on_drop_callbacks: Vec::new(),
             channel_pool: self.channel_pool.clone_box(),
+            prepared_queries: self.prepared_queries.clone(),
             timeouts: self.timeouts,
         }
     }
+
+    // Clone for close on server side, active transaction moved to the clone
+    // for rollback it before delete the session
+    pub(crate) fn clone_for_close(&mut self) -> Self {
+        let mut session = self.clone_without_ondrop();
+        session.tx_id = self.tx_id.take();
+        session
+    }
 }
 
 impl Drop for Session {
     fn drop(&mut self) {
         trace!("drop session: {}", &self.id);
+        if self.tx_id.is_some() {
+            // server side state of the session is unknown until rollback completed
+            self.can_pooled = false;
+        }
         while let Some(on_drop) = self.on_drop_callbacks.pop() {
             on_drop(self)
         }
+
+        // on_drop callbacks take the transaction if close the session
+        if let Some(tx_id) = self.tx_id.take() {
+            let mut session = self.clone_without_ondrop();
+            spawn_background(async move {
+                let _ = session.rollback_transaction(tx_id).await;
+            });
+        }
+    }
+}
+
+// Drop may be called outside of tokio runtime, the background work skipped in the case
+pub(crate) fn spawn_background(future: impl Future<Output = ()> + Send + 'static) {
+    match tokio::runtime::Handle::try_current() {
+        Ok(runtime) => {
+            runtime.spawn(future);
+        }
+        Err(_) => trace!("skip background work: no tokio runtime"),
     }
 }
 
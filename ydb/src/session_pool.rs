@@ -2,15 +2,24 @@ use crate::client::TimeoutSettings;
 use crate::errors::*;
 use crate::grpc_connection_manager::GrpcConnectionManager;
 use crate::grpc_wrapper::raw_table_service::client::RawTableClient;
-use crate::session::Session;
+use crate::query::PreparedQuery;
+use crate::session::{spawn_background, Session};
+use crate::shutdown::{ClientShutdown, ClosableSessionPool};
 use async_trait::async_trait;
+use futures_util::future::join_all;
 use std::collections::vec_deque::VecDeque;
-use std::ops::{Add, Sub};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex, Weak};
+use std::time::Duration;
 use tokio::sync::Semaphore;
+use tokio::time::Instant;
+use tokio_util::sync::CancellationToken;
 use tracing::trace;
 
 const DEFAULT_SIZE: usize = 1000;
+const DEFAULT_KEEP_ALIVE_INTERVAL: Duration = Duration::from_secs(60);
+pub(crate) const DEFAULT_PREPARED_QUERY_CACHE_SIZE: usize = 100;
 
 #[async_trait]
 pub(crate) trait SessionFabric: Send + Sync {
@@ -30,64 +39,253 @@ impl SessionFabric for GrpcConnectionManager {
     }
 }
 
-type IdleSessions = Arc<Mutex<VecDeque<IdleSessionItem>>>;
+/// Options of table service session pool
+///
+/// Example:
+/// ```no_run
+/// # use std::time::Duration;
+/// # use ydb::{ClientBuilder, SessionPoolOptions, YdbResult};
+/// # fn main() -> YdbResult<()> {
+/// let client = ClientBuilder::new_from_connection_string("grpc://localhost:2136?database=/local")?
+///     .with_session_pool_options(
+///         SessionPoolOptions::new()
+///             .with_max_size(50)
+///             .with_min_idle(5)
+///             .with_acquire_timeout(Duration::from_secs(1)),
+///     )
+///     .client()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct SessionPoolOptions {
+    max_size: usize,
+    min_idle: usize,
+    idle_timeout: Option<Duration>,
+    max_lifetime: Option<Duration>,
+    keep_alive_interval: Duration,
+    acquire_timeout: Option<Duration>,
+}
+
+impl SessionPoolOptions {
+    /// Options with max 1000 sessions, keep alive idle sessions every 60s and without other limits
+    pub fn new() -> Self {
+        Self {
+            max_size: DEFAULT_SIZE,
+            min_idle: 0,
+            idle_timeout: None,
+            max_lifetime: None,
+            keep_alive_interval: DEFAULT_KEEP_ALIVE_INTERVAL,
+            acquire_timeout: None,
+        }
+    }
+
+    /// Set max count of sessions: in use and idle
+    pub fn with_max_size(mut self, max_size: usize) -> Self {
+        self.max_size = max_size;
+        self
+    }
+
+    /// Set count of idle sessions, which pool create in background and keep ready for use
+    pub fn with_min_idle(mut self, min_idle: usize) -> Self {
+        self.min_idle = min_idle;
+        self
+    }
+
+    /// Close sessions, which are idle longer than the timeout (but keep min idle sessions)
+    pub fn with_idle_timeout(mut self, idle_timeout: Duration) -> Self {
+        self.idle_timeout = Some(idle_timeout);
+        self
+    }
+
+    /// Close sessions, which are created earlier than the lifetime ago.
+    /// Session in use is closed after return to the pool.
+    pub fn with_max_lifetime(mut self, max_lifetime: Duration) -> Self {
+        self.max_lifetime = Some(max_lifetime);
+        self
+    }
+
+    /// Set interval of keep alive requests for idle sessions
+    pub fn with_keep_alive_interval(mut self, interval: Duration) -> Self {
+        self.keep_alive_interval = interval;
+        self
+    }
+
+    /// Set max wait time of free session when pool is exhausted.
+    /// Get session return [YdbError::SessionPoolExhausted] after the timeout.
+    pub fn with_acquire_timeout(mut self, acquire_timeout: Duration) -> Self {
+        self.acquire_timeout = Some(acquire_timeout);
+        self
+    }
+
+    fn expired(&self, created_at: Instant, now: Instant) -> bool {
+        self.max_lifetime
+            .is_some_and(|max_lifetime| now.duration_since(created_at) >= max_lifetime)
+    }
+
+    // period of background pool maintenance
+    fn check_interval(&self) -> Duration {
+        [self.idle_timeout, self.max_lifetime]
+            .into_iter()
+            .flatten()
+            .fold(self.keep_alive_interval, Duration::min)
+            .max(Duration::from_millis(100))
+    }
+}
+
+impl Default for SessionPoolOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Snapshot of session pool state
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(not(feature = "force-exhaustive-all"), non_exhaustive)]
+pub struct SessionPoolStats {
+    /// Sessions, which are used now
+    pub in_use: usize,
+
+    /// Sessions in the pool, ready for use
+    pub idle: usize,
+
+    /// Count of requests, which wait for free session
+    pub waiting: usize,
+
+    /// Total count of sessions, created by the pool
+    pub created: usize,
+
+    /// Total count of sessions, removed from the pool: broken, expired, etc.
+    pub closed: usize,
+}
 
 #[derive(Clone)]
 pub(crate) struct SessionPool {
+    inner: Arc<SessionPoolInner>,
+}
+
+struct SessionPoolInner {
     active_sessions: Arc<Semaphore>,
     create_session: Arc<Box<dyn SessionFabric>>,
-    idle_sessions: IdleSessions,
+    idle_sessions: Mutex<VecDeque<IdleSessionItem>>,
+    options: SessionPoolOptions,
     timeouts: TimeoutSettings,
+    shutdown: ClientShutdown,
+
+    in_use: AtomicUsize,
+    waiting: AtomicUsize,
+    created: AtomicUsize,
+    closed: AtomicUsize,
 }
 
 impl SessionPool {
-    pub(crate) fn new(session_client: Box<dyn SessionFabric>, timeouts: TimeoutSettings) -> Self {
+    pub(crate) fn new(
+        session_client: Box<dyn SessionFabric>,
+        timeouts: TimeoutSettings,
+        options: SessionPoolOptions,
+        shutdown: ClientShutdown,
+    ) -> Self {
+        Self::new_with_fabric(Arc::new(session_client), timeouts, options, shutdown)
+    }
+
+    fn new_with_fabric(
+        create_session: Arc<Box<dyn SessionFabric>>,
+        timeouts: TimeoutSettings,
+        options: SessionPoolOptions,
+        shutdown: ClientShutdown,
+    ) -> Self {
         let pool = Self {
-            active_sessions: Arc::new(Semaphore::new(DEFAULT_SIZE)),
-            create_session: Arc::new(session_client),
-            idle_sessions: Arc::new(Mutex::new(VecDeque::new())),
-            timeouts,
+            inner: Arc::new(SessionPoolInner {
+                active_sessions: Arc::new(Semaphore::new(options.max_size)),
+                create_session,
+                idle_sessions: Mutex::new(VecDeque::new()),
+                options,
+                timeouts,
+                shutdown: shutdown.clone(),
+                in_use: AtomicUsize::new(0),
+                waiting: AtomicUsize::new(0),
+                created: AtomicUsize::new(0),
+                closed: AtomicUsize::new(0),
+            }),
         };
 
-        tokio::spawn(sessions_pinger(
-            pool.create_session.clone(),
-            Arc::downgrade(&pool.idle_sessions),
-            std::time::Duration::from_secs(60),
+        shutdown.register_session_pool(Arc::downgrade(&pool.inner));
+        tokio::spawn(pool_maintainer(
+            Arc::downgrade(&pool.inner),
+            shutdown.stop_token(),
         ));
         pool
     }
 
-    pub(crate) fn with_max_active_sessions(mut self, size: usize) -> Self {
-        self.active_sessions = Arc::new(Semaphore::new(size));
-        self
+    // New empty pool with same sessions source
+    pub(crate) fn clone_with_options(&self, options: SessionPoolOptions) -> Self {
+        Self::new_with_fabric(
+            self.inner.create_session.clone(),
+            self.inner.timeouts,
+            options,
+            self.inner.shutdown.clone(),
+        )
+    }
+
+    #[cfg(test)]
+    pub(crate) fn is_same(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+
+    pub(crate) fn stats(&self) -> SessionPoolStats {
+        let inner = &self.inner;
+        SessionPoolStats {
+            in_use: inner.in_use.load(Ordering::Relaxed),
+            idle: inner.idle_sessions.lock().unwrap().len(),
+            waiting: inner.waiting.load(Ordering::Relaxed),
+            created: inner.created.load(Ordering::Relaxed),
+            closed: inner.closed.load(Ordering::Relaxed),
+        }
     }
 
     pub(crate) async fn session(&self) -> YdbResult<Session> {
-        let active_session_permit = self.active_sessions.clone().acquire_owned().await?;
-        let idle_sessions = self.idle_sessions.clone();
+        self.inner.shutdown.check_not_closing()?;
+        let active_session_permit = {
+            let _waiting = CounterGuard::new(&self.inner.waiting);
+            let acquire = self.inner.active_sessions.clone().acquire_owned();
+            match self.inner.options.acquire_timeout {
+                Some(acquire_timeout) => tokio::time::timeout(acquire_timeout, acquire)
+                    .await
+                    .map_err(|_| {
+                        YdbError::SessionPoolExhausted(format!(
+                            "no free session in pool during {acquire_timeout:?}"
+                        ))
+                    })??,
+                None => acquire.await?,
+            }
+        };
+        let inner = self.inner.clone();
 
-        let mut session = {
-            let idle_item = {
-                // brackets need for drop mutex guard right after pop element: before start async await
-                idle_sessions.lock()?.pop_front()
-            };
-            if let Some(idle_item) = idle_item {
+        let (mut session, created_at) = match inner.pop_idle() {
+            Some(idle_item) => {
                 trace!("got session from pool: {}", &idle_item.session.id);
-                idle_item.session
-            } else {
-                let session = self.create_session.create_session(self.timeouts).await?;
-                trace!("create session: {}", &session.id);
-                session
+                (idle_item.session, idle_item.created_at)
             }
+            None => (inner.create_session().await?, Instant::now()),
         };
 
+        inner.in_use.fetch_add(1, Ordering::Relaxed);
         session.on_drop(Box::new(move |s: &mut Session| {
-            trace!("moved to pool: {}", s.id);
-            let item = IdleSessionItem {
-                idle_since: tokio::time::Instant::now(),
-                session: s.clone_without_ondrop(),
-            };
-            idle_sessions.lock().unwrap().push_back(item);
+            if !s.can_pooled
+                || inner.shutdown.is_closing()
+                || inner.options.expired(created_at, Instant::now())
+            {
+                inner.close_session(s.clone_for_close());
+            } else {
+                trace!("moved to pool: {}", s.id);
+                let item = IdleSessionItem {
+                    idle_since: Instant::now(),
+                    created_at,
+                    session: s.clone_without_ondrop(),
+                };
+                inner.idle_sessions.lock().unwrap().push_back(item);
+            }
+            inner.in_use.fetch_sub(1, Ordering::Relaxed);
             drop(active_session_permit);
         }));
         session = session.with_timeouts(TimeoutSettings::default());
@@ -95,54 +293,225 @@ impl SessionPool {
     }
 }
 
+impl SessionPoolInner {
+    async fn create_session(&self) -> YdbResult<Session> {
+        let session = self.create_session.create_session(self.timeouts).await?;
+        self.created.fetch_add(1, Ordering::Relaxed);
+        trace!("create session: {}", &session.id);
+        Ok(session)
+    }
+
+    // pop first not expired idle session
+    fn pop_idle(&self) -> Option<IdleSessionItem> {
+        let now = Instant::now();
+        let mut idle_sessions = self.idle_sessions.lock().unwrap();
+        while let Some(idle_item) = idle_sessions.pop_front() {
+            if !self.options.expired(idle_item.created_at, now) {
+                return Some(idle_item);
+            }
+            self.close_session(idle_item.session);
+        }
+        None
+    }
+
+    // delete session on server side in background
+    fn close_session(&self, mut session: Session) {
+        self.closed.fetch_add(1, Ordering::Relaxed);
+        if session.lost_on_server {
+            trace!("drop session without return to pool: {}", session.id);
+            return;
+        }
+        trace!("close session: {}", session.id);
+        spawn_background(async move {
+            if let Err(err) = session.delete().await {
+                trace!("failed to delete session '{}': {}", session.id, err);
+            }
+        });
+    }
+}
+
+#[async_trait]
+impl ClosableSessionPool for Weak<SessionPoolInner> {
+    fn is_alive(&self) -> bool {
+        self.strong_count() > 0
+    }
+
+    fn in_use(&self) -> usize {
+        self.upgrade()
+            .map_or(0, |pool| pool.in_use.load(Ordering::Relaxed))
+    }
+
+    async fn close_idle_sessions(&self) {
+        let Some(pool) = self.upgrade() else {
+            return;
+        };
+        let idle_sessions = std::mem::take(&mut *pool.idle_sessions.lock().unwrap());
+        pool.closed
+            .fetch_add(idle_sessions.len(), Ordering::Relaxed);
+        join_all(idle_sessions.into_iter().map(|mut idle_item| async move {
+            if let Err(err) = idle_item.session.delete().await {
+                trace!(
+                    "failed to delete session '{}': {}",
+                    idle_item.session.id,
+                    err
+                );
+            }
+        }))
+        .await;
+    }
+}
+
+// Increment counter while the guard alive
+struct CounterGuard<'a>(&'a AtomicUsize);
+
+impl<'a> CounterGuard<'a> {
+    fn new(counter: &'a AtomicUsize) -> Self {
+        counter.fetch_add(1, Ordering::Relaxed);
+        Self(counter)
+    }
+}
+
+impl Drop for CounterGuard<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+/// Least recently used prepared queries of one session, by query text
+///
+/// Clones share the cache, so it lives with the session while the session moves between pool and user.
+#[derive(Clone)]
+pub(crate) struct PreparedQueryCache {
+    inner: Arc<Mutex<PreparedQueryCacheInner>>,
+}
+
+struct PreparedQueryCacheInner {
+    capacity: usize,
+    counter: u64,
+    queries: HashMap<String, (u64, PreparedQuery)>,
+}
+
+impl PreparedQueryCache {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(PreparedQueryCacheInner {
+                capacity,
+                counter: 0,
+                queries: HashMap::new(),
+            })),
+        }
+    }
+
+    pub(crate) fn get(&self, text: &str) -> Option<PreparedQuery> {
+        let mut inner = self.inner.lock().unwrap();
+        inner.counter += 1;
+        let counter = inner.counter;
+        inner.queries.get_mut(text).map(|(last_used, query)| {
+            *last_used = counter;
+            query.clone()
+        })
+    }
+
+    pub(crate) fn insert(&self, query: PreparedQuery) {
+        let mut inner = self.inner.lock().unwrap();
+        if inner.capacity == 0 {
+            return;
+        }
+        if inner.queries.len() >= inner.capacity && !inner.queries.contains_key(&query.text) {
+            let oldest = inner
+                .queries
+                .iter()
+                .min_by_key(|(_, (last_used, _))| *last_used)
+                .map(|(text, _)| text.clone());
+            if let Some(oldest) = oldest {
+                inner.queries.remove(&oldest);
+            }
+        }
+        inner.counter += 1;
+        let counter = inner.counter;
+        inner.queries.insert(query.text.clone(), (counter, query));
+    }
+
+    pub(crate) fn remove(&self, text: &str) {
+        self.inner.lock().unwrap().queries.remove(text);
+    }
+}
+
 struct IdleSessionItem {
-    idle_since: tokio::time::Instant,
+    idle_since: Instant,
+    created_at: Instant,
     session: Session,
 }
 
-async fn sessions_pinger(
-    _session_client: Arc<Box<dyn SessionFabric>>,
-    idle_sessions: Weak<Mutex<VecDeque<IdleSessionItem>>>,
-    interval: std::time::Duration,
-) {
-    let mut sleep_until = tokio::time::Instant::now().add(interval);
+// Keep alive idle sessions, close expired and long idle sessions, prewarm min idle sessions
+async fn pool_maintainer(pool: Weak<SessionPoolInner>, stop: CancellationToken) {
     loop {
-        tokio::time::sleep_until(sleep_until).await;
-        let now = tokio::time::Instant::now();
-        let ping_since = now.sub(interval);
-        {
-            let idle_sessions = if let Some(idle_sessions) = idle_sessions.upgrade() {
-                idle_sessions
-            } else {
+        let check_interval = {
+            let Some(pool) = pool.upgrade() else {
                 return;
             };
+            maintain_pool(&pool).await;
+            pool.options.check_interval()
+        };
+        tokio::select! {
+            _ = stop.cancelled() => return,
+            _ = tokio::time::sleep(check_interval) => {}
+        }
+    }
+}
 
-            'sessions: loop {
-                let mut session = {
-                    let mut idle_sessions = idle_sessions.lock().unwrap();
-                    if let Some(idle_item) = idle_sessions.front() {
-                        if idle_item.idle_since <= ping_since {
-                            idle_sessions.pop_front().unwrap().session
-                        } else {
-                            // wait until front session need to ping
-                            sleep_until = idle_item.idle_since.add(interval);
-                            break 'sessions;
-                        }
-                    } else {
-                        // empty queue
-                        sleep_until = now.add(interval);
-                        break 'sessions;
-                    }
-                };
-                if session.keepalive().await.is_ok() && session.can_pooled {
-                    let mut idle_sessions = idle_sessions.lock().unwrap();
-                    idle_sessions.push_back(IdleSessionItem {
-                        idle_since: tokio::time::Instant::now(),
-                        session,
-                    });
-                } else {
-                    drop(session)
-                };
+async fn maintain_pool(pool: &SessionPoolInner) {
+    let options = &pool.options;
+    let now = Instant::now();
+
+    let mut need_ping = Vec::new();
+    {
+        let mut idle_sessions = pool.idle_sessions.lock().unwrap();
+        let mut idle_count = idle_sessions.len();
+        for idle_item in std::mem::take(&mut *idle_sessions) {
+            let idle_timeout_exceeded = options.idle_timeout.is_some_and(|idle_timeout| {
+                now.duration_since(idle_item.idle_since) >= idle_timeout
+            }) && idle_count > options.min_idle;
+
+            if options.expired(idle_item.created_at, now) || idle_timeout_exceeded {
+                pool.close_session(idle_item.session);
+                idle_count -= 1;
+            } else if now.duration_since(idle_item.idle_since) >= options.keep_alive_interval {
+                need_ping.push(idle_item);
+            } else {
+                idle_sessions.push_back(idle_item);
+            }
+        }
+    }
+
+    for mut idle_item in need_ping {
+        if idle_item.session.keep_alive().await.is_ok() && idle_item.session.can_pooled {
+            idle_item.idle_since = Instant::now();
+            pool.idle_sessions.lock().unwrap().push_back(idle_item);
+        } else {
+            pool.close_session(idle_item.session);
+        }
+    }
+
+    while !pool.shutdown.is_closing() {
+        let idle_count = pool.idle_sessions.lock().unwrap().len();
+        let total_count = idle_count + pool.in_use.load(Ordering::Relaxed);
+        if idle_count >= options.min_idle || total_count >= options.max_size {
+            return;
+        }
+        match pool.create_session().await {
+            Ok(session) => pool
+                .idle_sessions
+                .lock()
+                .unwrap()
+                .push_back(IdleSessionItem {
+                    idle_since: Instant::now(),
+                    created_at: Instant::now(),
+                    session,
+                }),
+            Err(err) => {
+                trace!("failed to prewarm session: {}", err);
+                return;
             }
         }
     }
@@ -156,21 +525,35 @@ mod test {
     use crate::errors::{YdbError, YdbResult};
     use crate::grpc_wrapper::raw_table_service::client::RawTableClient;
     use crate::grpc_wrapper::runtime_interceptors::InterceptedChannel;
+    use crate::query::PreparedQuery;
+    use crate::query_stats::StatsMode;
     use crate::session::{CreateTableClient, Session};
-    use crate::session_pool::SessionPool;
+    use crate::session_pool::{
+        PreparedQueryCache, SessionPool, SessionPoolOptions, SessionPoolStats,
+    };
+    use crate::shutdown::ClientShutdown;
+    use crate::test_helpers::{fake_grpc_channel, operation_response, FakeTableService};
     use async_trait::async_trait;
 
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
     use std::time::Duration;
     use tokio::sync::oneshot;
+    use ydb_grpc::ydb_proto::status_ids::StatusCode;
     use ydb_grpc::ydb_proto::table::v1::table_service_client::TableServiceClient;
 
-    struct SessionClientMock {}
+    #[derive(Default)]
+    struct SessionClientMock {
+        created: Arc<AtomicUsize>,
+    }
 
     #[async_trait]
     impl SessionFabric for SessionClientMock {
         async fn create_session(&self, timeouts: TimeoutSettings) -> YdbResult<Session> {
+            let index = self.created.fetch_add(1, Ordering::Relaxed);
             Ok(Session::new(
-                "asd".into(),
+                format!("session-{index}"),
                 TableChannelPoolMock {},
                 timeouts,
             ))
@@ -201,8 +584,12 @@ mod test {
 
     #[tokio::test]
     async fn max_active_session() -> YdbResult<()> {
-        let pool = SessionPool::new(Box::new(SessionClientMock {}), TimeoutSettings::default())
-            .with_max_active_sessions(1);
+        let pool = SessionPool::new(
+            Box::new(SessionClientMock::default()),
+            TimeoutSettings::default(),
+            SessionPoolOptions::new().with_max_size(1),
+            ClientShutdown::default(),
+        );
         let first_session = pool.session().await?;
 
         let (thread_started_sender, thread_started_receiver) = oneshot::channel();
@@ -227,4 +614,263 @@ mod test {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn reuse_alive_sessions_only() -> YdbResult<()> {
+        let fabric = SessionClientMock::default();
+        let created = fabric.created.clone();
+        let pool = SessionPool::new(
+            Box::new(fabric),
+            TimeoutSettings::default(),
+            SessionPoolOptions::default(),
+            ClientShutdown::default(),
+        );
+
+        let session = pool.session().await?;
+        assert_eq!("session-0", session.id());
+        drop(session);
+
+        let mut session = pool.session().await?;
+        assert_eq!("session-0", session.id());
+        session.can_pooled = false;
+        drop(session);
+
+        let session = pool.session().await?;
+        assert_eq!("session-1", session.id());
+        assert_eq!(2, created.load(Ordering::Relaxed));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn acquire_timeout() -> YdbResult<()> {
+        let pool = SessionPool::new(
+            Box::new(SessionClientMock::default()),
+            TimeoutSettings::default(),
+            SessionPoolOptions::new()
+                .with_max_size(1)
+                .with_acquire_timeout(Duration::from_millis(50)),
+            ClientShutdown::default(),
+        );
+
+        let session = pool.session().await?;
+        match pool.session().await {
+            Err(YdbError::SessionPoolExhausted(_)) => {}
+            res => panic!("unexpected result: {:?}", res.map(|s| s.id().to_string())),
+        }
+        assert_eq!(0, pool.stats().waiting);
+
+        drop(session);
+        pool.session().await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn stats() -> YdbResult<()> {
+        let pool = SessionPool::new(
+            Box::new(SessionClientMock::default()),
+            TimeoutSettings::default(),
+            SessionPoolOptions::default(),
+            ClientShutdown::default(),
+        );
+
+        let first = pool.session().await?;
+        let mut second = pool.session().await?;
+        assert_eq!(
+            SessionPoolStats {
+                in_use: 2,
+                idle: 0,
+                waiting: 0,
+                created: 2,
+                closed: 0,
+            },
+            pool.stats()
+        );
+
+        drop(first);
+        second.can_pooled = false;
+        drop(second);
+        assert_eq!(
+            SessionPoolStats {
+                in_use: 0,
+                idle: 1,
+                waiting: 0,
+                created: 2,
+                closed: 1,
+            },
+            pool.stats()
+        );
+        Ok(())
+    }
+
+    // move paused clock and let background maintainer handle it
+    async fn advance(duration: Duration) {
+        tokio::time::advance(duration).await;
+        for _ in 0..10 {
+            tokio::task::yield_now().await;
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn max_lifetime() -> YdbResult<()> {
+        let pool = SessionPool::new(
+            Box::new(SessionClientMock::default()),
+            TimeoutSettings::default(),
+            SessionPoolOptions::new().with_max_lifetime(Duration::from_millis(50)),
+            ClientShutdown::default(),
+        );
+
+        let session = pool.session().await?;
+        assert_eq!("session-0", session.id());
+        drop(session);
+        assert_eq!("session-0", pool.session().await?.id());
+
+        advance(Duration::from_millis(60)).await;
+        assert_eq!("session-1", pool.session().await?.id());
+        assert_eq!(1, pool.stats().closed);
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn min_idle_and_idle_timeout() -> YdbResult<()> {
+        let pool = SessionPool::new(
+            Box::new(SessionClientMock::default()),
+            TimeoutSettings::default(),
+            SessionPoolOptions::new()
+                .with_min_idle(2)
+                .with_idle_timeout(Duration::from_millis(100)),
+            ClientShutdown::default(),
+        );
+
+        // prewarm
+        advance(Duration::ZERO).await;
+        assert_eq!(2, pool.stats().idle);
+        assert_eq!(2, pool.stats().created);
+
+        let sessions = vec![
+            pool.session().await?,
+            pool.session().await?,
+            pool.session().await?,
+        ];
+        drop(sessions);
+        assert_eq!(3, pool.stats().idle);
+
+        // close sessions above min idle
+        advance(Duration::from_millis(350)).await;
+        let stats = pool.stats();
+        assert_eq!(2, stats.idle);
+        assert_eq!(1, stats.closed);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn close() -> YdbResult<()> {
+        let shutdown = ClientShutdown::default();
+        let pool = SessionPool::new(
+            Box::new(SessionClientMock::default()),
+            TimeoutSettings::default(),
+            SessionPoolOptions::default(),
+            shutdown.clone(),
+        );
+
+        let in_use = pool.session().await?;
+        drop(pool.session().await?);
+        assert_eq!(1, pool.stats().idle);
+
+        let close = tokio::spawn({
+            let shutdown = shutdown.clone();
+            async move { shutdown.close(Duration::from_secs(10)).await }
+        });
+        tokio::time::sleep(Duration::from_millis(20)).await;
+
+        match pool.session().await {
+            Err(YdbError::Cancelled(_)) => {}
+            res => panic!("unexpected result: {:?}", res.map(|s| s.id().to_string())),
+        }
+
+        drop(in_use);
+        close.await??;
+        assert_eq!(
+            SessionPoolStats {
+                in_use: 0,
+                idle: 0,
+                waiting: 0,
+                created: 2,
+                closed: 2,
+            },
+            pool.stats()
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn delete_not_pooled_sessions() -> YdbResult<()> {
+        let calls = Arc::new(Mutex::new(Vec::<String>::new()));
+        let channel = fake_grpc_channel({
+            let calls = calls.clone();
+            move |method, _request| {
+                calls.lock().unwrap().push(method.to_string());
+                operation_response(StatusCode::Success)
+            }
+        });
+        let pool = SessionPool::new(
+            Box::new(FakeTableService { channel }),
+            TimeoutSettings::default(),
+            SessionPoolOptions::default(),
+            ClientShutdown::default(),
+        );
+
+        // server already forgot the session
+        let mut session = pool.session().await?;
+        session.can_pooled = false;
+        session.lost_on_server = true;
+        drop(session);
+
+        // the session still alive on server
+        let mut session = pool.session().await?;
+        session.can_pooled = false;
+        drop(session);
+
+        tokio::time::timeout(Duration::from_secs(10), async {
+            while calls.lock().unwrap().is_empty() {
+                tokio::task::yield_now().await;
+            }
+        })
+        .await?;
+        assert_eq!(
+            vec!["/Ydb.Table.V1.TableService/DeleteSession".to_string()],
+            *calls.lock().unwrap()
+        );
+        assert_eq!(2, pool.stats().closed);
+        Ok(())
+    }
+
+    #[test]
+    fn prepared_query_cache_evicts_least_recently_used() {
+        let query = |text: &str| PreparedQuery {
+            session_id: "session".to_string(),
+            text: text.to_string(),
+            query_id: format!("id-{text}"),
+            parameter_types: HashMap::new(),
+            parameters: HashMap::new(),
+            stats_mode: StatsMode::None,
+        };
+
+        let cache = PreparedQueryCache::new(2);
+        cache.insert(query("q1"));
+        cache.insert(query("q2"));
+        assert!(cache.get("q1").is_some());
+
+        cache.insert(query("q3"));
+        assert!(cache.get("q2").is_none());
+        assert_eq!("id-q1", cache.get("q1").unwrap().query_id);
+        assert_eq!("id-q3", cache.get("q3").unwrap().query_id);
+
+        cache.remove("q1");
+        assert!(cache.get("q1").is_none());
+
+        let cache = PreparedQueryCache::new(0);
+        cache.insert(query("q1"));
+        assert!(cache.get("q1").is_none());
+    }
 }
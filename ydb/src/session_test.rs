@@ -0,0 +1,96 @@
+use crate::client::TimeoutSettings;
+use crate::errors::YdbResult;
+use crate::query::Query;
+use crate::query_stats::StatsMode;
+use crate::session::Session;
+use crate::test_helpers::{
+    fake_grpc_channel, operation_response, operation_result_response, FakeTableService,
+};
+use prost::Message;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use ydb_grpc::ydb_proto::status_ids::StatusCode;
+use ydb_grpc::ydb_proto::table::query_stats_collection::Mode;
+use ydb_grpc::ydb_proto::table::{
+    CommitTransactionResult, ExecuteDataQueryRequest, ExecuteQueryResult, PrepareQueryResult,
+    TransactionMeta,
+};
+use ydb_grpc::ydb_proto::table_stats::QueryStats;
+
+#[tokio::test]
+async fn execute_prepared_with_stats() -> YdbResult<()> {
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let channel = fake_grpc_channel({
+        let requests = requests.clone();
+        move |method, request| {
+            if method.ends_with("/PrepareDataQuery") {
+                return operation_result_response(PrepareQueryResult {
+                    query_id: "query-id".to_string(),
+                    ..PrepareQueryResult::default()
+                });
+            }
+
+            requests
+                .lock()
+                .unwrap()
+                .push(ExecuteDataQueryRequest::decode(request).unwrap());
+            operation_result_response(ExecuteQueryResult {
+                tx_meta: Some(TransactionMeta::default()),
+                query_stats: Some(QueryStats {
+                    total_duration_us: 10,
+                    ..QueryStats::default()
+                }),
+                ..ExecuteQueryResult::default()
+            })
+        }
+    });
+    let mut session = Session::new(
+        "session".to_string(),
+        FakeTableService { channel },
+        TimeoutSettings::default(),
+    );
+
+    let prepared = session
+        .prepare(Query::new("SELECT 1").with_stats(StatsMode::Basic))
+        .await?;
+    let res = session.execute_prepared(prepared).await?;
+    assert!(res.stats().is_some());
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(1, requests.len());
+    assert_eq!(Mode::StatsCollectionBasic as i32, requests[0].collect_stats);
+    assert!(requests[0]
+        .query_cache_policy
+        .as_ref()
+        .is_some_and(|policy| policy.keep_in_cache));
+    Ok(())
+}
+
+#[tokio::test]
+async fn failed_commit_keeps_transaction() -> YdbResult<()> {
+    let commits = Arc::new(AtomicUsize::new(0));
+    let channel = fake_grpc_channel({
+        let commits = commits.clone();
+        move |_method, _request| {
+            if commits.fetch_add(1, Ordering::Relaxed) == 0 {
+                operation_response(StatusCode::Unavailable)
+            } else {
+                operation_result_response(CommitTransactionResult::default())
+            }
+        }
+    });
+    let mut session = Session::new(
+        "session".to_string(),
+        FakeTableService { channel },
+        TimeoutSettings::default(),
+    );
+    session.tx_id = Some("tx".to_string());
+
+    assert!(session.commit().await.is_err());
+    assert_eq!(Some("tx"), session.tx_id.as_deref());
+
+    session.commit().await?;
+    assert_eq!(None, session.tx_id);
+    assert_eq!(2, commits.load(Ordering::Relaxed));
+    Ok(())
+}
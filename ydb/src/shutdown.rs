@@ -0,0 +1,132 @@
+#[cfg(test)]
+#[path = "shutdown_test.rs"]
+mod shutdown_test;
+
+use crate::errors::{YdbError, YdbResult};
+use async_trait::async_trait;
+use futures_util::future::join_all;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::time::Instant;
+use tokio_util::sync::CancellationToken;
+use tracing::trace;
+
+const IN_FLIGHT_CHECK_INTERVAL: Duration = Duration::from_millis(10);
+
+// Session pool, registered for close with client
+#[async_trait]
+pub(crate) trait ClosableSessionPool: Send + Sync {
+    // false if the pool already dropped
+    fn is_alive(&self) -> bool;
+
+    // count of sessions in use
+    fn in_use(&self) -> usize;
+
+    // remove idle sessions from the pool and close them on server side
+    async fn close_idle_sessions(&self);
+}
+
+// Topic writer, registered for flush buffered messages while close client
+#[async_trait]
+pub(crate) trait FlushableWriter: Send + Sync {
+    // false if the writer already dropped
+    fn is_alive(&self) -> bool;
+
+    // wait acknowledgement of all written messages
+    async fn flush(&self) -> YdbResult<()>;
+}
+
+/// Shared shutdown state of a client and all clients, sessions, readers, etc. created from it
+#[derive(Clone, Default)]
+pub(crate) struct ClientShutdown {
+    inner: Arc<ClientShutdownInner>,
+}
+
+#[derive(Default)]
+struct ClientShutdownInner {
+    // client doesn't start new operations
+    closing: AtomicBool,
+
+    // stop all background tasks
+    stop: CancellationToken,
+
+    session_pools: Mutex<Vec<Box<dyn ClosableSessionPool>>>,
+
+    topic_writers: Mutex<Vec<Box<dyn FlushableWriter>>>,
+}
+
+impl ClientShutdown {
+    pub(crate) fn is_closing(&self) -> bool {
+        self.inner.closing.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn check_not_closing(&self) -> YdbResult<()> {
+        if self.is_closing() {
+            Err(YdbError::Cancelled("client is closed".to_string()))
+        } else {
+            Ok(())
+        }
+    }
+
+    // Token, cancelled after client closed
+    pub(crate) fn stop_token(&self) -> CancellationToken {
+        self.inner.stop.child_token()
+    }
+
+    pub(crate) fn register_session_pool(&self, pool: impl ClosableSessionPool + 'static) {
+        let mut pools = self.inner.session_pools.lock().unwrap();
+        pools.retain(|pool| pool.is_alive());
+        pools.push(Box::new(pool));
+    }
+
+    pub(crate) fn register_topic_writer(&self, writer: impl FlushableWriter + 'static) {
+        let mut writers = self.inner.topic_writers.lock().unwrap();
+        writers.retain(|writer| writer.is_alive());
+        writers.push(Box::new(writer));
+    }
+
+    pub(crate) async fn close(&self, timeout: Duration) -> YdbResult<()> {
+        let deadline = Instant::now() + timeout;
+        self.inner.closing.store(true, Ordering::Relaxed);
+
+        let pools = std::mem::take(&mut *self.inner.session_pools.lock().unwrap());
+        let writers = std::mem::take(&mut *self.inner.topic_writers.lock().unwrap());
+
+        trace!("wait in-flight operations and flush topic writers");
+        let wait_sessions = async {
+            while pools.iter().any(|pool| pool.in_use() > 0) {
+                tokio::time::sleep(IN_FLIGHT_CHECK_INTERVAL).await;
+            }
+        };
+        let flush_writers = join_all(writers.iter().map(|writer| writer.flush()));
+        let (in_flight_completed, flush_result) = match tokio::time::timeout_at(
+            deadline,
+            futures_util::future::join(wait_sessions, flush_writers),
+        )
+        .await
+        {
+            Ok(((), flush_results)) => (true, flush_results.into_iter().collect::<YdbResult<()>>()),
+            Err(_) => (false, Ok(())),
+        };
+
+        trace!("close idle sessions");
+        let sessions_closed = tokio::time::timeout_at(
+            deadline,
+            join_all(pools.iter().map(|pool| pool.close_idle_sessions())),
+        )
+        .await
+        .is_ok();
+
+        trace!("stop background tasks");
+        self.inner.stop.cancel();
+
+        if in_flight_completed && sessions_closed {
+            flush_result
+        } else {
+            Err(YdbError::Timeout(format!(
+                "client close doesn't completed during {timeout:?}"
+            )))
+        }
+    }
+}
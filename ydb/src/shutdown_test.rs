@@ -0,0 +1,143 @@
+use crate::errors::{YdbError, YdbResult};
+use crate::shutdown::{ClientShutdown, ClosableSessionPool, FlushableWriter};
+use async_trait::async_trait;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+#[derive(Clone, Default)]
+struct PoolMock {
+    in_use: Arc<AtomicUsize>,
+    closed: Arc<AtomicUsize>,
+}
+
+#[async_trait]
+impl ClosableSessionPool for PoolMock {
+    fn is_alive(&self) -> bool {
+        true
+    }
+
+    fn in_use(&self) -> usize {
+        self.in_use.load(Ordering::Relaxed)
+    }
+
+    async fn close_idle_sessions(&self) {
+        self.closed.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+#[derive(Clone, Default)]
+struct WriterMock {
+    flushed: Arc<AtomicUsize>,
+    fail: bool,
+}
+
+#[async_trait]
+impl FlushableWriter for WriterMock {
+    fn is_alive(&self) -> bool {
+        true
+    }
+
+    async fn flush(&self) -> YdbResult<()> {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        self.flushed.fetch_add(1, Ordering::Relaxed);
+        if self.fail {
+            Err(YdbError::Custom("flush failed".to_string()))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[tokio::test]
+async fn close_without_pools() -> YdbResult<()> {
+    let shutdown = ClientShutdown::default();
+    let stop = shutdown.stop_token();
+
+    shutdown.check_not_closing()?;
+    shutdown.close(Duration::from_secs(1)).await?;
+
+    assert!(shutdown.is_closing());
+    assert!(stop.is_cancelled());
+    assert!(matches!(
+        shutdown.check_not_closing(),
+        Err(YdbError::Cancelled(_))
+    ));
+    Ok(())
+}
+
+#[tokio::test]
+async fn close_waits_in_flight_operations() -> YdbResult<()> {
+    let shutdown = ClientShutdown::default();
+    let stop = shutdown.stop_token();
+    let pool = PoolMock::default();
+    pool.in_use.store(1, Ordering::Relaxed);
+    shutdown.register_session_pool(pool.clone());
+
+    let in_use = pool.in_use.clone();
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        in_use.store(0, Ordering::Relaxed);
+    });
+
+    let close = tokio::spawn({
+        let shutdown = shutdown.clone();
+        async move { shutdown.close(Duration::from_secs(10)).await }
+    });
+
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    assert!(shutdown.is_closing());
+    assert!(!stop.is_cancelled());
+    assert_eq!(0, pool.closed.load(Ordering::Relaxed));
+
+    close.await??;
+    assert!(stop.is_cancelled());
+    assert_eq!(1, pool.closed.load(Ordering::Relaxed));
+    Ok(())
+}
+
+#[tokio::test]
+async fn close_timeout() -> YdbResult<()> {
+    let shutdown = ClientShutdown::default();
+    let stop = shutdown.stop_token();
+    let pool = PoolMock::default();
+    pool.in_use.store(1, Ordering::Relaxed);
+    shutdown.register_session_pool(pool.clone());
+
+    let res = shutdown.close(Duration::from_millis(50)).await;
+    assert!(matches!(res, Err(YdbError::Timeout(_))));
+
+    // background work stopped anyway
+    assert!(stop.is_cancelled());
+    assert_eq!(1, pool.closed.load(Ordering::Relaxed));
+    Ok(())
+}
+
+#[tokio::test]
+async fn close_flushes_topic_writers() -> YdbResult<()> {
+    let shutdown = ClientShutdown::default();
+    let stop = shutdown.stop_token();
+    let writer = WriterMock::default();
+    shutdown.register_topic_writer(writer.clone());
+
+    shutdown.close(Duration::from_secs(10)).await?;
+    assert_eq!(1, writer.flushed.load(Ordering::Relaxed));
+    assert!(stop.is_cancelled());
+    Ok(())
+}
+
+#[tokio::test]
+async fn close_returns_flush_error() -> YdbResult<()> {
+    let shutdown = ClientShutdown::default();
+    let stop = shutdown.stop_token();
+    let writer = WriterMock {
+        fail: true,
+        ..WriterMock::default()
+    };
+    shutdown.register_topic_writer(writer.clone());
+
+    let res = shutdown.close(Duration::from_secs(10)).await;
+    assert!(matches!(res, Err(YdbError::Custom(_))));
+    assert!(stop.is_cancelled());
+    Ok(())
+}
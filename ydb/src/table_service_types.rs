@@ -1,4 +1,25 @@
+#[cfg(test)]
+#[path = "table_service_types_test.rs"]
+mod table_service_types_test;
+
+use crate::errors::YdbResult;
+use crate::grpc_wrapper::raw_errors::RawError;
 use crate::grpc_wrapper::raw_table_service::copy_table::RawCopyTableItem;
+use crate::grpc_wrapper::raw_table_service::describe_table::RawDescribeTableResult;
+use crate::grpc_wrapper::raw_table_service::read_table::{RawKeyBound, RawKeyRange};
+use crate::grpc_wrapper::raw_table_service::rename_tables::RawRenameTableItem;
+use crate::grpc_wrapper::raw_table_service::table_description::{
+    RawChangefeed, RawChangefeedFormat, RawChangefeedMode, RawColumnFamily, RawColumnMeta,
+    RawCompression, RawIndexType, RawPartitionStats, RawPartitioningSettings,
+    RawReadReplicasSettings, RawTableIndex, RawTableStats, RawTtlSettings, RawTtlUnit,
+};
+use crate::grpc_wrapper::raw_table_service::value::r#type::{RawType, TupleType};
+use crate::grpc_wrapper::raw_table_service::value::{RawTypedValue, RawValue};
+use crate::Value;
+use derive_builder::Builder;
+use itertools::Itertools;
+use std::collections::HashMap;
+use std::time::{Duration, SystemTime};
 
 #[derive(Clone)]
 pub struct CopyTableItem {
@@ -23,3 +44,864 @@ impl From<CopyTableItem> for RawCopyTableItem {
         value.inner
     }
 }
+
+#[derive(Clone)]
+pub struct RenameTableItem {
+    inner: RawRenameTableItem,
+}
+
+impl RenameTableItem {
+    pub fn new(source_path: String, destination_path: String, replace_destination: bool) -> Self {
+        Self {
+            inner: RawRenameTableItem {
+                source_path,
+                destination_path,
+                replace_destination,
+            },
+        }
+    }
+}
+
+impl From<RenameTableItem> for RawRenameTableItem {
+    fn from(value: RenameTableItem) -> Self {
+        value.inner
+    }
+}
+
+/// Description of table for create table, also returned by describe table
+///
+/// Example:
+/// ```no_run
+/// # use ydb::YdbResult;
+/// #
+/// # #[tokio::main]
+/// # async fn main()->YdbResult<()>{
+/// #   use ydb::{TableColumnBuilder, TableDescriptionBuilder, TableIndexBuilder, TableIndexType, Value};
+/// #   let table_client = ydb::ClientBuilder::new_from_connection_string("")?.client()?.table_client();
+///     let description = TableDescriptionBuilder::default()
+///         .column(TableColumnBuilder::default().name("id").type_example(Value::Int64(0)).build()?)
+///         .column(
+///             TableColumnBuilder::default()
+///                 .name("name")
+///                 .type_example(Value::from(None::<String>))
+///                 .build()?,
+///         )
+///         .primary_key_column("id".to_string())
+///         .index(
+///             TableIndexBuilder::default()
+///                 .name("by_name")
+///                 .index_columns(vec!["name".to_string()])
+///                 .index_type(TableIndexType::GlobalAsync)
+///                 .build()?,
+///         )
+///         .build()?;
+///     table_client.create_table("my_table".to_string(), description).await?;
+/// #     return Ok(());
+/// # }
+/// ```
+#[derive(Builder, Clone, Debug)]
+#[builder(build_fn(error = "crate::errors::YdbError"))]
+#[cfg_attr(not(feature = "force-exhaustive-all"), non_exhaustive)]
+pub struct TableDescription {
+    // Use TableDescriptionBuilder
+    #[builder(setter(each(name = "column")))]
+    pub columns: Vec<TableColumn>,
+
+    #[builder(setter(each(name = "primary_key_column")))]
+    pub primary_key: Vec<String>,
+
+    #[builder(setter(each(name = "index")), default)]
+    pub indexes: Vec<TableIndex>,
+
+    #[builder(setter(strip_option), default)]
+    pub ttl_settings: Option<TtlSettings>,
+
+    #[builder(setter(strip_option), default)]
+    pub partitioning_settings: Option<TablePartitioningSettings>,
+
+    /// Create table with the count of partitions, split by uniform intervals of first key column.
+    /// The first key column must be Uint32 or Uint64.
+    #[builder(setter(strip_option), default)]
+    pub uniform_partitions: Option<u64>,
+
+    #[builder(setter(each(name = "column_family")), default)]
+    pub column_families: Vec<ColumnFamily>,
+
+    #[builder(setter(strip_option), default)]
+    pub read_replicas_settings: Option<ReadReplicasSettings>,
+
+    /// Changefeeds are added by alter table request right after create table
+    #[builder(setter(each(name = "changefeed")), default)]
+    pub changefeeds: Vec<Changefeed>,
+
+    #[builder(default)]
+    pub attributes: HashMap<String, String>,
+
+    /// Partition boundaries: values of first primary key columns for end of every partition
+    /// except the last.
+    /// Filled by describe table with include_shard_key_bounds option, ignored by create table.
+    #[builder(setter(skip))]
+    pub shard_key_bounds: Vec<Vec<Value>>,
+
+    /// Filled by describe table with include_table_stats option, ignored by create table.
+    #[builder(setter(skip))]
+    pub table_stats: Option<TableStats>,
+}
+
+impl TryFrom<RawDescribeTableResult> for TableDescription {
+    type Error = RawError;
+
+    fn try_from(value: RawDescribeTableResult) -> Result<Self, Self::Error> {
+        Ok(Self {
+            columns: value
+                .columns
+                .into_iter()
+                .map(TableColumn::try_from)
+                .try_collect()?,
+            primary_key: value.primary_key,
+            indexes: value.indexes.into_iter().map_into().collect(),
+            ttl_settings: value.ttl_settings.map(Into::into),
+            partitioning_settings: value.partitioning_settings.map(Into::into),
+            uniform_partitions: None,
+            column_families: value.column_families.into_iter().map_into().collect(),
+            read_replicas_settings: value.read_replicas_settings.map(Into::into),
+            changefeeds: value.changefeeds.into_iter().map_into().collect(),
+            attributes: value.attributes,
+            shard_key_bounds: value
+                .shard_key_bounds
+                .into_iter()
+                .map(key_bound_values)
+                .try_collect()?,
+            table_stats: value.table_stats.map(Into::into),
+        })
+    }
+}
+
+// shard key bound is tuple of values for prefix of primary key columns
+fn key_bound_values(bound: RawTypedValue) -> Result<Vec<Value>, RawError> {
+    match (bound.r#type, bound.value) {
+        (RawType::Tuple(tuple), RawValue::Items(items)) => {
+            if tuple.elements.len() != items.len() {
+                return Err(RawError::custom(format!(
+                    "different len of types and values in key bound: {} and {}",
+                    tuple.elements.len(),
+                    items.len()
+                )));
+            }
+            tuple
+                .elements
+                .into_iter()
+                .zip(items)
+                .map(|(r#type, value)| Value::try_from(RawTypedValue { r#type, value }))
+                .try_collect()
+        }
+        (r#type, value) => Ok(vec![Value::try_from(RawTypedValue { r#type, value })?]),
+    }
+}
+
+fn key_tuple(values: Vec<Value>) -> Result<RawTypedValue, RawError> {
+    let (types, values): (Vec<_>, Vec<_>) = values
+        .into_iter()
+        .map(RawTypedValue::try_from)
+        .map_ok(|typed| (typed.r#type, typed.value))
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .unzip();
+
+    Ok(RawTypedValue {
+        r#type: RawType::Tuple(TupleType { elements: types }),
+        value: RawValue::Items(values),
+    })
+}
+
+/// Options for describe table
+#[derive(Builder, Clone, Debug, Default)]
+#[builder(build_fn(error = "crate::errors::YdbError"))]
+pub struct DescribeTableOptions {
+    // Use DescribeTableOptionsBuilder
+    /// Fill shard_key_bounds of the description
+    #[builder(default)]
+    pub include_shard_key_bounds: bool,
+
+    /// Fill table_stats of the description
+    #[builder(default)]
+    pub include_table_stats: bool,
+
+    /// Fill partition_stats of table stats, require include_table_stats
+    #[builder(default)]
+    pub include_partition_stats: bool,
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(not(feature = "force-exhaustive-all"), non_exhaustive)]
+pub struct TableStats {
+    /// Filled with include_partition_stats option only
+    pub partition_stats: Vec<TablePartitionStats>,
+
+    /// Approximate number of rows in the table
+    pub rows_estimate: u64,
+
+    /// Approximate size of the table in bytes
+    pub store_size: u64,
+
+    pub partitions: u64,
+
+    pub creation_time: Option<SystemTime>,
+
+    pub modification_time: Option<SystemTime>,
+}
+
+impl From<RawTableStats> for TableStats {
+    fn from(value: RawTableStats) -> Self {
+        Self {
+            partition_stats: value.partition_stats.into_iter().map_into().collect(),
+            rows_estimate: value.rows_estimate,
+            store_size: value.store_size,
+            partitions: value.partitions,
+            creation_time: value.creation_time.map(Into::into),
+            modification_time: value.modification_time.map(Into::into),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(not(feature = "force-exhaustive-all"), non_exhaustive)]
+pub struct TablePartitionStats {
+    /// Approximate number of rows in the partition
+    pub rows_estimate: u64,
+
+    /// Approximate size of the partition in bytes
+    pub store_size: u64,
+}
+
+impl From<RawPartitionStats> for TablePartitionStats {
+    fn from(value: RawPartitionStats) -> Self {
+        Self {
+            rows_estimate: value.rows_estimate,
+            store_size: value.store_size,
+        }
+    }
+}
+
+#[derive(Builder, Clone, Debug)]
+#[builder(build_fn(error = "crate::errors::YdbError"))]
+pub struct TableColumn {
+    // Use TableColumnBuilder
+    #[builder(setter(into))]
+    pub name: String,
+
+    /// Example of value with column type, for example `Value::Int64(0)` or
+    /// `Value::from(None::<String>)` for nullable text column
+    pub type_example: Value,
+
+    #[builder(setter(into, strip_option), default)]
+    pub family: Option<String>,
+
+    #[builder(setter(strip_option), default)]
+    pub not_null: Option<bool>,
+}
+
+impl TryFrom<TableColumn> for RawColumnMeta {
+    type Error = RawError;
+
+    fn try_from(value: TableColumn) -> Result<Self, Self::Error> {
+        Ok(Self {
+            name: value.name,
+            column_type: RawTypedValue::try_from(value.type_example)?.r#type,
+            family: value.family.unwrap_or_default(),
+            not_null: value.not_null,
+        })
+    }
+}
+
+impl TryFrom<RawColumnMeta> for TableColumn {
+    type Error = RawError;
+
+    fn try_from(value: RawColumnMeta) -> Result<Self, Self::Error> {
+        Ok(Self {
+            name: value.name,
+            type_example: value.column_type.into_value_example()?,
+            family: Some(value.family).filter(|family| !family.is_empty()),
+            not_null: value.not_null,
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(not(feature = "force-exhaustive-all"), non_exhaustive)]
+pub enum TableIndexType {
+    /// Global index, updated synchronously with table
+    #[default]
+    Global,
+
+    /// Global index, updated asynchronously, reads from the index may see stale data
+    GlobalAsync,
+
+    /// Global index with unique constraint for index columns
+    GlobalUnique,
+
+    /// Index type, unknown for the SDK. Use for debug/log only.
+    Unknown,
+}
+
+impl From<TableIndexType> for RawIndexType {
+    fn from(value: TableIndexType) -> Self {
+        match value {
+            TableIndexType::Global => RawIndexType::Global,
+            TableIndexType::GlobalAsync => RawIndexType::GlobalAsync,
+            TableIndexType::GlobalUnique => RawIndexType::GlobalUnique,
+            TableIndexType::Unknown => RawIndexType::Unknown,
+        }
+    }
+}
+
+impl From<RawIndexType> for TableIndexType {
+    fn from(value: RawIndexType) -> Self {
+        match value {
+            RawIndexType::Global => TableIndexType::Global,
+            RawIndexType::GlobalAsync => TableIndexType::GlobalAsync,
+            RawIndexType::GlobalUnique => TableIndexType::GlobalUnique,
+            RawIndexType::Unknown => TableIndexType::Unknown,
+        }
+    }
+}
+
+#[derive(Builder, Clone, Debug)]
+#[builder(build_fn(error = "crate::errors::YdbError"))]
+pub struct TableIndex {
+    // Use TableIndexBuilder
+    #[builder(setter(into))]
+    pub name: String,
+
+    pub index_columns: Vec<String>,
+
+    /// Columns, copied to the index for read it without access to main table
+    #[builder(default)]
+    pub data_columns: Vec<String>,
+
+    #[builder(default)]
+    pub index_type: TableIndexType,
+}
+
+impl From<TableIndex> for RawTableIndex {
+    fn from(value: TableIndex) -> Self {
+        Self {
+            name: value.name,
+            index_columns: value.index_columns,
+            data_columns: value.data_columns,
+            index_type: value.index_type.into(),
+        }
+    }
+}
+
+impl From<RawTableIndex> for TableIndex {
+    fn from(value: RawTableIndex) -> Self {
+        Self {
+            name: value.name,
+            index_columns: value.index_columns,
+            data_columns: value.data_columns,
+            index_type: value.index_type.into(),
+        }
+    }
+}
+
+/// Unit of value in ttl column with numeric type
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TtlColumnUnit {
+    Seconds,
+    Milliseconds,
+    Microseconds,
+    Nanoseconds,
+}
+
+impl From<TtlColumnUnit> for RawTtlUnit {
+    fn from(value: TtlColumnUnit) -> Self {
+        match value {
+            TtlColumnUnit::Seconds => RawTtlUnit::Seconds,
+            TtlColumnUnit::Milliseconds => RawTtlUnit::Milliseconds,
+            TtlColumnUnit::Microseconds => RawTtlUnit::Microseconds,
+            TtlColumnUnit::Nanoseconds => RawTtlUnit::Nanoseconds,
+        }
+    }
+}
+
+impl From<RawTtlUnit> for TtlColumnUnit {
+    fn from(value: RawTtlUnit) -> Self {
+        match value {
+            RawTtlUnit::Seconds => TtlColumnUnit::Seconds,
+            RawTtlUnit::Milliseconds => TtlColumnUnit::Milliseconds,
+            RawTtlUnit::Microseconds => TtlColumnUnit::Microseconds,
+            RawTtlUnit::Nanoseconds => TtlColumnUnit::Nanoseconds,
+        }
+    }
+}
+
+/// Time to live settings: rows expire after expire_after since value of the column
+#[derive(Builder, Clone, Debug)]
+#[builder(build_fn(error = "crate::errors::YdbError"))]
+pub struct TtlSettings {
+    // Use TtlSettingsBuilder
+    #[builder(setter(into))]
+    pub column_name: String,
+
+    pub expire_after: Duration,
+
+    /// Unit of column value for numeric columns (Uint32, Uint64, DyNumber).
+    /// None for columns with date types.
+    #[builder(setter(strip_option), default)]
+    pub column_unit: Option<TtlColumnUnit>,
+
+    /// How often the server check the table for expired rows
+    #[builder(setter(strip_option), default)]
+    pub run_interval: Option<Duration>,
+}
+
+impl TryFrom<TtlSettings> for RawTtlSettings {
+    type Error = RawError;
+
+    fn try_from(value: TtlSettings) -> Result<Self, Self::Error> {
+        Ok(Self {
+            column_name: value.column_name,
+            expire_after_seconds: duration_to_seconds(value.expire_after)?,
+            column_unit: value.column_unit.map(Into::into),
+            run_interval_seconds: value
+                .run_interval
+                .map(duration_to_seconds)
+                .transpose()?
+                .unwrap_or_default(),
+        })
+    }
+}
+
+impl From<RawTtlSettings> for TtlSettings {
+    fn from(value: RawTtlSettings) -> Self {
+        Self {
+            column_name: value.column_name,
+            expire_after: Duration::from_secs(value.expire_after_seconds.into()),
+            column_unit: value.column_unit.map(Into::into),
+            run_interval: Some(value.run_interval_seconds)
+                .filter(|seconds| *seconds > 0)
+                .map(|seconds| Duration::from_secs(seconds.into())),
+        }
+    }
+}
+
+#[derive(Builder, Clone, Debug, Default)]
+#[builder(build_fn(error = "crate::errors::YdbError"))]
+pub struct TablePartitioningSettings {
+    // Use TablePartitioningSettingsBuilder
+    #[builder(default)]
+    pub partition_by: Vec<String>,
+
+    #[builder(setter(strip_option), default)]
+    pub partitioning_by_size: Option<bool>,
+
+    #[builder(setter(strip_option), default)]
+    pub partition_size_mb: Option<u64>,
+
+    #[builder(setter(strip_option), default)]
+    pub partitioning_by_load: Option<bool>,
+
+    #[builder(setter(strip_option), default)]
+    pub min_partitions_count: Option<u64>,
+
+    #[builder(setter(strip_option), default)]
+    pub max_partitions_count: Option<u64>,
+}
+
+impl From<TablePartitioningSettings> for RawPartitioningSettings {
+    fn from(value: TablePartitioningSettings) -> Self {
+        Self {
+            partition_by: value.partition_by,
+            partitioning_by_size: value.partitioning_by_size,
+            partition_size_mb: value.partition_size_mb.unwrap_or_default(),
+            partitioning_by_load: value.partitioning_by_load,
+            min_partitions_count: value.min_partitions_count.unwrap_or_default(),
+            max_partitions_count: value.max_partitions_count.unwrap_or_default(),
+        }
+    }
+}
+
+impl From<RawPartitioningSettings> for TablePartitioningSettings {
+    fn from(value: RawPartitioningSettings) -> Self {
+        fn non_zero(val: u64) -> Option<u64> {
+            Some(val).filter(|val| *val > 0)
+        }
+
+        Self {
+            partition_by: value.partition_by,
+            partitioning_by_size: value.partitioning_by_size,
+            partition_size_mb: non_zero(value.partition_size_mb),
+            partitioning_by_load: value.partitioning_by_load,
+            min_partitions_count: non_zero(value.min_partitions_count),
+            max_partitions_count: non_zero(value.max_partitions_count),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColumnFamilyCompression {
+    None,
+    Lz4,
+}
+
+#[derive(Builder, Clone, Debug)]
+#[builder(build_fn(error = "crate::errors::YdbError"))]
+pub struct ColumnFamily {
+    // Use ColumnFamilyBuilder
+    /// Name of the column family, "default" for family with primary key columns
+    #[builder(setter(into))]
+    pub name: String,
+
+    /// Storage media for data of the family, for example "ssd" or "hdd"
+    #[builder(setter(into, strip_option), default)]
+    pub data_media: Option<String>,
+
+    #[builder(setter(strip_option), default)]
+    pub compression: Option<ColumnFamilyCompression>,
+}
+
+impl From<ColumnFamily> for RawColumnFamily {
+    fn from(value: ColumnFamily) -> Self {
+        Self {
+            name: value.name,
+            data_media: value.data_media,
+            compression: match value.compression {
+                None => RawCompression::Unspecified,
+                Some(ColumnFamilyCompression::None) => RawCompression::None,
+                Some(ColumnFamilyCompression::Lz4) => RawCompression::Lz4,
+            },
+        }
+    }
+}
+
+impl From<RawColumnFamily> for ColumnFamily {
+    fn from(value: RawColumnFamily) -> Self {
+        Self {
+            name: value.name,
+            data_media: value.data_media,
+            compression: match value.compression {
+                RawCompression::Unspecified => None,
+                RawCompression::None => Some(ColumnFamilyCompression::None),
+                RawCompression::Lz4 => Some(ColumnFamilyCompression::Lz4),
+            },
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReadReplicasSettings {
+    /// Same count of read replicas in every availability zone
+    PerAzReadReplicasCount(u64),
+
+    /// Total count of read replicas in all availability zones
+    AnyAzReadReplicasCount(u64),
+}
+
+impl From<ReadReplicasSettings> for RawReadReplicasSettings {
+    fn from(value: ReadReplicasSettings) -> Self {
+        match value {
+            ReadReplicasSettings::PerAzReadReplicasCount(count) => {
+                RawReadReplicasSettings::PerAzReadReplicasCount(count)
+            }
+            ReadReplicasSettings::AnyAzReadReplicasCount(count) => {
+                RawReadReplicasSettings::AnyAzReadReplicasCount(count)
+            }
+        }
+    }
+}
+
+impl From<RawReadReplicasSettings> for ReadReplicasSettings {
+    fn from(value: RawReadReplicasSettings) -> Self {
+        match value {
+            RawReadReplicasSettings::PerAzReadReplicasCount(count) => {
+                ReadReplicasSettings::PerAzReadReplicasCount(count)
+            }
+            RawReadReplicasSettings::AnyAzReadReplicasCount(count) => {
+                ReadReplicasSettings::AnyAzReadReplicasCount(count)
+            }
+        }
+    }
+}
+
+/// Information, written to changefeed for every changed row
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChangefeedMode {
+    KeysOnly,
+    Updates,
+    NewImage,
+    OldImage,
+    NewAndOldImages,
+
+    // no use Unknown for own logic (use for debug/log only) - for prevent broke your code when new mode will be defined.
+    Unknown(i32),
+}
+
+impl From<ChangefeedMode> for RawChangefeedMode {
+    fn from(value: ChangefeedMode) -> Self {
+        match value {
+            ChangefeedMode::KeysOnly => RawChangefeedMode::KeysOnly,
+            ChangefeedMode::Updates => RawChangefeedMode::Updates,
+            ChangefeedMode::NewImage => RawChangefeedMode::NewImage,
+            ChangefeedMode::OldImage => RawChangefeedMode::OldImage,
+            ChangefeedMode::NewAndOldImages => RawChangefeedMode::NewAndOldImages,
+            ChangefeedMode::Unknown(value) => RawChangefeedMode::Unknown(value),
+        }
+    }
+}
+
+impl From<RawChangefeedMode> for ChangefeedMode {
+    fn from(value: RawChangefeedMode) -> Self {
+        match value {
+            RawChangefeedMode::KeysOnly => ChangefeedMode::KeysOnly,
+            RawChangefeedMode::Updates => ChangefeedMode::Updates,
+            RawChangefeedMode::NewImage => ChangefeedMode::NewImage,
+            RawChangefeedMode::OldImage => ChangefeedMode::OldImage,
+            RawChangefeedMode::NewAndOldImages => ChangefeedMode::NewAndOldImages,
+            RawChangefeedMode::Unknown(value) => ChangefeedMode::Unknown(value),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ChangefeedFormat {
+    #[default]
+    Json,
+    DynamodbStreamsJson,
+    DebeziumJson,
+
+    // no use Unknown for own logic (use for debug/log only) - for prevent broke your code when new format will be defined.
+    Unknown(i32),
+}
+
+impl From<ChangefeedFormat> for RawChangefeedFormat {
+    fn from(value: ChangefeedFormat) -> Self {
+        match value {
+            ChangefeedFormat::Json => RawChangefeedFormat::Json,
+            ChangefeedFormat::DynamodbStreamsJson => RawChangefeedFormat::DynamodbStreamsJson,
+            ChangefeedFormat::DebeziumJson => RawChangefeedFormat::DebeziumJson,
+            ChangefeedFormat::Unknown(value) => RawChangefeedFormat::Unknown(value),
+        }
+    }
+}
+
+impl From<RawChangefeedFormat> for ChangefeedFormat {
+    fn from(value: RawChangefeedFormat) -> Self {
+        match value {
+            RawChangefeedFormat::Json => ChangefeedFormat::Json,
+            RawChangefeedFormat::DynamodbStreamsJson => ChangefeedFormat::DynamodbStreamsJson,
+            RawChangefeedFormat::DebeziumJson => ChangefeedFormat::DebeziumJson,
+            RawChangefeedFormat::Unknown(value) => ChangefeedFormat::Unknown(value),
+        }
+    }
+}
+
+#[derive(Builder, Clone, Debug)]
+#[builder(build_fn(error = "crate::errors::YdbError"))]
+pub struct Changefeed {
+    // Use ChangefeedBuilder
+    #[builder(setter(into))]
+    pub name: String,
+
+    pub mode: ChangefeedMode,
+
+    #[builder(default)]
+    pub format: ChangefeedFormat,
+
+    /// How long changes stored in the changefeed topic
+    #[builder(setter(strip_option), default)]
+    pub retention_period: Option<Duration>,
+
+    #[builder(default)]
+    pub virtual_timestamps: bool,
+
+    /// Write current state of the table to the changefeed before changes
+    #[builder(default)]
+    pub initial_scan: bool,
+
+    #[builder(default)]
+    pub attributes: HashMap<String, String>,
+
+    #[builder(setter(strip_option), default)]
+    pub resolved_timestamps_interval: Option<Duration>,
+}
+
+impl From<Changefeed> for RawChangefeed {
+    fn from(value: Changefeed) -> Self {
+        Self {
+            name: value.name,
+            mode: value.mode.into(),
+            format: value.format.into(),
+            retention_period: value.retention_period.map(Into::into),
+            virtual_timestamps: value.virtual_timestamps,
+            initial_scan: value.initial_scan,
+            attributes: value.attributes,
+            resolved_timestamps_interval: value.resolved_timestamps_interval.map(Into::into),
+        }
+    }
+}
+
+// retention period and initial scan are not returned by describe table
+impl From<RawChangefeed> for Changefeed {
+    fn from(value: RawChangefeed) -> Self {
+        Self {
+            name: value.name,
+            mode: value.mode.into(),
+            format: value.format.into(),
+            retention_period: value.retention_period.map(Into::into),
+            virtual_timestamps: value.virtual_timestamps,
+            initial_scan: value.initial_scan,
+            attributes: value.attributes,
+            resolved_timestamps_interval: value.resolved_timestamps_interval.map(Into::into),
+        }
+    }
+}
+
+/// Options for alter table
+#[derive(Builder, Clone, Debug, Default)]
+#[builder(build_fn(error = "crate::errors::YdbError"))]
+pub struct AlterTableOptions {
+    // Use AlterTableOptionsBuilder
+    #[builder(setter(each(name = "add_column")), default)]
+    pub add_columns: Vec<TableColumn>,
+
+    #[builder(setter(each(name = "drop_column")), default)]
+    pub drop_columns: Vec<String>,
+
+    #[builder(setter(each(name = "add_index")), default)]
+    pub add_indexes: Vec<TableIndex>,
+
+    #[builder(setter(each(name = "drop_index")), default)]
+    pub drop_indexes: Vec<String>,
+
+    #[builder(setter(strip_option), default)]
+    pub set_ttl_settings: Option<TtlSettings>,
+
+    #[builder(default)]
+    pub drop_ttl_settings: bool,
+
+    #[builder(setter(strip_option), default)]
+    pub set_partitioning_settings: Option<TablePartitioningSettings>,
+
+    #[builder(setter(each(name = "add_column_family")), default)]
+    pub add_column_families: Vec<ColumnFamily>,
+
+    #[builder(setter(each(name = "alter_column_family")), default)]
+    pub alter_column_families: Vec<ColumnFamily>,
+
+    #[builder(setter(strip_option), default)]
+    pub set_read_replicas_settings: Option<ReadReplicasSettings>,
+
+    #[builder(setter(each(name = "add_changefeed")), default)]
+    pub add_changefeeds: Vec<Changefeed>,
+
+    #[builder(setter(each(name = "drop_changefeed")), default)]
+    pub drop_changefeeds: Vec<String>,
+
+    /// Empty value - remove attribute
+    #[builder(default)]
+    pub alter_attributes: HashMap<String, String>,
+}
+
+fn duration_to_seconds(duration: Duration) -> Result<u32, RawError> {
+    u32::try_from(duration.as_secs())
+        .map_err(|_| RawError::custom(format!("too long duration: {duration:?}")))
+}
+
+pub(crate) fn convert_vec<T, R>(items: Vec<T>) -> YdbResult<Vec<R>>
+where
+    R: TryFrom<T, Error = RawError>,
+{
+    items
+        .into_iter()
+        .map(|item| R::try_from(item).map_err(Into::into))
+        .collect()
+}
+
+/// Bound of primary key range
+///
+/// Values are for prefix of primary key columns in order of the primary key and must have exact
+/// types of the columns. Columns without `NOT NULL` are nullable and have type `Optional<T>`,
+/// for example `vec![Value::from(Some(10_i64))]` for table `(id Int64, name Utf8, PRIMARY KEY (id, name))`
+/// and `vec![Value::Int64(10)]` if `id` declared as `Int64 NOT NULL`.
+/// Bounds from [TableDescription::shard_key_bounds] may be used as is.
+#[derive(Clone, Debug)]
+pub struct KeyBound {
+    pub key: Vec<Value>,
+    pub inclusive: bool,
+}
+
+impl KeyBound {
+    pub fn inclusive(key: Vec<Value>) -> Self {
+        Self {
+            key,
+            inclusive: true,
+        }
+    }
+
+    pub fn exclusive(key: Vec<Value>) -> Self {
+        Self {
+            key,
+            inclusive: false,
+        }
+    }
+}
+
+impl TryFrom<KeyBound> for RawKeyBound {
+    type Error = RawError;
+
+    fn try_from(value: KeyBound) -> Result<Self, Self::Error> {
+        Ok(Self {
+            value: key_tuple(value.key)?,
+            inclusive: value.inclusive,
+        })
+    }
+}
+
+/// Range of primary keys, without bound the range is not limited from the side
+#[derive(Builder, Clone, Debug, Default)]
+#[builder(build_fn(error = "crate::errors::YdbError"))]
+pub struct KeyRange {
+    // Use KeyRangeBuilder
+    #[builder(setter(strip_option), default)]
+    pub from: Option<KeyBound>,
+
+    #[builder(setter(strip_option), default)]
+    pub to: Option<KeyBound>,
+}
+
+impl TryFrom<KeyRange> for RawKeyRange {
+    type Error = RawError;
+
+    fn try_from(value: KeyRange) -> Result<Self, Self::Error> {
+        Ok(Self {
+            from: value.from.map(RawKeyBound::try_from).transpose()?,
+            to: value.to.map(RawKeyBound::try_from).transpose()?,
+        })
+    }
+}
+
+/// Options for read table
+#[derive(Builder, Clone, Debug, Default)]
+#[builder(build_fn(error = "crate::errors::YdbError"))]
+pub struct ReadTableOptions {
+    // Use ReadTableOptionsBuilder
+    /// Columns for read, empty for read all columns
+    #[builder(setter(each(name = "column", into)), default)]
+    pub columns: Vec<String>,
+
+    #[builder(setter(strip_option), default)]
+    pub key_range: Option<KeyRange>,
+
+    /// Max count of rows for read, without limit if None
+    #[builder(setter(strip_option), default)]
+    pub row_limit: Option<u64>,
+
+    /// Return rows ordered by primary key
+    #[builder(default)]
+    pub ordered: bool,
+
+    /// Read from consistent snapshot of the table, server default if None
+    #[builder(setter(strip_option), default)]
+    pub use_snapshot: Option<bool>,
+}
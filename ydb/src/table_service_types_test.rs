@@ -0,0 +1,247 @@
+use crate::errors::YdbResult;
+use crate::grpc_wrapper::raw_table_service::describe_table::RawDescribeTableResult;
+use crate::grpc_wrapper::raw_table_service::read_table::RawKeyRange;
+use crate::grpc_wrapper::raw_table_service::table_description::{RawColumnMeta, RawTtlSettings};
+use crate::table_service_types::{
+    ChangefeedFormat, ChangefeedMode, KeyBound, KeyRangeBuilder, TableColumnBuilder,
+    TableDescription, TableIndexType, TtlColumnUnit, TtlSettingsBuilder,
+};
+use crate::Value;
+use std::time::Duration;
+use ydb_grpc::ydb_proto::r#type::{PrimitiveTypeId, Type as TypeKind};
+use ydb_grpc::ydb_proto::table::{
+    key_range, table_index_description, ttl_settings, value_since_unix_epoch_mode_settings,
+};
+use ydb_grpc::ydb_proto::table::{
+    ChangefeedDescription, ColumnMeta, DescribeTableResult, GlobalAsyncIndex, KeyRange,
+    PartitionStats, TableIndexDescription, TableStats, TtlSettings,
+    ValueSinceUnixEpochModeSettings,
+};
+use ydb_grpc::ydb_proto::{OptionalType, TupleType, Type, TypedValue};
+
+#[test]
+fn column_type_from_example() -> YdbResult<()> {
+    let column = TableColumnBuilder::default()
+        .name("val")
+        .type_example(Value::from(None::<i64>))
+        .family("default")
+        .build()?;
+
+    let proto: ColumnMeta = RawColumnMeta::try_from(column)?.into();
+    assert_eq!("val", proto.name);
+    assert_eq!("default", proto.family);
+
+    let Some(TypeKind::OptionalType(optional)) = proto.r#type.and_then(|t| t.r#type) else {
+        panic!("expected optional type");
+    };
+    assert_eq!(
+        Some(Box::new(Type {
+            r#type: Some(TypeKind::TypeId(PrimitiveTypeId::Int64 as i32))
+        })),
+        optional.item
+    );
+    Ok(())
+}
+
+#[test]
+fn ttl_settings_mode() -> YdbResult<()> {
+    let date_column = TtlSettingsBuilder::default()
+        .column_name("created_at")
+        .expire_after(Duration::from_secs(60))
+        .build()?;
+    let proto: TtlSettings = RawTtlSettings::try_from(date_column)?.into();
+    assert_eq!(0, proto.run_interval_seconds);
+    let Some(ttl_settings::Mode::DateTypeColumn(settings)) = proto.mode else {
+        panic!("expected date type column mode");
+    };
+    assert_eq!("created_at", settings.column_name);
+    assert_eq!(60, settings.expire_after_seconds);
+
+    let numeric_column = TtlSettingsBuilder::default()
+        .column_name("created_at_ms")
+        .expire_after(Duration::from_secs(60))
+        .column_unit(TtlColumnUnit::Milliseconds)
+        .run_interval(Duration::from_secs(10))
+        .build()?;
+    let proto: TtlSettings = RawTtlSettings::try_from(numeric_column)?.into();
+    assert_eq!(10, proto.run_interval_seconds);
+    let Some(ttl_settings::Mode::ValueSinceUnixEpoch(settings)) = proto.mode else {
+        panic!("expected value since unix epoch mode");
+    };
+    assert_eq!(
+        value_since_unix_epoch_mode_settings::Unit::Milliseconds as i32,
+        settings.column_unit
+    );
+    Ok(())
+}
+
+#[test]
+fn table_description_from_describe_result() -> YdbResult<()> {
+    let primitive = |id: PrimitiveTypeId| Type {
+        r#type: Some(TypeKind::TypeId(id as i32)),
+    };
+    let optional = |item: Type| Type {
+        r#type: Some(TypeKind::OptionalType(Box::new(OptionalType {
+            item: Some(Box::new(item)),
+        }))),
+    };
+    let key_bound = |val: u64| TypedValue {
+        r#type: Some(Type {
+            r#type: Some(TypeKind::TupleType(TupleType {
+                elements: vec![optional(primitive(PrimitiveTypeId::Uint64))],
+            })),
+        }),
+        value: Some(ydb_grpc::ydb_proto::Value {
+            items: vec![ydb_grpc::ydb_proto::Value {
+                value: Some(ydb_grpc::ydb_proto::value::Value::Uint64Value(val)),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }),
+    };
+
+    let proto = DescribeTableResult {
+        columns: vec![
+            ColumnMeta {
+                name: "id".to_string(),
+                r#type: Some(primitive(PrimitiveTypeId::Uint64)),
+                not_null: Some(true),
+                ..Default::default()
+            },
+            ColumnMeta {
+                name: "name".to_string(),
+                r#type: Some(optional(primitive(PrimitiveTypeId::Utf8))),
+                family: "default".to_string(),
+                ..Default::default()
+            },
+        ],
+        primary_key: vec!["id".to_string()],
+        shard_key_bounds: vec![key_bound(100), key_bound(200)],
+        indexes: vec![TableIndexDescription {
+            name: "by_name".to_string(),
+            index_columns: vec!["name".to_string()],
+            r#type: Some(table_index_description::Type::GlobalAsyncIndex(
+                GlobalAsyncIndex {},
+            )),
+            ..Default::default()
+        }],
+        table_stats: Some(TableStats {
+            partition_stats: vec![PartitionStats::default(); 3],
+            partitions: 3,
+            ..Default::default()
+        }),
+        ttl_settings: Some(TtlSettings {
+            run_interval_seconds: 0,
+            mode: Some(ttl_settings::Mode::ValueSinceUnixEpoch(
+                ValueSinceUnixEpochModeSettings {
+                    column_name: "id".to_string(),
+                    column_unit: value_since_unix_epoch_mode_settings::Unit::Seconds as i32,
+                    expire_after_seconds: 60,
+                },
+            )),
+        }),
+        ..Default::default()
+    };
+
+    let description = TableDescription::try_from(RawDescribeTableResult::try_from(proto)?)?;
+
+    assert_eq!(2, description.columns.len());
+    assert_eq!("id", description.columns[0].name);
+    assert_eq!(Value::Uint64(0), description.columns[0].type_example);
+    assert_eq!(Some(true), description.columns[0].not_null);
+    assert_eq!(None, description.columns[0].family);
+    assert_eq!(
+        Value::from(None::<String>),
+        description.columns[1].type_example
+    );
+    assert_eq!(Some("default".to_string()), description.columns[1].family);
+    assert_eq!(vec!["id".to_string()], description.primary_key);
+
+    assert_eq!(1, description.indexes.len());
+    assert_eq!(
+        TableIndexType::GlobalAsync,
+        description.indexes[0].index_type
+    );
+
+    let ttl = description.ttl_settings.expect("ttl settings");
+    assert_eq!(Some(TtlColumnUnit::Seconds), ttl.column_unit);
+    assert_eq!(Duration::from_secs(60), ttl.expire_after);
+    assert_eq!(None, ttl.run_interval);
+
+    assert_eq!(
+        vec![
+            vec![Value::from(Some(100_u64))],
+            vec![Value::from(Some(200_u64))]
+        ],
+        description.shard_key_bounds
+    );
+
+    let stats = description.table_stats.expect("table stats");
+    assert_eq!(3, stats.partitions);
+    assert_eq!(3, stats.partition_stats.len());
+    Ok(())
+}
+
+#[test]
+fn key_range_to_proto() -> YdbResult<()> {
+    let range = KeyRangeBuilder::default()
+        .from(KeyBound::exclusive(vec![
+            Value::Int64(1),
+            Value::Text("a".to_string()),
+        ]))
+        .build()?;
+    let proto: KeyRange = RawKeyRange::try_from(range)?.into();
+    assert!(proto.to_bound.is_none());
+
+    let Some(key_range::FromBound::Greater(bound)) = proto.from_bound else {
+        panic!("expected exclusive from bound");
+    };
+    let Some(TypeKind::TupleType(tuple)) = bound.r#type.and_then(|t| t.r#type) else {
+        panic!("expected tuple type");
+    };
+    assert_eq!(
+        vec![
+            Type {
+                r#type: Some(TypeKind::TypeId(PrimitiveTypeId::Int64 as i32))
+            },
+            Type {
+                r#type: Some(TypeKind::TypeId(PrimitiveTypeId::Utf8 as i32))
+            },
+        ],
+        tuple.elements
+    );
+    assert_eq!(2, bound.value.expect("tuple value").items.len());
+    Ok(())
+}
+
+#[test]
+fn unknown_index_type_and_changefeed_mode() -> YdbResult<()> {
+    let proto = DescribeTableResult {
+        indexes: vec![TableIndexDescription {
+            name: "without_type".to_string(),
+            index_columns: vec!["name".to_string()],
+            r#type: None,
+            ..Default::default()
+        }],
+        changefeeds: vec![ChangefeedDescription {
+            name: "feed".to_string(),
+            mode: 100,
+            format: 200,
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+
+    let description = TableDescription::try_from(RawDescribeTableResult::try_from(proto)?)?;
+
+    assert_eq!(TableIndexType::Unknown, description.indexes[0].index_type);
+    assert_eq!(
+        ChangefeedMode::Unknown(100),
+        description.changefeeds[0].mode
+    );
+    assert_eq!(
+        ChangefeedFormat::Unknown(200),
+        description.changefeeds[0].format
+    );
+    Ok(())
+}
@@ -1,7 +1,28 @@
-use crate::ClientBuilder;
+use crate::client::TimeoutSettings;
+use crate::client_query::session::CreateQueryClient;
+use crate::errors::YdbResult;
+use crate::grpc_wrapper::raw_query_service::client::RawQueryClient;
+use crate::grpc_wrapper::raw_table_service::client::RawTableClient;
+use crate::grpc_wrapper::raw_table_service::value::{RawColumn, RawResultSet, RawTypedValue};
+use crate::grpc_wrapper::runtime_interceptors::{InterceptedChannel, MultiInterceptor};
+use crate::session::{CreateTableClient, Session};
+use crate::session_pool::SessionFabric;
+use crate::{ClientBuilder, ResultSet, Value};
+use async_trait::async_trait;
+use hyper_util::rt::TokioIo;
 use once_cell::sync::Lazy;
+use prost::Message;
+use std::future::{ready, Ready};
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use tokio::io::DuplexStream;
 use tracing::trace;
 use url::Url;
+use ydb_grpc::google_proto_workaround::protobuf::Any;
+use ydb_grpc::ydb_proto::operations::Operation;
+use ydb_grpc::ydb_proto::status_ids::StatusCode;
+use ydb_grpc::ydb_proto::table::v1::table_service_client::TableServiceClient;
+use ydb_grpc::ydb_proto::table::AlterTableResponse;
 
 pub(crate) static CONNECTION_STRING: Lazy<String> = Lazy::new(|| {
     std::env::var("YDB_CONNECTION_STRING")
@@ -52,3 +73,179 @@ pub(crate) fn test_with_password_builder() -> ClientBuilder {
 pub(crate) fn test_custom_ca_client_builder() -> ClientBuilder {
     ClientBuilder::new_from_connection_string(get_custom_ca_connection_string()).unwrap()
 }
+
+// result set with columns typed by examples
+pub(crate) fn make_result_set(
+    columns: Vec<(&str, Value)>,
+    rows: Vec<Vec<Value>>,
+) -> YdbResult<ResultSet> {
+    let columns = columns
+        .into_iter()
+        .map(|(name, example)| {
+            Ok(RawColumn {
+                name: name.to_string(),
+                column_type: RawTypedValue::try_from(example)?.r#type,
+            })
+        })
+        .collect::<YdbResult<_>>()?;
+    let rows = rows
+        .into_iter()
+        .map(|row| {
+            row.into_iter()
+                .map(|value| Ok(RawTypedValue::try_from(value)?.value))
+                .collect::<YdbResult<_>>()
+        })
+        .collect::<YdbResult<_>>()?;
+
+    ResultSet::try_from(RawResultSet {
+        columns,
+        rows,
+        truncated: false,
+    })
+}
+
+type FakeGrpcHandler = Arc<dyn Fn(&str, &[u8]) -> Vec<u8> + Send + Sync>;
+
+// Channel to in-memory grpc server.
+// The server answers every request by handler result: handler receives method path
+// like "/Ydb.Table.V1.TableService/CreateTable" with encoded request message
+// and returns encoded response message.
+pub(crate) fn fake_grpc_channel(
+    handler: impl Fn(&str, &[u8]) -> Vec<u8> + Send + Sync + 'static,
+) -> InterceptedChannel {
+    let channel = tonic::transport::Endpoint::from_static("http://fake.server")
+        .connect_with_connector_lazy(FakeGrpcConnector {
+            handler: Arc::new(handler),
+        });
+    InterceptedChannel::new(channel, MultiInterceptor::new())
+}
+
+// Connect to new in-memory server for every connection
+struct FakeGrpcConnector {
+    handler: FakeGrpcHandler,
+}
+
+impl tower::Service<http::Uri> for FakeGrpcConnector {
+    type Response = TokioIo<DuplexStream>;
+    type Error = std::io::Error;
+    type Future = Ready<Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, _uri: http::Uri) -> Self::Future {
+        let (client, server) = tokio::io::duplex(64 * 1024);
+        tokio::spawn(serve_fake_grpc(server, self.handler.clone()));
+        ready(Ok(TokioIo::new(client)))
+    }
+}
+
+async fn serve_fake_grpc(io: DuplexStream, handler: FakeGrpcHandler) {
+    let Ok(mut connection) = h2::server::handshake(io).await else {
+        return;
+    };
+    while let Some(Ok((request, mut respond))) = connection.accept().await {
+        let handler = handler.clone();
+        tokio::spawn(async move {
+            let path = request.uri().path().to_string();
+            let mut body = request.into_body();
+            let mut request_frame = Vec::new();
+            while let Some(Ok(chunk)) = body.data().await {
+                let _ = body.flow_control().release_capacity(chunk.len());
+                request_frame.extend_from_slice(&chunk);
+            }
+
+            // grpc message frame: uncompressed flag, message length, message
+            let message = handler(&path, request_frame.get(5..).unwrap_or_default());
+            let mut frame = vec![0];
+            frame.extend_from_slice(&(message.len() as u32).to_be_bytes());
+            frame.extend_from_slice(&message);
+
+            let response = http::Response::builder()
+                .header("content-type", "application/grpc")
+                .body(())
+                .unwrap();
+            let Ok(mut send) = respond.send_response(response, false) else {
+                return;
+            };
+            let _ = send.send_data(frame.into(), false);
+            let mut trailers = http::HeaderMap::new();
+            trailers.insert("grpc-status", http::HeaderValue::from_static("0"));
+            let _ = send.send_trailers(trailers);
+        });
+    }
+}
+
+// Table service with sessions, connected to fake grpc channel
+#[derive(Clone)]
+pub(crate) struct FakeTableService {
+    pub(crate) channel: InterceptedChannel,
+}
+
+#[async_trait]
+impl CreateTableClient for FakeTableService {
+    async fn create_grpc_table_client(&self) -> YdbResult<TableServiceClient<InterceptedChannel>> {
+        Ok(TableServiceClient::new(self.channel.clone()))
+    }
+
+    async fn create_table_client(&self, timeouts: TimeoutSettings) -> YdbResult<RawTableClient> {
+        Ok(RawTableClient::new(self.channel.clone()).with_timeout(timeouts))
+    }
+
+    fn clone_box(&self) -> Box<dyn CreateTableClient> {
+        Box::new(self.clone())
+    }
+}
+
+#[async_trait]
+impl SessionFabric for FakeTableService {
+    async fn create_session(&self, timeouts: TimeoutSettings) -> YdbResult<Session> {
+        Ok(Session::new("session".to_string(), self.clone(), timeouts))
+    }
+}
+
+// Query service, connected to fake grpc channel
+#[derive(Clone)]
+pub(crate) struct FakeQueryService {
+    pub(crate) channel: InterceptedChannel,
+}
+
+#[async_trait]
+impl CreateQueryClient for FakeQueryService {
+    async fn create_query_client(&self) -> YdbResult<RawQueryClient> {
+        Ok(RawQueryClient::new(self.channel.clone()))
+    }
+
+    fn clone_box(&self) -> Box<dyn CreateQueryClient> {
+        Box::new(self.clone())
+    }
+}
+
+// all operation responses has same layout: operation object only
+pub(crate) fn operation_response(status: StatusCode) -> Vec<u8> {
+    AlterTableResponse {
+        operation: Some(Operation {
+            ready: true,
+            status: status as i32,
+            ..Operation::default()
+        }),
+    }
+    .encode_to_vec()
+}
+
+// successful operation response with result message
+pub(crate) fn operation_result_response(result: impl Message) -> Vec<u8> {
+    AlterTableResponse {
+        operation: Some(Operation {
+            ready: true,
+            status: StatusCode::Success as i32,
+            result: Some(Any {
+                type_url: String::new(),
+                value: result.encode_to_vec(),
+            }),
+            ..Operation::default()
+        }),
+    }
+    .encode_to_vec()
+}
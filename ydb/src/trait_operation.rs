@@ -9,9 +9,11 @@ use ydb_grpc::ydb_proto::scheme::{
     ListDirectoryResponse, MakeDirectoryResponse, RemoveDirectoryResponse,
 };
 use ydb_grpc::ydb_proto::table::{
-    BulkUpsertResponse, CommitTransactionResponse, CopyTableResponse, CopyTablesResponse,
-    CreateSessionResponse, DeleteSessionResponse, ExecuteDataQueryResponse,
-    ExecuteSchemeQueryResponse, KeepAliveResponse, RollbackTransactionResponse,
+    AlterTableResponse, BeginTransactionResponse, BulkUpsertResponse, CommitTransactionResponse,
+    CopyTableResponse, CopyTablesResponse, CreateSessionResponse, CreateTableResponse,
+    DeleteSessionResponse, DescribeTableResponse, DropTableResponse, ExecuteDataQueryResponse,
+    ExecuteSchemeQueryResponse, ExplainDataQueryResponse, KeepAliveResponse,
+    PrepareDataQueryResponse, RenameTablesResponse, RollbackTransactionResponse,
 };
 use ydb_grpc::ydb_proto::topic::{
     AlterTopicResponse, CreateTopicResponse, DescribeConsumerResponse, DescribeTopicResponse,
@@ -32,13 +34,21 @@ macro_rules! operation_impl_for {
     };
 }
 
+operation_impl_for!(AlterTableResponse);
+operation_impl_for!(BeginTransactionResponse);
 operation_impl_for!(CommitTransactionResponse);
 operation_impl_for!(CreateSessionResponse);
+operation_impl_for!(CreateTableResponse);
 operation_impl_for!(DeleteSessionResponse);
+operation_impl_for!(DescribeTableResponse);
+operation_impl_for!(DropTableResponse);
 operation_impl_for!(ExecuteDataQueryResponse);
 operation_impl_for!(ExecuteSchemeQueryResponse);
+operation_impl_for!(ExplainDataQueryResponse);
 operation_impl_for!(KeepAliveResponse);
+operation_impl_for!(PrepareDataQueryResponse);
 operation_impl_for!(ListEndpointsResponse);
+operation_impl_for!(RenameTablesResponse);
 operation_impl_for!(RollbackTransactionResponse);
 operation_impl_for!(WhoAmIResponse);
 operation_impl_for!(MakeDirectoryResponse);
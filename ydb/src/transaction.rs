@@ -1,7 +1,8 @@
 use crate::client::TimeoutSettings;
 use crate::errors::{YdbError, YdbResult};
-use crate::grpc_wrapper::raw_table_service::execute_data_query::RawExecuteDataQueryRequest;
-use crate::grpc_wrapper::raw_table_service::query_stats::RawQueryStatMode;
+use crate::grpc_wrapper::raw_table_service::execute_data_query::{
+    RawDataQuery, RawExecuteDataQueryRequest,
+};
 use crate::grpc_wrapper::raw_table_service::transaction_control::{
     RawOnlineReadonlySettings, RawTransactionControl, RawTxMode, RawTxSelector, RawTxSettings,
 };
@@ -13,7 +14,9 @@ use async_trait::async_trait;
 use itertools::Itertools;
 use tracing::trace;
 use ydb_grpc::ydb_proto::table::transaction_settings::TxMode;
-use ydb_grpc::ydb_proto::table::{OnlineModeSettings, SerializableModeSettings};
+use ydb_grpc::ydb_proto::table::{
+    OnlineModeSettings, SerializableModeSettings, SnapshotModeSettings, StaleModeSettings,
+};
 
 #[derive(Clone, Debug)]
 pub struct TransactionInfo {
@@ -21,19 +24,38 @@ pub struct TransactionInfo {
     pub(crate) session_id: String,
 }
 
-#[derive(Copy, Clone, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum Mode {
+    /// Read only committed data, every read may see different state of the database
     OnlineReadonly,
+
+    /// Same as [Mode::OnlineReadonly], but every read may see inconsistent data within a read
+    /// (for example rows of one table from different commits). Fastest read only mode.
+    OnlineReadonlyInconsistent,
+
     SerializableReadWrite,
+
+    /// Read committed data from consistent snapshot of the database, the snapshot may be stale
+    /// (for a fraction of seconds).
+    StaleReadonly,
+
+    /// All reads see consistent snapshot of the database, taken on transaction start.
+    /// Doesn't take locks, allow interactive transactions.
+    SnapshotReadonly,
 }
 
 impl From<Mode> for TxMode {
     fn from(m: Mode) -> Self {
         match m {
             Mode::OnlineReadonly => TxMode::OnlineReadOnly(OnlineModeSettings::default()),
+            Mode::OnlineReadonlyInconsistent => TxMode::OnlineReadOnly(OnlineModeSettings {
+                allow_inconsistent_reads: true,
+            }),
             Mode::SerializableReadWrite => {
                 TxMode::SerializableReadWrite(SerializableModeSettings::default())
             }
+            Mode::StaleReadonly => TxMode::StaleReadOnly(StaleModeSettings::default()),
+            Mode::SnapshotReadonly => TxMode::SnapshotReadOnly(SnapshotModeSettings::default()),
         }
     }
 }
@@ -44,11 +66,51 @@ impl From<Mode> for RawTxMode {
             Mode::OnlineReadonly => Self::OnlineReadOnly(RawOnlineReadonlySettings {
                 allow_inconsistent_reads: false,
             }),
+            Mode::OnlineReadonlyInconsistent => Self::OnlineReadOnly(RawOnlineReadonlySettings {
+                allow_inconsistent_reads: true,
+            }),
             Mode::SerializableReadWrite => Self::SerializableReadWrite,
+            Mode::StaleReadonly => Self::StaleReadOnly,
+            Mode::SnapshotReadonly => Self::SnapshotReadOnly,
         }
     }
 }
 
+impl Mode {
+    // Table service allow interactive transactions in the modes only,
+    // other modes need commit with first query.
+    pub(crate) fn allow_interactive(&self) -> bool {
+        matches!(self, Mode::SerializableReadWrite | Mode::SnapshotReadonly)
+    }
+}
+
+/// Settings for explicit transaction
+///
+/// See [Session::begin_transaction](crate::Session::begin_transaction)
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(not(feature = "force-exhaustive-all"), non_exhaustive)]
+pub struct TxSettings {
+    pub mode: Mode,
+}
+
+impl TxSettings {
+    pub fn new(mode: Mode) -> Self {
+        Self { mode }
+    }
+}
+
+impl Default for TxSettings {
+    fn default() -> Self {
+        Self::new(Mode::SerializableReadWrite)
+    }
+}
+
+impl From<Mode> for TxSettings {
+    fn from(mode: Mode) -> Self {
+        Self::new(mode)
+    }
+}
+
 #[async_trait]
 pub trait Transaction: Send + Sync {
     async fn query(&mut self, query: Query) -> YdbResult<QueryResult>;
@@ -101,7 +163,7 @@ impl Transaction for AutoCommit {
                     mode: self.mode.into(),
                 }),
             },
-            yql_text: query.text,
+            query: RawDataQuery::YqlText(query.text),
             operation_params: self.timeouts.operation_params(),
             params: query
                 .parameters
@@ -112,12 +174,12 @@ impl Transaction for AutoCommit {
                 })
                 .try_collect()?,
             keep_in_cache: query.keep_in_cache,
-            collect_stats: RawQueryStatMode::None,
+            collect_stats: query.stats_mode.into(),
         };
 
         let mut session = self.session_pool.session().await?;
         return session
-            .execute_data_query(req, self.error_on_truncate_response)
+            .execute_raw_data_query(req, self.error_on_truncate_response)
             .await;
     }
 
@@ -132,7 +194,8 @@ impl Transaction for AutoCommit {
     }
 }
 
-pub(crate) struct SerializableReadWriteTx {
+pub(crate) struct InteractiveTx {
+    mode: Mode,
     error_on_truncate_response: bool,
     session_pool: SessionPool,
 
@@ -144,9 +207,10 @@ pub(crate) struct SerializableReadWriteTx {
     timeouts: TimeoutSettings,
 }
 
-impl SerializableReadWriteTx {
-    pub(crate) fn new(session_pool: SessionPool, timeouts: TimeoutSettings) -> Self {
+impl InteractiveTx {
+    pub(crate) fn new(session_pool: SessionPool, mode: Mode, timeouts: TimeoutSettings) -> Self {
         Self {
+            mode,
             error_on_truncate_response: false,
             session_pool,
 
@@ -172,7 +236,7 @@ impl SerializableReadWriteTx {
     }
 }
 
-impl Drop for SerializableReadWriteTx {
+impl Drop for InteractiveTx {
     // rollback if unfinished
     fn drop(&mut self) {
         if !self.finished {
@@ -186,7 +250,7 @@ impl Drop for SerializableReadWriteTx {
 }
 
 #[async_trait]
-impl Transaction for SerializableReadWriteTx {
+impl Transaction for InteractiveTx {
     async fn query(&mut self, query: Query) -> YdbResult<QueryResult> {
         let session = if let Some(session) = self.session.as_mut() {
             session
@@ -203,7 +267,7 @@ impl Transaction for SerializableReadWriteTx {
         } else {
             trace!("start new transaction");
             RawTxSelector::Begin(RawTxSettings {
-                mode: RawTxMode::SerializableReadWrite,
+                mode: self.mode.into(),
             })
         };
 
@@ -213,7 +277,7 @@ impl Transaction for SerializableReadWriteTx {
                 commit_tx: false,
                 tx_selector,
             },
-            yql_text: query.text,
+            query: RawDataQuery::YqlText(query.text),
 
             operation_params: self.timeouts.operation_params(),
             params: query
@@ -225,10 +289,10 @@ impl Transaction for SerializableReadWriteTx {
                 })
                 .try_collect()?,
             keep_in_cache: false,
-            collect_stats: RawQueryStatMode::None,
+            collect_stats: query.stats_mode.into(),
         };
         let query_result = session
-            .execute_data_query(req, self.error_on_truncate_response)
+            .execute_raw_data_query(req, self.error_on_truncate_response)
             .await?;
         if self.id.is_none() {
             self.id = Some(query_result.tx_id.clone());
@@ -2,7 +2,8 @@ use crate::errors::{YdbError, YdbResult};
 use std::collections::HashMap;
 
 use crate::grpc_wrapper::raw_table_service::value::r#type::RawType;
-use crate::grpc_wrapper::raw_table_service::value::RawColumn;
+use crate::grpc_wrapper::raw_table_service::value::{RawColumn, RawTypedValue};
+use crate::types_pg::ValuePg;
 use std::convert::TryInto;
 use std::fmt::Debug;
 use std::num::TryFromIntError;
@@ -79,6 +80,27 @@ pub enum Value {
     Timestamp(std::time::SystemTime),
     Interval(SignedInterval),
 
+    /// Date in extended range, can be before unix epoch
+    Date32(std::time::SystemTime),
+
+    /// Datetime in extended range, can be before unix epoch
+    Datetime64(std::time::SystemTime),
+
+    /// Timestamp in extended range, can be before unix epoch
+    Timestamp64(std::time::SystemTime),
+
+    /// Interval in extended range, with microseconds precision
+    Interval64(SignedInterval),
+
+    /// Date with timezone, value in format `YYYY-MM-DD`
+    TzDate(ValueTz),
+
+    /// Datetime with timezone, value in format `YYYY-MM-DDThh:mm:ss`
+    TzDatetime(ValueTz),
+
+    /// Timestamp with timezone, value in format `YYYY-MM-DDThh:mm:ss.ffffff`
+    TzTimestamp(ValueTz),
+
     // It named String at server, but server String type contains binary data https://ydb.tech/docs/en/yql/reference/types/primitive#string
     Bytes(Bytes),
 
@@ -92,8 +114,31 @@ pub enum Value {
     List(Box<ValueList>),
     Struct(ValueStruct),
 
+    /// Fixed size list of values with different types
+    Tuple(Vec<Value>),
+
+    Dict(Box<ValueDict>),
+
+    /// One of alternative values, see [Value::variant_from]
+    Variant(Box<ValueVariant>),
+
+    /// Value with named tag, see [Value::tagged]
+    Tagged(Box<ValueTagged>),
+
+    /// List without items type, for example result of `SELECT []`
+    EmptyList,
+
+    /// Dict without key and payload types, for example result of `SELECT {}`
+    EmptyDict,
+
     Decimal(decimal_rs::Decimal),
     Uuid(uuid::Uuid),
+
+    /// Number with wide range of exponent, see [DyNumber]
+    DyNumber(DyNumber),
+
+    /// Value of PostgreSQL compatible type, see [ValuePg]
+    Pg(ValuePg),
 }
 
 impl Value {
@@ -209,6 +254,136 @@ impl Default for Box<ValueOptional> {
     }
 }
 
+#[derive(Clone, Debug, PartialEq)]
+pub struct ValueDict {
+    pub(crate) key_t: Value,
+    pub(crate) payload_t: Value,
+    pub(crate) values: Vec<(Value, Value)>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ValueVariant {
+    // Value::Tuple or Value::Struct with examples of alternatives
+    pub(crate) t: Value,
+    pub(crate) index: u32,
+    pub(crate) value: Value,
+}
+
+impl ValueVariant {
+    /// Index of the alternative
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// Name of the alternative for variant over struct, None for variant over tuple
+    pub fn name(&self) -> Option<&str> {
+        match &self.t {
+            Value::Struct(t) => t.fields_name.get(self.index as usize).map(String::as_str),
+            _ => None,
+        }
+    }
+
+    pub fn value(&self) -> &Value {
+        &self.value
+    }
+
+    pub fn into_value(self) -> Value {
+        self.value
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ValueTagged {
+    pub(crate) tag: String,
+    pub(crate) value: Value,
+}
+
+impl ValueTagged {
+    pub fn tag(&self) -> &str {
+        &self.tag
+    }
+
+    pub fn value(&self) -> &Value {
+        &self.value
+    }
+
+    pub fn into_value(self) -> Value {
+        self.value
+    }
+}
+
+/// Date or time in named timezone
+///
+/// Value is local date or time in the timezone, as it sent by server
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ValueTz {
+    pub(crate) value: String,
+    pub(crate) timezone: String,
+}
+
+impl ValueTz {
+    /// Create value from local date or time and timezone name
+    ///
+    /// Example:
+    /// ```
+    /// # use ydb::{Value, ValueTz};
+    /// let v = Value::TzDatetime(ValueTz::new("2024-03-01T12:00:00", "Europe/Moscow"));
+    /// ```
+    pub fn new<V: Into<String>, T: Into<String>>(value: V, timezone: T) -> Self {
+        Self {
+            value: value.into(),
+            timezone: timezone.into(),
+        }
+    }
+
+    /// Local date or time in the timezone
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Timezone name, for example `Europe/Moscow`
+    pub fn timezone(&self) -> &str {
+        &self.timezone
+    }
+
+    pub(crate) fn to_text(&self) -> String {
+        format!("{},{}", self.value, self.timezone)
+    }
+
+    pub(crate) fn from_text(text: &str) -> Option<Self> {
+        let (value, timezone) = text.rsplit_once(',')?;
+        Some(Self::new(value, timezone))
+    }
+}
+
+/// Number with up to 38 significant decimal digits and exponent in range [-130, 126]
+///
+/// Value stored as decimal string, as it sent by server, for example `.123e3`.
+/// Use TryFrom for exact conversion to decimal_rs::Decimal, i64 or u64.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DyNumber {
+    pub(crate) value: String,
+}
+
+impl DyNumber {
+    /// Create value from decimal string, for example `123.45` or `1.2345e2`
+    pub fn new<V: Into<String>>(value: V) -> Self {
+        Self {
+            value: value.into(),
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.value
+    }
+}
+
+impl Default for DyNumber {
+    fn default() -> Self {
+        Self::new("0")
+    }
+}
+
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
 pub enum Sign {
     #[default]
@@ -244,6 +419,63 @@ impl SignedInterval {
             duration: Duration::from_nanos(nanos),
         }
     }
+
+    pub(crate) fn as_micros(self) -> std::result::Result<i64, TryFromIntError> {
+        let micros: i64 = self.duration.as_micros().try_into()?;
+        let res = match self.sign {
+            Sign::Plus => micros,
+            Sign::Minus => -micros,
+        };
+        Ok(res)
+    }
+
+    pub(crate) fn from_micros(micros: i64) -> Self {
+        let sign = if micros >= 0 { Sign::Plus } else { Sign::Minus };
+        Self {
+            sign,
+            duration: Duration::from_micros(micros.unsigned_abs()),
+        }
+    }
+
+    // whole seconds, rounded to past
+    pub(crate) fn as_secs_floor(self) -> i64 {
+        let secs = self.duration.as_secs() as i64;
+        match self.sign {
+            Sign::Plus => secs,
+            Sign::Minus if self.duration.subsec_nanos() > 0 => -secs - 1,
+            Sign::Minus => -secs,
+        }
+    }
+
+    // interval from unix epoch to the time
+    pub(crate) fn since_unix_epoch(t: SystemTime) -> Self {
+        match t.duration_since(SystemTime::UNIX_EPOCH) {
+            Ok(duration) => Self {
+                sign: Sign::Plus,
+                duration,
+            },
+            Err(err) => Self {
+                sign: Sign::Minus,
+                duration: err.duration(),
+            },
+        }
+    }
+
+    pub(crate) fn to_unix_time(self) -> Option<SystemTime> {
+        match self.sign {
+            Sign::Plus => SystemTime::UNIX_EPOCH.checked_add(self.duration),
+            Sign::Minus => SystemTime::UNIX_EPOCH.checked_sub(self.duration),
+        }
+    }
+}
+
+// Date, Datetime and Timestamp can't be before unix epoch, use Date32, Datetime64 and Timestamp64 for it
+pub(crate) fn duration_since_unix_epoch(t: SystemTime) -> YdbResult<Duration> {
+    t.duration_since(SystemTime::UNIX_EPOCH).map_err(|_| {
+        YdbError::Convert(format!(
+            "time before unix epoch: {t:?}, use extended range date types for it"
+        ))
+    })
 }
 
 impl Value {
@@ -286,6 +518,95 @@ impl Value {
         })))
     }
 
+    /// dict_from create Value from examples of key and payload and pairs of values
+    /// examples must be same types as keys and payloads of pairs.
+    ///
+    /// Example:
+    /// ```
+    ///  # use ydb::{Value, YdbResult};
+    ///  # fn example() -> YdbResult<()>{
+    ///  let v = Value::dict_from(
+    ///     0.into(),
+    ///     "".into(),
+    ///     vec![(1.into(), "a".into()), (2.into(), "b".into())],
+    ///  )?;
+    ///  # Ok(())
+    /// }
+    /// ```
+    pub fn dict_from(
+        key_example: Value,
+        payload_example: Value,
+        values: Vec<(Value, Value)>,
+    ) -> YdbResult<Self> {
+        for (index, (key, payload)) in values.iter().enumerate() {
+            if std::mem::discriminant(&key_example) != std::mem::discriminant(key)
+                || std::mem::discriminant(&payload_example) != std::mem::discriminant(payload)
+            {
+                return Err(YdbError::Custom(format!("failed dict_from: type and value has different enum-types. index: {index}, key type: '{key_example:?}', payload type: '{payload_example:?}', key: '{key:?}', payload: '{payload:?}'")));
+            }
+        }
+
+        Ok(Value::Dict(Box::new(ValueDict {
+            key_t: key_example,
+            payload_t: payload_example,
+            values,
+        })))
+    }
+
+    /// variant_from create Value, contains one of alternatives
+    ///
+    /// Alternatives described by example value: Value::Tuple for variant over tuple and
+    /// Value::Struct for variant over struct. Value must be same type as alternative with the index.
+    ///
+    /// Example:
+    /// ```
+    ///  # use ydb::{Value, YdbResult};
+    ///  # fn example() -> YdbResult<()>{
+    ///  let alternatives = Value::Tuple(vec![0_i32.into(), "".into()]);
+    ///  let v = Value::variant_from(alternatives, 1, "text".into())?;
+    ///  # Ok(())
+    /// }
+    /// ```
+    pub fn variant_from(alternatives_example: Value, index: u32, value: Value) -> YdbResult<Self> {
+        let alternative = match &alternatives_example {
+            Value::Tuple(items) => items.get(index as usize),
+            Value::Struct(fields) => fields.values.get(index as usize),
+            t => {
+                return Err(YdbError::Custom(format!(
+                "failed variant_from: alternatives example must be tuple or struct, got: '{t:?}'"
+            )))
+            }
+        };
+
+        match alternative {
+            Some(alternative)
+                if std::mem::discriminant(alternative) == std::mem::discriminant(&value) => {}
+            _ => {
+                return Err(YdbError::Custom(format!("failed variant_from: value has different enum-type with alternative. index: {index}, alternatives: '{alternatives_example:?}', value: '{value:?}'")));
+            }
+        }
+
+        Ok(Value::Variant(Box::new(ValueVariant {
+            t: alternatives_example,
+            index,
+            value,
+        })))
+    }
+
+    /// Create value with tag
+    ///
+    /// Example:
+    /// ```
+    /// # use ydb::Value;
+    /// let v = Value::tagged("user_id", 1_i64.into());
+    /// ```
+    pub fn tagged<T: Into<String>>(tag: T, value: Value) -> Value {
+        Value::Tagged(Box::new(ValueTagged {
+            tag: tag.into(),
+            value,
+        }))
+    }
+
     pub(crate) fn optional_from(t: Value, value: Option<Value>) -> YdbResult<Self> {
         if let Some(value) = &value {
             if std::mem::discriminant(&t) != std::mem::discriminant(value) {
@@ -317,6 +638,9 @@ impl Value {
     /// present current value as Option
     /// if value is Optional - return inner unwrapper value.
     /// else - return self, wrapped to Option.
+    ///
+    /// For nested optional unwrap only one level: Just(Null) returned as Some(Value::Optional)
+    /// with empty value, Null returned as None.
     pub fn to_option(self) -> Option<Value> {
         match self {
             Value::Optional(inner_box) => inner_box.value,
@@ -381,30 +705,16 @@ impl Value {
             Self::Date(val) => proto_typed_value(
                 pt::Date,
                 pv::Uint32Value(
-                    (val.duration_since(SystemTime::UNIX_EPOCH)
-                        .unwrap()
-                        .as_secs()
-                        / SECONDS_PER_DAY)
-                        .try_into()?,
+                    (duration_since_unix_epoch(val)?.as_secs() / SECONDS_PER_DAY).try_into()?,
                 ),
             ),
             Self::DateTime(val) => proto_typed_value(
                 pt::Datetime,
-                pv::Uint32Value(
-                    val.duration_since(SystemTime::UNIX_EPOCH)
-                        .unwrap()
-                        .as_secs()
-                        .try_into()?,
-                ),
+                pv::Uint32Value(duration_since_unix_epoch(val)?.as_secs().try_into()?),
             ),
             Self::Timestamp(val) => proto_typed_value(
                 pt::Timestamp,
-                pv::Uint64Value(
-                    val.duration_since(SystemTime::UNIX_EPOCH)
-                        .unwrap()
-                        .as_micros()
-                        .try_into()?,
-                ),
+                pv::Uint64Value(duration_since_unix_epoch(val)?.as_micros().try_into()?),
             ),
             Self::Interval(val) => proto_typed_value(pt::Interval, pv::Int64Value(val.as_nanos()?)),
             Self::Bytes(val) => proto_typed_value(pt::String, pv::BytesValue(val.into())),
@@ -417,6 +727,21 @@ impl Value {
             Value::Struct(s) => { Self::to_typed_struct(s) }?,
             Self::Decimal(val) => Self::to_typed_decimal(val)?,
             Self::Uuid(val) => Self::to_typed_uuid(val)?,
+            val @ (Self::Date32(_)
+            | Self::Datetime64(_)
+            | Self::Timestamp64(_)
+            | Self::Interval64(_)
+            | Self::TzDate(_)
+            | Self::TzDatetime(_)
+            | Self::TzTimestamp(_)
+            | Self::Tuple(_)
+            | Self::Dict(_)
+            | Self::Variant(_)
+            | Self::Tagged(_)
+            | Self::EmptyList
+            | Self::EmptyDict
+            | Self::DyNumber(_)
+            | Self::Pg(_)) => RawTypedValue::try_from(val)?.into(),
         };
         Ok(res)
     }
@@ -460,11 +785,15 @@ impl Value {
     }
 
     fn to_typed_optional(optional: ValueOptional) -> YdbResult<ydb_proto::TypedValue> {
-        if let Value::Optional(_opt) = optional.t {
-            unimplemented!("nested optional")
-        }
-
+        let is_nested = optional.t.is_optional();
         let val = match optional.value {
+            // value of nested optional wrapped for distinguish Just(Null) from Null
+            Some(val) if is_nested => ydb_proto::Value {
+                value: Some(ydb_proto::value::Value::NestedValue(Box::new(
+                    val.to_typed_value()?.value.unwrap(),
+                ))),
+                ..ydb_proto::Value::default()
+            },
             Some(val) => val.to_typed_value()?.value.unwrap(),
             None => ydb_proto::Value {
                 value: Some(ydb_proto::value::Value::NullFlagValue(0)),
@@ -542,6 +871,7 @@ impl Value {
 
     #[cfg(test)]
     pub(crate) fn examples_for_test() -> Vec<Value> {
+        use crate::types_pg::PgValue;
         use std::{collections::HashSet, ops::Add};
 
         // test zero, one, minimum and maximum values
@@ -607,9 +937,46 @@ impl Value {
             duration: Duration::from_secs(1),
         })); // -1 second interval
 
+        values.push(Value::Date32(
+            SystemTime::UNIX_EPOCH - std::time::Duration::from_secs(SECONDS_PER_DAY * 365 * 100),
+        )); // Sun Jan 13 00:00:00 UTC 1870
+        values.push(Value::Datetime64(
+            SystemTime::UNIX_EPOCH - std::time::Duration::from_secs(1634000523),
+        ));
+        values.push(Value::Timestamp64(
+            SystemTime::UNIX_EPOCH - std::time::Duration::from_micros(16340005230000123),
+        ));
+        values.push(Value::Interval64(SignedInterval {
+            sign: Sign::Minus,
+            duration: Duration::from_micros(1_000_001),
+        }));
+
+        values.push(Value::TzDate(ValueTz::new("2021-10-12", "Europe/Moscow")));
+        values.push(Value::TzDatetime(ValueTz::new(
+            "2021-10-12T01:02:03",
+            "Europe/Moscow",
+        )));
+        values.push(Value::TzTimestamp(ValueTz::new(
+            "2021-10-12T01:02:03.000123",
+            "America/New_York",
+        )));
+
         values.push(Value::optional_from(Value::Int8(0), None).unwrap());
         values.push(Value::optional_from(Value::Int8(0), Some(Value::Int8(1))).unwrap());
 
+        let optional_int8 = Value::optional_from(Value::Int8(0), None).unwrap();
+        values.push(Value::optional_from(optional_int8.clone(), None).unwrap());
+        values.push(
+            Value::optional_from(optional_int8.clone(), Some(optional_int8.clone())).unwrap(),
+        );
+        values.push(
+            Value::optional_from(
+                optional_int8.clone(),
+                Some(Value::optional_from(Value::Int8(0), Some(Value::Int8(1))).unwrap()),
+            )
+            .unwrap(),
+        );
+
         values.push(
             Value::list_from(
                 Value::Int8(0),
@@ -630,6 +997,65 @@ impl Value {
             ],
         }));
 
+        values.push(Value::Tuple(vec![
+            Value::Int32(1),
+            Value::Text("a".into()),
+            Value::optional_from(Value::Int64(0), None).unwrap(),
+        ]));
+
+        values.push(
+            Value::dict_from(
+                Value::Text("".into()),
+                Value::Int32(0),
+                vec![
+                    (Value::Text("a".into()), Value::Int32(1)),
+                    (Value::Text("b".into()), Value::Int32(2)),
+                ],
+            )
+            .unwrap(),
+        );
+        values.push(Value::dict_from(Value::Int32(0), Value::Bool(false), vec![]).unwrap());
+
+        values.push(
+            Value::variant_from(
+                Value::Tuple(vec![Value::Int32(0), Value::Text("".into())]),
+                1,
+                Value::Text("b".into()),
+            )
+            .unwrap(),
+        );
+        values.push(
+            Value::variant_from(
+                Value::struct_from_fields(vec![
+                    ("a".to_string(), Value::Int32(0)),
+                    ("b".to_string(), Value::Text("".into())),
+                ]),
+                0,
+                Value::Int32(1),
+            )
+            .unwrap(),
+        );
+
+        values.push(Value::tagged("tag", Value::Int32(1)));
+
+        values.push(Value::list_from(Value::Int32(0), vec![]).unwrap());
+        values.push(Value::EmptyList);
+        values.push(Value::EmptyDict);
+
+        values.push(Value::DyNumber(DyNumber::new("-1.2345e-100")));
+        values.push(Value::Pg(ValuePg::new(ValuePg::INT4, "123")));
+        values.push(Value::Pg(ValuePg::null(ValuePg::TEXT)));
+        values.push(Value::Pg(ValuePg::with_typmod(
+            ValuePg::NUMERIC,
+            655366, // numeric(10, 2)
+            PgValue::Text("12.34".into()),
+        )));
+        values.push(Value::Pg(ValuePg::with_typmod(
+            ValuePg::BYTEA,
+            -1,
+            PgValue::Bytes(Bytes::from("asd")),
+        )));
+
         let mut discriminants = HashSet::new();
         for item in values.iter() {
             discriminants.insert(std::mem::discriminant(item));
@@ -640,13 +1066,25 @@ impl Value {
     }
 }
 
-#[derive(Debug)]
-pub(crate) struct Column {
-    #[allow(dead_code)]
+/// Column of result set
+#[derive(Clone, Debug)]
+pub struct Column {
     pub(crate) name: String,
     pub(crate) v_type: RawType,
 }
 
+impl Column {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Example of value with column type, for example `Value::Int64(0)` or
+    /// `Value::from(None::<String>)` for nullable text column
+    pub fn type_example(&self) -> YdbResult<Value> {
+        Ok(self.v_type.clone().into_value_example()?)
+    }
+}
+
 impl TryFrom<RawColumn> for Column {
     type Error = YdbError;
 
@@ -0,0 +1,190 @@
+#[cfg(test)]
+#[path = "types_arrow_test.rs"]
+mod types_arrow_test;
+
+use crate::errors::{YdbError, YdbResult};
+use crate::grpc_wrapper::raw_table_service::value::r#type::RawType;
+use crate::grpc_wrapper::raw_table_service::value::value_ydb::merge_parts;
+use crate::grpc_wrapper::raw_table_service::value::RawValue;
+use crate::types::Column;
+use arrow_array::builder::{
+    BinaryBuilder, BooleanBuilder, Date32Builder, Decimal128Builder, DurationMicrosecondBuilder,
+    FixedSizeBinaryBuilder, Float32Builder, Float64Builder, Int16Builder, Int32Builder,
+    Int64Builder, Int8Builder, StringBuilder, TimestampMicrosecondBuilder, TimestampSecondBuilder,
+    UInt16Builder, UInt32Builder, UInt64Builder, UInt8Builder,
+};
+use arrow_array::{ArrayRef, NullArray, RecordBatch};
+use arrow_ipc::writer::{write_message, DictionaryTracker, IpcDataGenerator, IpcWriteOptions};
+use arrow_schema::{Field, Schema};
+use std::sync::Arc;
+use uuid::Uuid;
+
+fn type_mismatch(t: &RawType, v: &RawValue) -> YdbError {
+    YdbError::Convert(format!(
+        "unexpected combination of type '{t:?}' and value '{v:?}'"
+    ))
+}
+
+// append values of column to arrow builder, NullFlag appended as null
+macro_rules! build_array {
+    ($builder:expr, $t:expr, $values:expr, $pattern:pat => $value:expr) => {{
+        let mut builder = $builder;
+        for v in $values {
+            match v {
+                RawValue::NullFlag => builder.append_null(),
+                $pattern => builder.append_value($value),
+                v => return Err(type_mismatch($t, v)),
+            }
+        }
+        Arc::new(builder.finish()) as ArrayRef
+    }};
+}
+
+pub(crate) fn record_batch_from_rows(
+    columns: &[Column],
+    rows: &[Vec<RawValue>],
+) -> YdbResult<RecordBatch> {
+    let mut fields = Vec::with_capacity(columns.len());
+    let mut arrays = Vec::with_capacity(columns.len());
+    for (index, column) in columns.iter().enumerate() {
+        let (t, nullable) = match &column.v_type {
+            RawType::Optional(inner) => (inner.as_ref(), true),
+            t @ RawType::Null => (t, true),
+            t => (t, false),
+        };
+
+        let values = rows.iter().map(|row| &row[index]);
+        let array = build_column(t, rows.len(), values).map_err(|err| {
+            YdbError::Convert(format!(
+                "failed to convert column '{}' to arrow: {err}",
+                column.name
+            ))
+        })?;
+        fields.push(Field::new(
+            column.name.clone(),
+            array.data_type().clone(),
+            nullable,
+        ));
+        arrays.push(array);
+    }
+
+    Ok(RecordBatch::try_new(Arc::new(Schema::new(fields)), arrays)?)
+}
+
+fn build_column<'a>(
+    t: &RawType,
+    len: usize,
+    values: impl Iterator<Item = &'a RawValue>,
+) -> YdbResult<ArrayRef> {
+    let array = match t {
+        RawType::Bool => build_array!(BooleanBuilder::with_capacity(len), t, values,
+            RawValue::Bool(v) => *v),
+        RawType::Int8 => build_array!(Int8Builder::with_capacity(len), t, values,
+            RawValue::Int32(v) => i8::try_from(*v)?),
+        RawType::Uint8 => build_array!(UInt8Builder::with_capacity(len), t, values,
+            RawValue::UInt32(v) => u8::try_from(*v)?),
+        RawType::Int16 => build_array!(Int16Builder::with_capacity(len), t, values,
+            RawValue::Int32(v) => i16::try_from(*v)?),
+        RawType::Uint16 => build_array!(UInt16Builder::with_capacity(len), t, values,
+            RawValue::UInt32(v) => u16::try_from(*v)?),
+        RawType::Int32 => build_array!(Int32Builder::with_capacity(len), t, values,
+            RawValue::Int32(v) => *v),
+        RawType::Uint32 => build_array!(UInt32Builder::with_capacity(len), t, values,
+            RawValue::UInt32(v) => *v),
+        RawType::Int64 => build_array!(Int64Builder::with_capacity(len), t, values,
+            RawValue::Int64(v) => *v),
+        RawType::Uint64 => build_array!(UInt64Builder::with_capacity(len), t, values,
+            RawValue::UInt64(v) => *v),
+        RawType::Float => build_array!(Float32Builder::with_capacity(len), t, values,
+            RawValue::Float(v) => *v),
+        RawType::Double => build_array!(Float64Builder::with_capacity(len), t, values,
+            RawValue::Double(v) => *v),
+        RawType::Date => build_array!(Date32Builder::with_capacity(len), t, values,
+            RawValue::UInt32(v) => i32::try_from(*v)?),
+        RawType::Date32 => build_array!(Date32Builder::with_capacity(len), t, values,
+            RawValue::Int32(v) => *v),
+        RawType::DateTime => build_array!(TimestampSecondBuilder::with_capacity(len), t, values,
+            RawValue::UInt32(v) => i64::from(*v)),
+        RawType::Datetime64 => build_array!(TimestampSecondBuilder::with_capacity(len), t, values,
+            RawValue::Int64(v) => *v),
+        RawType::Timestamp => {
+            build_array!(TimestampMicrosecondBuilder::with_capacity(len), t, values,
+            RawValue::UInt64(v) => i64::try_from(*v)?)
+        }
+        RawType::Timestamp64 => {
+            build_array!(TimestampMicrosecondBuilder::with_capacity(len), t, values,
+            RawValue::Int64(v) => *v)
+        }
+        RawType::Interval | RawType::Interval64 => build_array!(
+            DurationMicrosecondBuilder::with_capacity(len), t, values,
+            RawValue::Int64(v) => *v),
+        RawType::Bytes | RawType::Yson => {
+            build_array!(BinaryBuilder::with_capacity(len, 0), t, values,
+            RawValue::Bytes(v) => v)
+        }
+        RawType::UTF8
+        | RawType::Json
+        | RawType::JSONDocument
+        | RawType::DyNumber
+        | RawType::TzDate
+        | RawType::TzDatetime
+        | RawType::TzTimestamp => build_array!(StringBuilder::with_capacity(len, 0), t, values,
+            RawValue::Text(v) => v),
+        RawType::Decimal(decimal) => build_array!(
+            Decimal128Builder::with_capacity(len)
+                .with_precision_and_scale(decimal.precision, decimal.scale.try_into()?)?,
+            t, values,
+            RawValue::HighLow128(high, low) => merge_parts(*high, *low) as i128),
+        RawType::Uuid => {
+            let mut builder = FixedSizeBinaryBuilder::with_capacity(len, 16);
+            for v in values {
+                match v {
+                    RawValue::NullFlag => builder.append_null(),
+                    RawValue::HighLow128(high, low) => {
+                        let mut le_bytes = [0u8; 16];
+                        le_bytes[0..8].copy_from_slice(&low.to_le_bytes());
+                        le_bytes[8..16].copy_from_slice(&high.to_le_bytes());
+                        builder.append_value(Uuid::from_bytes_le(le_bytes).as_bytes())?
+                    }
+                    v => return Err(type_mismatch(t, v)),
+                }
+            }
+            Arc::new(builder.finish()) as ArrayRef
+        }
+        RawType::Null => Arc::new(NullArray::new(len)) as ArrayRef,
+        t => {
+            return Err(YdbError::Convert(format!(
+                "unsupported type for arrow: {t:?}"
+            )))
+        }
+    };
+    Ok(array)
+}
+
+// Serialized arrow schema and record batch messages, as expected by bulk upsert
+pub(crate) fn encode_record_batch(batch: &RecordBatch) -> YdbResult<(Vec<u8>, Vec<u8>)> {
+    let options = IpcWriteOptions::default();
+    let generator = IpcDataGenerator::default();
+
+    let mut dictionary_tracker = DictionaryTracker::new(false);
+
+    let mut schema = Vec::new();
+    let encoded_schema = generator.schema_to_bytes_with_dictionary_tracker(
+        batch.schema_ref(),
+        &mut dictionary_tracker,
+        &options,
+    );
+    write_message(&mut schema, encoded_schema, &options)?;
+
+    let (dictionaries, encoded_batch) =
+        generator.encoded_batch(batch, &mut dictionary_tracker, &options)?;
+    if !dictionaries.is_empty() {
+        return Err(YdbError::Convert(
+            "dictionary encoded arrow columns are unsupported".to_string(),
+        ));
+    }
+    let mut data = Vec::new();
+    write_message(&mut data, encoded_batch, &options)?;
+
+    Ok((schema, data))
+}
@@ -0,0 +1,148 @@
+use crate::errors::YdbResult;
+use crate::test_helpers::make_result_set;
+use crate::types::SignedInterval;
+use crate::types_arrow::encode_record_batch;
+use crate::{Bytes, Value};
+use arrow_array::cast::AsArray;
+use arrow_array::types::{
+    Date32Type, Decimal128Type, DurationMicrosecondType, Int32Type, Int64Type,
+    TimestampMicrosecondType, UInt64Type,
+};
+use arrow_array::{Array, Int64Array, RecordBatch, StringArray};
+use arrow_ipc::reader::StreamReader;
+use arrow_schema::{DataType, Field, Schema, TimeUnit};
+use std::sync::Arc;
+use std::time::{Duration, UNIX_EPOCH};
+
+#[test]
+fn result_set_to_record_batch() -> YdbResult<()> {
+    let t = UNIX_EPOCH + Duration::from_secs(86400 * 2 + 1);
+    let uuid = uuid::Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+    let result_set = make_result_set(
+        vec![
+            ("id", Value::Uint64(0)),
+            ("val", Value::from(None::<i32>)),
+            ("text", Value::Text(String::new())),
+            ("bytes", Value::Bytes(Bytes::default())),
+            ("date", Value::Date(UNIX_EPOCH)),
+            ("ts", Value::Timestamp(UNIX_EPOCH)),
+            ("interval", Value::Interval64(SignedInterval::default())),
+            ("decimal", Value::Decimal("1.25".parse().unwrap())),
+            ("uuid", Value::Uuid(uuid::Uuid::nil())),
+        ],
+        vec![
+            vec![
+                Value::Uint64(1),
+                Value::from(Some(10_i32)),
+                Value::Text("first".to_string()),
+                Value::Bytes(b"ab".to_vec().into()),
+                Value::Date(t),
+                Value::Timestamp(t),
+                Value::Interval64(SignedInterval::from_micros(-5)),
+                Value::Decimal("1.25".parse().unwrap()),
+                Value::Uuid(uuid),
+            ],
+            vec![
+                Value::Uint64(2),
+                Value::from(None::<i32>),
+                Value::Text("second".to_string()),
+                Value::Bytes(Bytes::default()),
+                Value::Date(UNIX_EPOCH),
+                Value::Timestamp(UNIX_EPOCH),
+                Value::Interval64(SignedInterval::default()),
+                Value::Decimal("-2.75".parse().unwrap()),
+                Value::Uuid(uuid::Uuid::nil()),
+            ],
+        ],
+    )?;
+
+    let batch = result_set.to_record_batch()?;
+    assert_eq!(2, batch.num_rows());
+
+    let schema = batch.schema();
+    assert_eq!(&Field::new("id", DataType::UInt64, false), schema.field(0));
+    assert_eq!(&Field::new("val", DataType::Int32, true), schema.field(1));
+    assert_eq!(
+        &DataType::Timestamp(TimeUnit::Microsecond, None),
+        schema.field(5).data_type()
+    );
+
+    let ids = batch.column(0).as_primitive::<UInt64Type>();
+    assert_eq!(vec![1, 2], ids.values().to_vec());
+
+    let val = batch.column(1).as_primitive::<Int32Type>();
+    assert_eq!(10, val.value(0));
+    assert!(val.is_null(1));
+
+    assert_eq!("second", batch.column(2).as_string::<i32>().value(1));
+    assert_eq!(b"ab", batch.column(3).as_binary::<i32>().value(0));
+    assert_eq!(2, batch.column(4).as_primitive::<Date32Type>().value(0));
+    assert_eq!(
+        (86400 * 2 + 1) * 1_000_000,
+        batch
+            .column(5)
+            .as_primitive::<TimestampMicrosecondType>()
+            .value(0)
+    );
+    assert_eq!(
+        -5,
+        batch
+            .column(6)
+            .as_primitive::<DurationMicrosecondType>()
+            .value(0)
+    );
+
+    let decimal = batch.column(7).as_primitive::<Decimal128Type>();
+    assert_eq!("1.25", decimal.value_as_string(0));
+    assert_eq!("-2.75", decimal.value_as_string(1));
+
+    assert_eq!(
+        uuid.as_bytes(),
+        batch.column(8).as_fixed_size_binary().value(0)
+    );
+    Ok(())
+}
+
+#[test]
+fn unsupported_types() -> YdbResult<()> {
+    let result_set = make_result_set(
+        vec![("list", Value::list_from(Value::Int32(0), vec![])?)],
+        vec![],
+    )?;
+    let err = result_set.to_record_batch().unwrap_err();
+    assert!(err.to_string().contains("'list'"), "{err}");
+    Ok(())
+}
+
+#[test]
+fn encode_batch() -> YdbResult<()> {
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("id", DataType::Int64, false),
+        Field::new("val", DataType::Utf8, true),
+    ]));
+    let batch = RecordBatch::try_new(
+        schema,
+        vec![
+            Arc::new(Int64Array::from(vec![1, 2])),
+            Arc::new(StringArray::from(vec![Some("a"), None])),
+        ],
+    )?;
+
+    let (schema, data) = encode_record_batch(&batch)?;
+
+    // schema and batch messages together are arrow stream
+    let stream = [schema, data].concat();
+    let mut reader = StreamReader::try_new(stream.as_slice(), None)?;
+    let decoded = reader.next().unwrap()?;
+    assert_eq!(batch, decoded);
+    assert_eq!(
+        vec![1, 2],
+        decoded
+            .column(0)
+            .as_primitive::<Int64Type>()
+            .values()
+            .to_vec()
+    );
+    assert!(reader.next().is_none());
+    Ok(())
+}
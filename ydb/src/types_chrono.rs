@@ -0,0 +1,151 @@
+#[cfg(test)]
+#[path = "types_chrono_test.rs"]
+mod types_chrono_test;
+
+use crate::errors::{YdbError, YdbResult};
+use crate::types::{SignedInterval, Value, ValueTz};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+use std::any::type_name;
+use std::str::FromStr;
+use std::time::SystemTime;
+
+fn to_chrono_utc(t: SystemTime) -> YdbResult<DateTime<Utc>> {
+    let micros = SignedInterval::since_unix_epoch(t).as_micros()?;
+    DateTime::from_timestamp_micros(micros)
+        .ok_or_else(|| YdbError::Convert(format!("time out of chrono range: {t:?}")))
+}
+
+fn convert_error<T>(value: &Value) -> YdbError {
+    YdbError::Convert(format!(
+        "failed to convert from {} to {}",
+        value.kind_static(),
+        type_name::<T>(),
+    ))
+}
+
+fn parse_local(value: &str) -> YdbResult<NaiveDateTime> {
+    let res = if value.contains('T') {
+        NaiveDateTime::from_str(value)
+    } else {
+        NaiveDate::from_str(value).map(|date| date.and_time(NaiveTime::MIN))
+    };
+    res.map_err(|err| YdbError::Convert(format!("failed to parse local time '{value}': {err}")))
+}
+
+impl ValueTz {
+    /// Convert to chrono DateTime in timezone of the value
+    ///
+    /// Timezone parsed from name by Tz::from_str, for example with `chrono_tz::Tz`.
+    ///
+    /// Example:
+    /// ```
+    /// # use ydb::{ValueTz, YdbResult};
+    /// # fn main() -> YdbResult<()> {
+    /// use chrono::FixedOffset;
+    ///
+    /// let v = ValueTz::new("2024-03-01T12:00:00", "+03:00");
+    /// let t = v.to_chrono::<FixedOffset>()?;
+    /// assert_eq!("2024-03-01T12:00:00+03:00", t.to_rfc3339());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_chrono<Tz: TimeZone + FromStr>(&self) -> YdbResult<DateTime<Tz>> {
+        let tz = Tz::from_str(&self.timezone)
+            .map_err(|_| YdbError::Convert(format!("unknown timezone: '{}'", self.timezone)))?;
+        let local = parse_local(&self.value)?;
+        tz.from_local_datetime(&local).earliest().ok_or_else(|| {
+            YdbError::Convert(format!(
+                "local time '{}' doesn't exist in timezone '{}'",
+                self.value, self.timezone
+            ))
+        })
+    }
+}
+
+impl From<NaiveDate> for Value {
+    fn from(value: NaiveDate) -> Self {
+        Value::Date32(value.and_time(NaiveTime::MIN).and_utc().into())
+    }
+}
+
+impl From<NaiveDateTime> for Value {
+    fn from(value: NaiveDateTime) -> Self {
+        Value::Timestamp64(value.and_utc().into())
+    }
+}
+
+impl From<DateTime<Utc>> for Value {
+    fn from(value: DateTime<Utc>) -> Self {
+        Value::Timestamp64(value.into())
+    }
+}
+
+impl TryFrom<Value> for NaiveDate {
+    type Error = YdbError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Date(t) | Value::Date32(t) => Ok(to_chrono_utc(t)?.date_naive()),
+            Value::TzDate(v) => Ok(parse_local(&v.value)?.date()),
+            value => Err(convert_error::<Self>(&value)),
+        }
+    }
+}
+
+impl TryFrom<Value> for NaiveDateTime {
+    type Error = YdbError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Date(t)
+            | Value::DateTime(t)
+            | Value::Timestamp(t)
+            | Value::Date32(t)
+            | Value::Datetime64(t)
+            | Value::Timestamp64(t) => Ok(to_chrono_utc(t)?.naive_utc()),
+            Value::TzDate(v) | Value::TzDatetime(v) | Value::TzTimestamp(v) => {
+                parse_local(&v.value)
+            }
+            value => Err(convert_error::<Self>(&value)),
+        }
+    }
+}
+
+// values with timezone can be converted by ValueTz::to_chrono
+impl TryFrom<Value> for DateTime<Utc> {
+    type Error = YdbError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Date(t)
+            | Value::DateTime(t)
+            | Value::Timestamp(t)
+            | Value::Date32(t)
+            | Value::Datetime64(t)
+            | Value::Timestamp64(t) => to_chrono_utc(t),
+            value => Err(convert_error::<Self>(&value)),
+        }
+    }
+}
+
+macro_rules! optional_convert {
+    ($native_type:ty) => {
+        impl TryFrom<Value> for Option<$native_type> {
+            type Error = YdbError;
+
+            fn try_from(value: Value) -> Result<Self, Self::Error> {
+                match value {
+                    Value::Optional(opt_val) => {
+                        <$native_type as TryFrom<Value>>::try_from(opt_val.t)?;
+                        opt_val.value.map(<$native_type>::try_from).transpose()
+                    }
+                    value => Ok(Some(value.try_into()?)),
+                }
+            }
+        }
+    };
+}
+
+optional_convert!(NaiveDate);
+optional_convert!(NaiveDateTime);
+optional_convert!(DateTime<Utc>);
@@ -0,0 +1,51 @@
+use crate::errors::YdbResult;
+use crate::types::{Value, ValueTz};
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, Utc};
+use std::time::{Duration, SystemTime};
+
+#[test]
+fn convert_pre_epoch() -> YdbResult<()> {
+    let date = NaiveDate::from_ymd_opt(1812, 9, 7).unwrap();
+    let value = Value::from(date);
+    assert!(matches!(value, Value::Date32(_)));
+    assert_eq!(date, NaiveDate::try_from(value)?);
+
+    let t = DateTime::from_timestamp_millis(-500).unwrap();
+    let value = Value::from(t);
+    assert_eq!(
+        Value::Timestamp64(SystemTime::UNIX_EPOCH - Duration::from_millis(500)),
+        value
+    );
+    assert_eq!(t, DateTime::<Utc>::try_from(value)?);
+    Ok(())
+}
+
+#[test]
+fn convert_with_timezone() -> YdbResult<()> {
+    let value = ValueTz::new("2024-03-01T12:00:00.5", "+03:00");
+    assert_eq!(
+        NaiveDate::from_ymd_opt(2024, 3, 1)
+            .unwrap()
+            .and_hms_milli_opt(12, 0, 0, 500)
+            .unwrap(),
+        NaiveDateTime::try_from(Value::TzTimestamp(value.clone()))?
+    );
+    assert_eq!(
+        "2024-03-01T09:00:00.500Z",
+        value
+            .to_chrono::<FixedOffset>()?
+            .with_timezone(&Utc)
+            .to_rfc3339_opts(chrono::SecondsFormat::Millis, true)
+    );
+    assert!(DateTime::<Utc>::try_from(Value::TzTimestamp(value)).is_err());
+
+    let value = Value::TzDate(ValueTz::new("2024-03-01", "Europe/Moscow"));
+    assert_eq!(
+        NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(),
+        NaiveDate::try_from(value)?
+    );
+
+    let null = Value::optional_from(Value::TzDate(ValueTz::new("1970-01-01", "GMT")), None)?;
+    assert_eq!(None, Option::<NaiveDate>::try_from(null)?);
+    Ok(())
+}
@@ -1,9 +1,10 @@
 use crate::errors::YdbError;
-use crate::types::{Bytes, Value, ValueOptional};
+use crate::types::{Bytes, DyNumber, Value, ValueDict, ValueOptional};
 use crate::{ValueList, ValueStruct};
 use itertools::Itertools;
 use std::any::type_name;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
 use std::time::SystemTime;
 
 macro_rules! simple_convert {
@@ -19,8 +20,9 @@ macro_rules! simple_convert {
 
             fn try_from(value: Value) -> Result<Self, Self::Error> {
                 match value {
-                    $ydb_value_kind_first(val) => Ok(val.into()),
-                    $($ydb_value_kind(val) => Ok(val.into()),)*
+                    $ydb_value_kind_first(val) => Ok(val.try_into()?),
+                    $($ydb_value_kind(val) => Ok(val.try_into()?),)*
+                    Value::Pg(val) => Self::try_from(val.into_native()?),
                     value => Err(YdbError::Convert(format!(
                         "failed to convert from {} to {}",
                         value.kind_static(),
@@ -46,6 +48,7 @@ macro_rules! simple_convert {
                             None => Ok(None),
                         }
                     }
+                    Value::Pg(val) => Self::try_from(val.into_native()?),
                     value => Ok(Some(value.try_into()?)),
                 }
             }
@@ -77,6 +80,7 @@ simple_convert!(
     Value::Uint16,
     Value::Int8,
     Value::Uint8,
+    Value::DyNumber,
 );
 simple_convert!(
     u64,
@@ -84,6 +88,7 @@ simple_convert!(
     Value::Uint32,
     Value::Uint16,
     Value::Uint8,
+    Value::DyNumber,
 );
 simple_convert!(String, Value::Text, Value::Json, Value::JsonDocument,);
 simple_convert!(
@@ -96,9 +101,76 @@ simple_convert!(
 );
 simple_convert!(f32, Value::Float);
 simple_convert!(f64, Value::Double, Value::Float);
-simple_convert!(SystemTime, Value::Timestamp, Value::Date, Value::DateTime);
-simple_convert!(decimal_rs::Decimal, Value::Decimal);
+simple_convert!(
+    SystemTime,
+    Value::Timestamp,
+    Value::Date,
+    Value::DateTime,
+    Value::Timestamp64,
+    Value::Date32,
+    Value::Datetime64,
+);
+simple_convert!(decimal_rs::Decimal, Value::Decimal, Value::DyNumber);
 simple_convert!(uuid::Uuid, Value::Uuid);
+simple_convert!(DyNumber, Value::DyNumber);
+
+// DyNumber converted exactly: without rounding and overflow
+impl From<decimal_rs::Decimal> for DyNumber {
+    fn from(value: decimal_rs::Decimal) -> Self {
+        DyNumber::new(value.to_string())
+    }
+}
+
+impl From<i64> for DyNumber {
+    fn from(value: i64) -> Self {
+        DyNumber::new(value.to_string())
+    }
+}
+
+impl From<u64> for DyNumber {
+    fn from(value: u64) -> Self {
+        DyNumber::new(value.to_string())
+    }
+}
+
+impl TryFrom<DyNumber> for decimal_rs::Decimal {
+    type Error = YdbError;
+
+    // Decimal has same precision (38 digits) and range of exponent as DyNumber
+    fn try_from(value: DyNumber) -> Result<Self, Self::Error> {
+        value.value.parse().map_err(|err| {
+            YdbError::Convert(format!("failed to parse dynumber '{}': {err}", value.value))
+        })
+    }
+}
+
+macro_rules! dynumber_to_int {
+    ($int_type:ty) => {
+        impl TryFrom<DyNumber> for $int_type {
+            type Error = YdbError;
+
+            fn try_from(value: DyNumber) -> Result<Self, Self::Error> {
+                let text = value.value.clone();
+                let decimal = decimal_rs::Decimal::try_from(value)?;
+                if decimal.trunc(0) != decimal {
+                    return Err(YdbError::Convert(format!(
+                        "dynumber '{text}' has fractional part, can't convert to {}",
+                        type_name::<$int_type>(),
+                    )));
+                }
+                <$int_type>::try_from(decimal).map_err(|err| {
+                    YdbError::Convert(format!(
+                        "failed to convert dynumber '{text}' to {}: {err}",
+                        type_name::<$int_type>(),
+                    ))
+                })
+            }
+        }
+    };
+}
+
+dynumber_to_int!(i64);
+dynumber_to_int!(u64);
 
 // Impl additional Value From
 impl From<&str> for Value {
@@ -116,6 +188,24 @@ impl<T: Into<Value> + Default> From<Option<T>> for Value {
     }
 }
 
+// Nested optional, for example result of LEFT JOIN over nullable column
+impl<T> TryFrom<Value> for Option<Option<T>>
+where
+    Option<T>: TryFrom<Value, Error = YdbError>,
+{
+    type Error = YdbError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Optional(opt_val) if opt_val.t.is_optional() => {
+                <Option<T> as TryFrom<Value>>::try_from(opt_val.t)?;
+                opt_val.value.map(<Option<T>>::try_from).transpose()
+            }
+            value => Ok(Some(value.try_into()?)),
+        }
+    }
+}
+
 impl<T: Into<Value> + Default> FromIterator<T> for Value {
     fn from_iter<T2: IntoIterator<Item = T>>(iter: T2) -> Self {
         let t: Value = T::default().into();
@@ -133,6 +223,8 @@ where
     fn try_from(value: Value) -> Result<Self, Self::Error> {
         let value = match value {
             Value::List(inner) => inner,
+            Value::EmptyList => return Ok(Vec::new()),
+            Value::Tuple(items) => return items.into_iter().map(T::try_from).try_collect(),
             value => {
                 return Err(YdbError::from_str(format!(
                     "can't convert from {} to Vec",
@@ -170,20 +262,119 @@ impl From<HashMap<String, Value>> for Value {
     }
 }
 
-// From Value::Struct to Hashmap
-impl TryFrom<Value> for HashMap<String, Value> {
+// From Value::Dict or Value::Struct to map
+fn map_pairs_from_value<K, V>(
+    value: Value,
+    map_name: &str,
+) -> Result<impl Iterator<Item = Result<(K, V), YdbError>>, YdbError>
+where
+    K: TryFrom<Value>,
+    V: TryFrom<Value>,
+    YdbError: From<K::Error> + From<V::Error>,
+{
+    let pairs = match value {
+        Value::Dict(dict) => dict.values,
+        Value::EmptyDict => Vec::new(),
+        Value::Struct(value_struct) => value_struct
+            .fields_name
+            .into_iter()
+            .map(Value::Text)
+            .zip(value_struct.values)
+            .collect(),
+        value => {
+            return Err(YdbError::from_str(format!(
+                "failed convert {} to {map_name}",
+                value.kind_static()
+            )))
+        }
+    };
+
+    Ok(pairs
+        .into_iter()
+        .map(|(key, payload)| Ok((K::try_from(key)?, V::try_from(payload)?))))
+}
+
+// From Value::Dict or Value::Struct to HashMap
+impl<K, V> TryFrom<Value> for HashMap<K, V>
+where
+    K: TryFrom<Value> + Eq + Hash,
+    V: TryFrom<Value>,
+    YdbError: From<K::Error> + From<V::Error>,
+{
+    type Error = YdbError;
+
+    fn try_from(from_value: Value) -> Result<Self, Self::Error> {
+        map_pairs_from_value(from_value, "HashMap")?.try_collect()
+    }
+}
+
+// From Value::Dict or Value::Struct to BTreeMap
+impl<K, V> TryFrom<Value> for BTreeMap<K, V>
+where
+    K: TryFrom<Value> + Ord,
+    V: TryFrom<Value>,
+    YdbError: From<K::Error> + From<V::Error>,
+{
     type Error = YdbError;
 
     fn try_from(from_value: Value) -> Result<Self, Self::Error> {
-        let kind_name = from_value.kind_static();
-        let value_struct = match from_value {
-            Value::Struct(value_struct) => value_struct,
-            _ => {
-                return Err(YdbError::from_str(format!(
-                    "failed convert {kind_name} to HashMap"
-                )))
-            }
-        };
-        Ok(value_struct.into())
+        map_pairs_from_value(from_value, "BTreeMap")?.try_collect()
     }
 }
+
+// From BTreeMap to Value::Dict
+impl<K: Into<Value> + Default, V: Into<Value> + Default> From<BTreeMap<K, V>> for Value {
+    fn from(from_value: BTreeMap<K, V>) -> Self {
+        Value::Dict(Box::new(ValueDict {
+            key_t: K::default().into(),
+            payload_t: V::default().into(),
+            values: from_value
+                .into_iter()
+                .map(|(key, payload)| (key.into(), payload.into()))
+                .collect(),
+        }))
+    }
+}
+
+macro_rules! tuple_convert {
+    ($len:expr, $($item:ident),+) => {
+        impl<$($item: Into<Value>),+> From<($($item,)+)> for Value {
+            #[allow(non_snake_case)]
+            fn from(($($item,)+): ($($item,)+)) -> Self {
+                Value::Tuple(vec![$($item.into()),+])
+            }
+        }
+
+        impl<$($item),+> TryFrom<Value> for ($($item,)+)
+        where
+            $($item: TryFrom<Value>, YdbError: From<$item::Error>,)+
+        {
+            type Error = YdbError;
+
+            fn try_from(value: Value) -> Result<Self, Self::Error> {
+                let items = match value {
+                    Value::Tuple(items) if items.len() == $len => items,
+                    value => {
+                        return Err(YdbError::Convert(format!(
+                            "failed to convert from {} to tuple with {} items",
+                            value.kind_static(),
+                            $len,
+                        )))
+                    }
+                };
+
+                let mut items = items.into_iter();
+                Ok(($($item::try_from(items.next().unwrap())?,)+))
+            }
+        }
+    };
+}
+
+tuple_convert!(1, A);
+tuple_convert!(2, A, B);
+tuple_convert!(3, A, B, C);
+tuple_convert!(4, A, B, C, D);
+tuple_convert!(5, A, B, C, D, E);
+tuple_convert!(6, A, B, C, D, E, F);
+tuple_convert!(7, A, B, C, D, E, F, G);
+tuple_convert!(8, A, B, C, D, E, F, G, H);
@@ -0,0 +1,311 @@
+#[cfg(test)]
+#[path = "types_pg_test.rs"]
+mod types_pg_test;
+
+use crate::errors::{YdbError, YdbResult};
+use crate::types::{Bytes, Value, ValueOptional};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Value of PostgreSQL compatible type, for example from table created by PostgreSQL compatibility layer
+///
+/// Type identified by oid from pg_type catalog: <https://github.com/postgres/postgres/blob/master/src/include/catalog/pg_type.dat>
+/// Value stored in text or binary format, as it sent by server.
+///
+/// Values of common types (see constants of the struct) can be converted
+/// from and to native rust types by TryFrom, through native Value.
+///
+/// Example:
+/// ```
+/// # use ydb::{Value, ValuePg, YdbResult};
+/// # fn main() -> YdbResult<()> {
+/// let v = Value::Pg(ValuePg::new(ValuePg::INT4, "123"));
+/// let native: i32 = v.try_into()?;
+/// assert_eq!(123, native);
+///
+/// let v = Value::Pg(ValuePg::try_from(Value::from("text"))?);
+/// assert_eq!(Value::Pg(ValuePg::new(ValuePg::TEXT, "text")), v);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValuePg {
+    pub(crate) oid: u32,
+    pub(crate) typmod: i32,
+    pub(crate) value: PgValue,
+}
+
+/// Content of pg value
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PgValue {
+    Null,
+
+    /// Value in pg text format, for example `t` for bool or `2024-03-01 12:00:00+00` for timestamptz
+    Text(String),
+
+    /// Value in pg binary format
+    Bytes(Bytes),
+}
+
+impl ValuePg {
+    pub const BOOL: u32 = 16;
+    pub const BYTEA: u32 = 17;
+    pub const INT8: u32 = 20;
+    pub const INT2: u32 = 21;
+    pub const INT4: u32 = 23;
+    pub const TEXT: u32 = 25;
+    pub const JSON: u32 = 114;
+    pub const FLOAT4: u32 = 700;
+    pub const FLOAT8: u32 = 701;
+    pub const BPCHAR: u32 = 1042;
+    pub const VARCHAR: u32 = 1043;
+    pub const DATE: u32 = 1082;
+    pub const TIMESTAMP: u32 = 1114;
+    pub const TIMESTAMPTZ: u32 = 1184;
+    pub const NUMERIC: u32 = 1700;
+    pub const UUID: u32 = 2950;
+    pub const JSONB: u32 = 3802;
+
+    // pg typmod for types without modifier
+    const NO_TYPMOD: i32 = -1;
+
+    /// Create value of the type from text format
+    pub fn new<V: Into<String>>(oid: u32, text: V) -> Self {
+        Self::with_typmod(oid, Self::NO_TYPMOD, PgValue::Text(text.into()))
+    }
+
+    /// Create null value of the type
+    pub fn null(oid: u32) -> Self {
+        Self::with_typmod(oid, Self::NO_TYPMOD, PgValue::Null)
+    }
+
+    /// Create value with type modifier, for example with precision and scale for numeric
+    pub fn with_typmod(oid: u32, typmod: i32, value: PgValue) -> Self {
+        Self { oid, typmod, value }
+    }
+
+    pub fn oid(&self) -> u32 {
+        self.oid
+    }
+
+    pub fn typmod(&self) -> i32 {
+        self.typmod
+    }
+
+    pub fn value(&self) -> &PgValue {
+        &self.value
+    }
+
+    pub fn into_value(self) -> PgValue {
+        self.value
+    }
+
+    pub fn is_null(&self) -> bool {
+        self.value == PgValue::Null
+    }
+
+    // Convert to native value of the type, null converted to empty optional
+    pub(crate) fn into_native(self) -> YdbResult<Value> {
+        let text = match self.value {
+            PgValue::Null => {
+                return Ok(Value::Optional(Box::new(ValueOptional {
+                    t: native_example(self.oid)?,
+                    value: None,
+                })))
+            }
+            PgValue::Text(text) => text,
+            PgValue::Bytes(bytes) if self.oid == Self::BYTEA => return Ok(Value::Bytes(bytes)),
+            PgValue::Bytes(_) => {
+                return Err(YdbError::Convert(format!(
+                    "convert pg value with oid {} from binary format is unsupported",
+                    self.oid
+                )))
+            }
+        };
+
+        let res = match self.oid {
+            Self::BOOL => match text.as_str() {
+                "t" | "true" => Value::Bool(true),
+                "f" | "false" => Value::Bool(false),
+                _ => return Err(parse_error(self.oid, &text, "bad bool value")),
+            },
+            Self::INT2 => Value::Int16(parse(self.oid, &text)?),
+            Self::INT4 => Value::Int32(parse(self.oid, &text)?),
+            Self::INT8 => Value::Int64(parse(self.oid, &text)?),
+            Self::FLOAT4 => Value::Float(parse(self.oid, &text)?),
+            Self::FLOAT8 => Value::Double(parse(self.oid, &text)?),
+            Self::NUMERIC => Value::Decimal(parse(self.oid, &text)?),
+            Self::TEXT | Self::VARCHAR | Self::BPCHAR => Value::Text(text),
+            Self::JSON => Value::Json(text),
+            Self::JSONB => Value::JsonDocument(text),
+            Self::UUID => Value::Uuid(parse(self.oid, &text)?),
+            Self::BYTEA => Value::Bytes(decode_bytea(&text)?),
+            Self::DATE => {
+                let date = NaiveDate::parse_from_str(&text, "%Y-%m-%d")
+                    .map_err(|err| parse_error(self.oid, &text, err))?;
+                Value::Date32(date.and_time(NaiveTime::MIN).and_utc().into())
+            }
+            Self::TIMESTAMP => {
+                let t = NaiveDateTime::parse_from_str(&text, "%Y-%m-%d %H:%M:%S%.f")
+                    .map_err(|err| parse_error(self.oid, &text, err))?;
+                Value::Timestamp64(t.and_utc().into())
+            }
+            Self::TIMESTAMPTZ => {
+                let t = DateTime::parse_from_str(&text, "%Y-%m-%d %H:%M:%S%.f%#z")
+                    .map_err(|err| parse_error(self.oid, &text, err))?;
+                Value::Timestamp64(t.into())
+            }
+            oid => return Err(unsupported_oid(oid)),
+        };
+        Ok(res)
+    }
+}
+
+impl TryFrom<Value> for ValuePg {
+    type Error = YdbError;
+
+    /// Convert native value to pg value of corresponding type
+    /// Timestamps converted to timestamptz, empty optional - to null value.
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        let res = match value {
+            Value::Bool(v) => Self::new(Self::BOOL, if v { "t" } else { "f" }),
+            Value::Int16(v) => Self::new(Self::INT2, v.to_string()),
+            Value::Int32(v) => Self::new(Self::INT4, v.to_string()),
+            Value::Int64(v) => Self::new(Self::INT8, v.to_string()),
+            Value::Float(v) => Self::new(Self::FLOAT4, float_text(v)),
+            Value::Double(v) => Self::new(Self::FLOAT8, float_text(v)),
+            Value::Decimal(v) => Self::new(Self::NUMERIC, v.to_string()),
+            Value::Text(v) => Self::new(Self::TEXT, v),
+            Value::Json(v) => Self::new(Self::JSON, v),
+            Value::JsonDocument(v) => Self::new(Self::JSONB, v),
+            Value::Uuid(v) => Self::new(Self::UUID, v.to_string()),
+            Value::Bytes(v) => Self::new(Self::BYTEA, encode_bytea(v)),
+            Value::Date(t) | Value::Date32(t) => Self::new(
+                Self::DATE,
+                DateTime::<Utc>::from(t).format("%Y-%m-%d").to_string(),
+            ),
+            Value::Timestamp(t) | Value::Timestamp64(t) => Self::new(
+                Self::TIMESTAMPTZ,
+                DateTime::<Utc>::from(t)
+                    .format("%Y-%m-%d %H:%M:%S%.6f+00")
+                    .to_string(),
+            ),
+            Value::Optional(opt) => match opt.value {
+                Some(v) => Self::try_from(v)?,
+                None => Self::null(Self::try_from(opt.t)?.oid),
+            },
+            Value::Pg(v) => v,
+            value => {
+                return Err(YdbError::Convert(format!(
+                    "failed to convert from {} to pg value",
+                    value.kind_static(),
+                )))
+            }
+        };
+        Ok(res)
+    }
+}
+
+// Name and length (-1 for variable length types) of the type from pg_type catalog
+pub(crate) fn pg_type_name_and_len(oid: u32) -> Option<(&'static str, i32)> {
+    let res = match oid {
+        ValuePg::BOOL => ("bool", 1),
+        ValuePg::BYTEA => ("bytea", -1),
+        ValuePg::INT8 => ("int8", 8),
+        ValuePg::INT2 => ("int2", 2),
+        ValuePg::INT4 => ("int4", 4),
+        ValuePg::TEXT => ("text", -1),
+        ValuePg::JSON => ("json", -1),
+        ValuePg::FLOAT4 => ("float4", 4),
+        ValuePg::FLOAT8 => ("float8", 8),
+        ValuePg::BPCHAR => ("bpchar", -1),
+        ValuePg::VARCHAR => ("varchar", -1),
+        ValuePg::DATE => ("date", 4),
+        ValuePg::TIMESTAMP => ("timestamp", 8),
+        ValuePg::TIMESTAMPTZ => ("timestamptz", 8),
+        ValuePg::NUMERIC => ("numeric", -1),
+        ValuePg::UUID => ("uuid", 16),
+        ValuePg::JSONB => ("jsonb", -1),
+        _ => return None,
+    };
+    Some(res)
+}
+
+// pg text format of float: special values are Infinity, -Infinity and NaN
+fn float_text<F: Display>(v: F) -> String {
+    match v.to_string().as_str() {
+        "inf" => "Infinity".to_string(),
+        "-inf" => "-Infinity".to_string(),
+        text => text.to_string(),
+    }
+}
+
+fn native_example(oid: u32) -> YdbResult<Value> {
+    let res = match oid {
+        ValuePg::BOOL => Value::Bool(false),
+        ValuePg::INT2 => Value::Int16(0),
+        ValuePg::INT4 => Value::Int32(0),
+        ValuePg::INT8 => Value::Int64(0),
+        ValuePg::FLOAT4 => Value::Float(0.0),
+        ValuePg::FLOAT8 => Value::Double(0.0),
+        ValuePg::NUMERIC => Value::Decimal(decimal_rs::Decimal::default()),
+        ValuePg::TEXT | ValuePg::VARCHAR | ValuePg::BPCHAR => Value::Text(String::default()),
+        ValuePg::JSON => Value::Json(String::default()),
+        ValuePg::JSONB => Value::JsonDocument(String::default()),
+        ValuePg::UUID => Value::Uuid(uuid::Uuid::nil()),
+        ValuePg::BYTEA => Value::Bytes(Bytes::default()),
+        ValuePg::DATE => Value::Date32(std::time::SystemTime::UNIX_EPOCH),
+        ValuePg::TIMESTAMP | ValuePg::TIMESTAMPTZ => {
+            Value::Timestamp64(std::time::SystemTime::UNIX_EPOCH)
+        }
+        oid => return Err(unsupported_oid(oid)),
+    };
+    Ok(res)
+}
+
+fn parse<T: FromStr>(oid: u32, text: &str) -> YdbResult<T>
+where
+    T::Err: Display,
+{
+    text.parse().map_err(|err| parse_error(oid, text, err))
+}
+
+fn parse_error<E: Display>(oid: u32, text: &str, err: E) -> YdbError {
+    YdbError::Convert(format!(
+        "failed to parse pg value with oid {oid}: '{text}': {err}"
+    ))
+}
+
+fn unsupported_oid(oid: u32) -> YdbError {
+    YdbError::Convert(format!(
+        "convert pg value with oid {oid} to native value is unsupported"
+    ))
+}
+
+// bytea in hex text format: \x0a0b
+fn decode_bytea(text: &str) -> YdbResult<Bytes> {
+    let Some(hex) = text.strip_prefix("\\x") else {
+        return Err(parse_error(ValuePg::BYTEA, text, "expected hex format"));
+    };
+    if !hex.is_ascii() || hex.len() % 2 != 0 {
+        return Err(parse_error(ValuePg::BYTEA, text, "bad hex string"));
+    }
+
+    let bytes: Vec<u8> = (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16))
+        .collect::<Result<_, _>>()
+        .map_err(|err| parse_error(ValuePg::BYTEA, text, err))?;
+    Ok(bytes.into())
+}
+
+fn encode_bytea(bytes: Bytes) -> String {
+    let bytes: Vec<u8> = bytes.into();
+    let mut res = String::with_capacity(2 + bytes.len() * 2);
+    res.push_str("\\x");
+    for b in bytes {
+        res.push_str(&format!("{b:02x}"));
+    }
+    res
+}
@@ -0,0 +1,116 @@
+use crate::errors::YdbResult;
+use crate::types::{Bytes, Value};
+use crate::types_pg::{PgValue, ValuePg};
+use std::time::{Duration, SystemTime};
+
+#[test]
+fn convert_to_native() -> YdbResult<()> {
+    let v: i32 = Value::Pg(ValuePg::new(ValuePg::INT4, "-123")).try_into()?;
+    assert_eq!(-123, v);
+
+    // int4 extended to int64 same as native Int32
+    let v: i64 = Value::Pg(ValuePg::new(ValuePg::INT4, "123")).try_into()?;
+    assert_eq!(123, v);
+
+    let v: bool = Value::Pg(ValuePg::new(ValuePg::BOOL, "t")).try_into()?;
+    assert!(v);
+
+    let v: String = Value::Pg(ValuePg::new(ValuePg::JSONB, r#"{"a": 1}"#)).try_into()?;
+    assert_eq!(r#"{"a": 1}"#, v);
+
+    let v: decimal_rs::Decimal = Value::Pg(ValuePg::new(ValuePg::NUMERIC, "12.34")).try_into()?;
+    assert_eq!("12.34".parse::<decimal_rs::Decimal>().unwrap(), v);
+
+    let v: SystemTime = Value::Pg(ValuePg::new(
+        ValuePg::TIMESTAMPTZ,
+        "1970-01-02 03:00:00.5+03",
+    ))
+    .try_into()?;
+    assert_eq!(
+        SystemTime::UNIX_EPOCH + Duration::from_millis(86400 * 1000 + 500),
+        v
+    );
+
+    let v: Bytes = Value::Pg(ValuePg::new(ValuePg::BYTEA, "\\x0aff")).try_into()?;
+    assert_eq!(Bytes::from(vec![10, 255]), v);
+
+    Ok(())
+}
+
+#[test]
+fn convert_null() -> YdbResult<()> {
+    let v: Option<i64> = Value::Pg(ValuePg::null(ValuePg::INT8)).try_into()?;
+    assert_eq!(None, v);
+
+    let v: Option<i64> = Value::Pg(ValuePg::new(ValuePg::INT8, "1")).try_into()?;
+    assert_eq!(Some(1), v);
+
+    // type checked for null values too
+    assert!(Option::<i64>::try_from(Value::Pg(ValuePg::null(ValuePg::TEXT))).is_err());
+    assert!(i64::try_from(Value::Pg(ValuePg::null(ValuePg::INT8))).is_err());
+    Ok(())
+}
+
+#[test]
+fn convert_errors() {
+    assert!(i32::try_from(Value::Pg(ValuePg::new(ValuePg::INT4, "abc"))).is_err());
+    assert!(i32::try_from(Value::Pg(ValuePg::new(ValuePg::TEXT, "1"))).is_err());
+    assert!(i32::try_from(Value::Pg(ValuePg::new(123456, "1"))).is_err());
+    assert!(i32::try_from(Value::Pg(ValuePg::with_typmod(
+        ValuePg::INT4,
+        -1,
+        PgValue::Bytes(Bytes::from(vec![0, 0, 0, 1])),
+    )))
+    .is_err());
+}
+
+#[test]
+fn convert_from_native() -> YdbResult<()> {
+    let values = vec![
+        Value::Bool(true),
+        Value::Int16(-1),
+        Value::Int32(i32::MAX),
+        Value::Int64(i64::MIN),
+        Value::Double(1.5),
+        Value::Text("text".into()),
+        Value::Json("{}".into()),
+        Value::JsonDocument("{}".into()),
+        Value::Decimal("-123.456".parse().unwrap()),
+        Value::Uuid(uuid::Uuid::new_v4()),
+        Value::Bytes(Bytes::from(vec![0, 1, 254])),
+        Value::Date32(SystemTime::UNIX_EPOCH - Duration::from_secs(86400)),
+        Value::Timestamp64(SystemTime::UNIX_EPOCH + Duration::from_micros(1_000_001)),
+    ];
+
+    for value in values {
+        let pg = ValuePg::try_from(value.clone())?;
+        assert_eq!(value, pg.into_native()?);
+    }
+
+    let pg = ValuePg::try_from(Value::from(None::<i32>))?;
+    assert_eq!(ValuePg::null(ValuePg::INT4), pg);
+
+    assert!(ValuePg::try_from(Value::Uint32(1)).is_err());
+    Ok(())
+}
+
+#[test]
+fn convert_special_floats() -> YdbResult<()> {
+    let cases = vec![
+        (Value::Double(f64::INFINITY), "Infinity"),
+        (Value::Double(f64::NEG_INFINITY), "-Infinity"),
+        (Value::Double(f64::NAN), "NaN"),
+        (Value::Float(f32::INFINITY), "Infinity"),
+        (Value::Float(f32::NEG_INFINITY), "-Infinity"),
+        (Value::Float(f32::NAN), "NaN"),
+        (Value::Float(0.1), "0.1"),
+    ];
+    for (value, text) in cases {
+        let pg = ValuePg::try_from(value)?;
+        assert_eq!(&PgValue::Text(text.to_string()), pg.value());
+    }
+
+    let native: f64 = Value::Pg(ValuePg::new(ValuePg::FLOAT8, "-Infinity")).try_into()?;
+    assert_eq!(f64::NEG_INFINITY, native);
+    Ok(())
+}
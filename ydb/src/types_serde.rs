@@ -0,0 +1,718 @@
+#[cfg(test)]
+#[path = "types_serde_test.rs"]
+mod types_serde_test;
+
+use crate::errors::{YdbError, YdbResult};
+use crate::result::Row;
+use crate::types::{Value, ValueList, ValueOptional, ValueStruct, ValueTz};
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde::de::value::{MapDeserializer, SeqDeserializer};
+use serde::de::{DeserializeOwned, DeserializeSeed, EnumAccess, IntoDeserializer, VariantAccess};
+use serde::{forward_to_deserialize_any, ser, Serialize};
+use std::time::SystemTime;
+
+/// Convert serializable value to Value
+///
+/// Struct converted to Value::Struct, sequence - to Value::List, map - to Value::Dict,
+/// tuple - to Value::Tuple, Some - to Value::Optional and unit enum variant - to Value::Text with name of variant.
+///
+/// Serde doesn't know type of None. None in list items converted to empty optional with type
+/// of other items, for example in struct fields of bulk upsert rows. Other None values (top-level,
+/// field of single struct or field with None in all list items) return [YdbError::Convert],
+/// use [to_value_with_example] for such values.
+///
+/// Example:
+/// ```
+/// # use ydb::{Value, YdbResult};
+/// # fn main() -> YdbResult<()> {
+/// #[derive(serde::Serialize)]
+/// struct Episode {
+///     id: u64,
+///     title: String,
+/// }
+///
+/// let v = ydb::to_value(&Episode { id: 1, title: "first".to_string() })?;
+/// assert_eq!(
+///     Value::struct_from_fields(vec![
+///         ("id".to_string(), 1_u64.into()),
+///         ("title".to_string(), "first".into()),
+///     ]),
+///     v
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn to_value<T: Serialize + ?Sized>(value: &T) -> YdbResult<Value> {
+    check_no_nulls(value.serialize(ValueSerializer)?)
+}
+
+/// Convert serializable value to Value, Nulls replaced by empty optionals with type from example
+///
+/// Example used for fields of structs and items of lists, other values converted as by [to_value].
+///
+/// Example:
+/// ```
+/// # use ydb::{Value, YdbResult};
+/// # fn main() -> YdbResult<()> {
+/// #[derive(serde::Serialize)]
+/// struct Episode {
+///     id: u64,
+///     title: Option<String>,
+/// }
+///
+/// let example = Value::struct_from_fields(vec![
+///     ("id".to_string(), Value::Uint64(0)),
+///     ("title".to_string(), Option::<String>::None.into()),
+/// ]);
+/// let v = ydb::to_value_with_example(&Episode { id: 1, title: None }, &example)?;
+/// assert_eq!(
+///     Value::struct_from_fields(vec![
+///         ("id".to_string(), 1_u64.into()),
+///         ("title".to_string(), Option::<String>::None.into()),
+///     ]),
+///     v
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn to_value_with_example<T: Serialize + ?Sized>(
+    value: &T,
+    example: &Value,
+) -> YdbResult<Value> {
+    check_no_nulls(fill_nulls(value.serialize(ValueSerializer)?, example))
+}
+
+/// Convert Value to deserializable type
+///
+/// Date and time values deserialized as strings in RFC 3339 format, intervals - as i64 microseconds,
+/// decimal, dynumber and uuid - as strings, variants - as enums.
+///
+/// Example:
+/// ```
+/// # use ydb::{Value, YdbResult};
+/// # fn main() -> YdbResult<()> {
+/// #[derive(serde::Deserialize)]
+/// struct Episode {
+///     id: u64,
+///     title: Option<String>,
+/// }
+///
+/// let v = Value::struct_from_fields(vec![
+///     ("id".to_string(), 1_u64.into()),
+///     ("title".to_string(), Some("first").into()),
+/// ]);
+/// let episode: Episode = ydb::from_value(v)?;
+/// assert_eq!(Some("first".to_string()), episode.title);
+/// # Ok(())
+/// # }
+/// ```
+pub fn from_value<T: DeserializeOwned>(value: Value) -> YdbResult<T> {
+    T::deserialize(value)
+}
+
+/// Convert row to deserializable type, columns deserialized as fields of struct
+pub fn from_row<T: DeserializeOwned>(row: Row) -> YdbResult<T> {
+    T::deserialize(row)
+}
+
+impl serde::de::Error for YdbError {
+    fn custom<T: std::fmt::Display>(msg: T) -> Self {
+        YdbError::Convert(msg.to_string())
+    }
+}
+
+impl ser::Error for YdbError {
+    fn custom<T: std::fmt::Display>(msg: T) -> Self {
+        YdbError::Convert(msg.to_string())
+    }
+}
+
+//
+// Deserialize from Value
+//
+
+fn format_time(t: SystemTime, with_time: bool) -> String {
+    let t = DateTime::<Utc>::from(t);
+    if with_time {
+        t.to_rfc3339_opts(SecondsFormat::AutoSi, true)
+    } else {
+        t.format("%Y-%m-%d").to_string()
+    }
+}
+
+impl<'de> serde::Deserializer<'de> for Value {
+    type Error = YdbError;
+
+    fn deserialize_any<V: serde::de::Visitor<'de>>(self, visitor: V) -> YdbResult<V::Value> {
+        match self {
+            Value::Void | Value::Null => visitor.visit_unit(),
+            Value::Bool(v) => visitor.visit_bool(v),
+            Value::Int8(v) => visitor.visit_i8(v),
+            Value::Uint8(v) => visitor.visit_u8(v),
+            Value::Int16(v) => visitor.visit_i16(v),
+            Value::Uint16(v) => visitor.visit_u16(v),
+            Value::Int32(v) => visitor.visit_i32(v),
+            Value::Uint32(v) => visitor.visit_u32(v),
+            Value::Int64(v) => visitor.visit_i64(v),
+            Value::Uint64(v) => visitor.visit_u64(v),
+            Value::Float(v) => visitor.visit_f32(v),
+            Value::Double(v) => visitor.visit_f64(v),
+            Value::Date(t) | Value::Date32(t) => visitor.visit_string(format_time(t, false)),
+            Value::DateTime(t)
+            | Value::Timestamp(t)
+            | Value::Datetime64(t)
+            | Value::Timestamp64(t) => visitor.visit_string(format_time(t, true)),
+            Value::Interval(v) | Value::Interval64(v) => visitor.visit_i64(v.as_micros()?),
+            Value::TzDate(v) | Value::TzDatetime(v) | Value::TzTimestamp(v) => {
+                visitor.visit_string(ValueTz::to_text(&v))
+            }
+            Value::Bytes(v) | Value::Yson(v) => visitor.visit_byte_buf(v.into()),
+            Value::Text(v) | Value::Json(v) | Value::JsonDocument(v) => visitor.visit_string(v),
+            Value::Decimal(v) => visitor.visit_string(v.to_string()),
+            Value::Uuid(v) => visitor.visit_string(v.to_string()),
+            Value::DyNumber(v) => visitor.visit_string(v.value),
+            Value::Pg(v) => v.into_native()?.deserialize_any(visitor),
+            Value::Optional(v) => match v.value {
+                Some(v) => visitor.visit_some(v),
+                None => visitor.visit_none(),
+            },
+            Value::List(v) => visitor.visit_seq(SeqDeserializer::new(v.values.into_iter())),
+            Value::Tuple(v) => visitor.visit_seq(SeqDeserializer::new(v.into_iter())),
+            Value::EmptyList => {
+                visitor.visit_seq(SeqDeserializer::new(std::iter::empty::<Value>()))
+            }
+            Value::Struct(v) => visitor.visit_map(MapDeserializer::new(
+                v.fields_name.into_iter().map(Value::Text).zip(v.values),
+            )),
+            Value::Dict(v) => visitor.visit_map(MapDeserializer::new(v.values.into_iter())),
+            Value::EmptyDict => {
+                visitor.visit_map(MapDeserializer::new(std::iter::empty::<(Value, Value)>()))
+            }
+            Value::Variant(v) => {
+                let variant = match v.name() {
+                    Some(name) => Value::Text(name.to_string()),
+                    None => Value::Uint32(v.index),
+                };
+                visitor.visit_enum(ValueEnumAccess {
+                    variant,
+                    value: Some(v.value),
+                })
+            }
+            Value::Tagged(v) => v.value.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_option<V: serde::de::Visitor<'de>>(self, visitor: V) -> YdbResult<V::Value> {
+        match self {
+            Value::Void | Value::Null => visitor.visit_none(),
+            Value::Optional(v) => match v.value {
+                Some(v) => visitor.visit_some(v),
+                None => visitor.visit_none(),
+            },
+            Value::Pg(v) if v.is_null() => visitor.visit_none(),
+            Value::Tagged(v) => v.value.deserialize_option(visitor),
+            value => visitor.visit_some(value),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: serde::de::Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> YdbResult<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: serde::de::Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> YdbResult<V::Value> {
+        match self {
+            // unit variant by name
+            Value::Text(variant) => visitor.visit_enum(ValueEnumAccess {
+                variant: Value::Text(variant),
+                value: None,
+            }),
+            Value::Optional(v) if v.value.is_some() => {
+                v.value.unwrap().deserialize_enum(name, variants, visitor)
+            }
+            Value::Tagged(v) => v.value.deserialize_enum(name, variants, visitor),
+            value => value.deserialize_any(visitor),
+        }
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple
+        tuple_struct map struct identifier ignored_any
+    }
+}
+
+impl<'de> IntoDeserializer<'de, YdbError> for Value {
+    type Deserializer = Value;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        self
+    }
+}
+
+impl<'de> serde::Deserializer<'de> for Row {
+    type Error = YdbError;
+
+    fn deserialize_any<V: serde::de::Visitor<'de>>(self, visitor: V) -> YdbResult<V::Value> {
+        Value::Struct(self.into_value_struct()?).deserialize_any(visitor)
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+struct ValueEnumAccess {
+    // name of the variant as Value::Text or index as Value::Uint32
+    variant: Value,
+    value: Option<Value>,
+}
+
+impl<'de> EnumAccess<'de> for ValueEnumAccess {
+    type Error = YdbError;
+    type Variant = ValueVariantAccess;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> YdbResult<(V::Value, Self::Variant)> {
+        Ok((
+            seed.deserialize(self.variant)?,
+            ValueVariantAccess(self.value),
+        ))
+    }
+}
+
+// value of the variant, None for unit variant by name
+struct ValueVariantAccess(Option<Value>);
+
+impl<'de> VariantAccess<'de> for ValueVariantAccess {
+    type Error = YdbError;
+
+    fn unit_variant(self) -> YdbResult<()> {
+        match self.0 {
+            None | Some(Value::Void) | Some(Value::Null) => Ok(()),
+            Some(value) => Err(YdbError::Convert(format!(
+                "expected unit variant, got value: {}",
+                value.kind_static()
+            ))),
+        }
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> YdbResult<T::Value> {
+        seed.deserialize(self.0.unwrap_or(Value::Void))
+    }
+
+    fn tuple_variant<V: serde::de::Visitor<'de>>(
+        self,
+        _len: usize,
+        visitor: V,
+    ) -> YdbResult<V::Value> {
+        serde::Deserializer::deserialize_any(self.0.unwrap_or(Value::EmptyList), visitor)
+    }
+
+    fn struct_variant<V: serde::de::Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> YdbResult<V::Value> {
+        serde::Deserializer::deserialize_any(self.0.unwrap_or(Value::EmptyDict), visitor)
+    }
+}
+
+//
+// Serialize to Value
+//
+
+struct ValueSerializer;
+
+impl ser::Serializer for ValueSerializer {
+    type Ok = Value;
+    type Error = YdbError;
+    type SerializeSeq = ListSerializer;
+    type SerializeTuple = TupleSerializer;
+    type SerializeTupleStruct = TupleSerializer;
+    type SerializeTupleVariant = ser::Impossible<Value, YdbError>;
+    type SerializeMap = DictSerializer;
+    type SerializeStruct = StructSerializer;
+    type SerializeStructVariant = ser::Impossible<Value, YdbError>;
+
+    fn serialize_bool(self, v: bool) -> YdbResult<Value> {
+        Ok(Value::Bool(v))
+    }
+
+    fn serialize_i8(self, v: i8) -> YdbResult<Value> {
+        Ok(Value::Int8(v))
+    }
+
+    fn serialize_i16(self, v: i16) -> YdbResult<Value> {
+        Ok(Value::Int16(v))
+    }
+
+    fn serialize_i32(self, v: i32) -> YdbResult<Value> {
+        Ok(Value::Int32(v))
+    }
+
+    fn serialize_i64(self, v: i64) -> YdbResult<Value> {
+        Ok(Value::Int64(v))
+    }
+
+    fn serialize_u8(self, v: u8) -> YdbResult<Value> {
+        Ok(Value::Uint8(v))
+    }
+
+    fn serialize_u16(self, v: u16) -> YdbResult<Value> {
+        Ok(Value::Uint16(v))
+    }
+
+    fn serialize_u32(self, v: u32) -> YdbResult<Value> {
+        Ok(Value::Uint32(v))
+    }
+
+    fn serialize_u64(self, v: u64) -> YdbResult<Value> {
+        Ok(Value::Uint64(v))
+    }
+
+    fn serialize_f32(self, v: f32) -> YdbResult<Value> {
+        Ok(Value::Float(v))
+    }
+
+    fn serialize_f64(self, v: f64) -> YdbResult<Value> {
+        Ok(Value::Double(v))
+    }
+
+    fn serialize_char(self, v: char) -> YdbResult<Value> {
+        Ok(Value::Text(v.to_string()))
+    }
+
+    fn serialize_str(self, v: &str) -> YdbResult<Value> {
+        Ok(Value::Text(v.to_string()))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> YdbResult<Value> {
+        Ok(Value::Bytes(v.to_vec().into()))
+    }
+
+    fn serialize_none(self) -> YdbResult<Value> {
+        Ok(Value::Null)
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> YdbResult<Value> {
+        let value = value.serialize(ValueSerializer)?;
+        Ok(Value::Optional(Box::new(ValueOptional {
+            t: value.clone(),
+            value: Some(value),
+        })))
+    }
+
+    fn serialize_unit(self) -> YdbResult<Value> {
+        Ok(Value::Void)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> YdbResult<Value> {
+        Ok(Value::Void)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> YdbResult<Value> {
+        Ok(Value::Text(variant.to_string()))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> YdbResult<Value> {
+        value.serialize(ValueSerializer)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _value: &T,
+    ) -> YdbResult<Value> {
+        Err(unsupported_variant(name, variant))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> YdbResult<ListSerializer> {
+        Ok(ListSerializer {
+            values: Vec::with_capacity(len.unwrap_or_default()),
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> YdbResult<TupleSerializer> {
+        Ok(TupleSerializer {
+            values: Vec::with_capacity(len),
+        })
+    }
+
+    fn serialize_tuple_struct(self, _name: &'static str, len: usize) -> YdbResult<TupleSerializer> {
+        self.serialize_tuple(len)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> YdbResult<Self::SerializeTupleVariant> {
+        Err(unsupported_variant(name, variant))
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> YdbResult<DictSerializer> {
+        Ok(DictSerializer {
+            values: Vec::with_capacity(len.unwrap_or_default()),
+            key: None,
+        })
+    }
+
+    fn serialize_struct(self, _name: &'static str, len: usize) -> YdbResult<StructSerializer> {
+        Ok(StructSerializer {
+            value: ValueStruct::with_capacity(len),
+        })
+    }
+
+    fn serialize_struct_variant(
+        self,
+        name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> YdbResult<Self::SerializeStructVariant> {
+        Err(unsupported_variant(name, variant))
+    }
+}
+
+fn unsupported_variant(name: &str, variant: &str) -> YdbError {
+    YdbError::Convert(format!(
+        "serialize enum variant with data is unsupported: {name}::{variant}"
+    ))
+}
+
+// Example of values type, Null replaced by optionals from other values
+fn merge_example(example: Value, value: &Value) -> Value {
+    match (example, value) {
+        (Value::Null, value @ Value::Optional(_)) => value.clone(),
+        (Value::Struct(mut example), Value::Struct(value))
+            if example.fields_name == value.fields_name =>
+        {
+            example.values = example
+                .values
+                .into_iter()
+                .zip(value.values.iter())
+                .map(|(example, value)| merge_example(example, value))
+                .collect();
+            Value::Struct(example)
+        }
+        (example, _) => example,
+    }
+}
+
+// Replace Null by empty optional with type of example
+fn check_no_nulls(value: Value) -> YdbResult<Value> {
+    if has_nulls(&value) {
+        return Err(YdbError::Convert(
+            "unknown type of None value, use to_value_with_example with example of the value"
+                .to_string(),
+        ));
+    }
+    Ok(value)
+}
+
+fn has_nulls(value: &Value) -> bool {
+    match value {
+        Value::Null => true,
+        Value::Optional(optional) => {
+            has_nulls(&optional.t) || optional.value.as_ref().is_some_and(has_nulls)
+        }
+        Value::List(list) => has_nulls(&list.t) || list.values.iter().any(has_nulls),
+        Value::Struct(value) => value.values.iter().any(has_nulls),
+        Value::Tuple(values) => values.iter().any(has_nulls),
+        Value::Dict(dict) => {
+            has_nulls(&dict.key_t)
+                || has_nulls(&dict.payload_t)
+                || dict
+                    .values
+                    .iter()
+                    .any(|(key, payload)| has_nulls(key) || has_nulls(payload))
+        }
+        _ => false,
+    }
+}
+
+fn fill_nulls(value: Value, example: &Value) -> Value {
+    match (value, example) {
+        (Value::Null, Value::Optional(example)) => Value::Optional(Box::new(ValueOptional {
+            t: example.t.clone(),
+            value: None,
+        })),
+        (Value::Struct(mut value), Value::Struct(example))
+            if value.fields_name == example.fields_name =>
+        {
+            value.values = value
+                .values
+                .into_iter()
+                .zip(example.values.iter())
+                .map(|(value, example)| fill_nulls(value, example))
+                .collect();
+            Value::Struct(value)
+        }
+        (Value::List(mut value), Value::List(example)) => {
+            value.values = value
+                .values
+                .into_iter()
+                .map(|value| fill_nulls(value, &example.t))
+                .collect();
+            value.t = fill_nulls(value.t, &example.t);
+            Value::List(value)
+        }
+        (Value::EmptyList, Value::List(example)) => Value::List(Box::new(ValueList {
+            t: example.t.clone(),
+            values: Vec::new(),
+        })),
+        (value, _) => value,
+    }
+}
+
+struct ListSerializer {
+    values: Vec<Value>,
+}
+
+impl ser::SerializeSeq for ListSerializer {
+    type Ok = Value;
+    type Error = YdbError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> YdbResult<()> {
+        self.values.push(value.serialize(ValueSerializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> YdbResult<Value> {
+        let Some(first) = self.values.first().cloned() else {
+            return Ok(Value::EmptyList);
+        };
+
+        let example = self.values.iter().fold(first, merge_example);
+        let values = self
+            .values
+            .into_iter()
+            .map(|value| fill_nulls(value, &example))
+            .collect();
+        Value::list_from(example, values)
+    }
+}
+
+struct TupleSerializer {
+    values: Vec<Value>,
+}
+
+impl ser::SerializeTuple for TupleSerializer {
+    type Ok = Value;
+    type Error = YdbError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> YdbResult<()> {
+        self.values.push(value.serialize(ValueSerializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> YdbResult<Value> {
+        Ok(Value::Tuple(self.values))
+    }
+}
+
+impl ser::SerializeTupleStruct for TupleSerializer {
+    type Ok = Value;
+    type Error = YdbError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> YdbResult<()> {
+        ser::SerializeTuple::serialize_element(self, value)
+    }
+
+    fn end(self) -> YdbResult<Value> {
+        ser::SerializeTuple::end(self)
+    }
+}
+
+struct DictSerializer {
+    values: Vec<(Value, Value)>,
+    key: Option<Value>,
+}
+
+impl ser::SerializeMap for DictSerializer {
+    type Ok = Value;
+    type Error = YdbError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> YdbResult<()> {
+        self.key = Some(key.serialize(ValueSerializer)?);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> YdbResult<()> {
+        let Some(key) = self.key.take() else {
+            return Err(YdbError::Convert(
+                "serialize map value without key".to_string(),
+            ));
+        };
+        self.values.push((key, value.serialize(ValueSerializer)?));
+        Ok(())
+    }
+
+    fn end(self) -> YdbResult<Value> {
+        let Some((first_key, first_payload)) = self.values.first().cloned() else {
+            return Ok(Value::EmptyDict);
+        };
+
+        let payload_example = self
+            .values
+            .iter()
+            .fold(first_payload, |example, (_, payload)| {
+                merge_example(example, payload)
+            });
+        let values = self
+            .values
+            .into_iter()
+            .map(|(key, payload)| (key, fill_nulls(payload, &payload_example)))
+            .collect();
+        Value::dict_from(first_key, payload_example, values)
+    }
+}
+
+struct StructSerializer {
+    value: ValueStruct,
+}
+
+impl ser::SerializeStruct for StructSerializer {
+    type Ok = Value;
+    type Error = YdbError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> YdbResult<()> {
+        self.value
+            .insert(key.to_string(), value.serialize(ValueSerializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> YdbResult<Value> {
+        Ok(Value::Struct(self.value))
+    }
+}
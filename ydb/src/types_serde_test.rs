@@ -0,0 +1,338 @@
+use crate::errors::{YdbError, YdbResult};
+use crate::test_helpers::make_result_set;
+use crate::types::SignedInterval;
+use crate::{from_value, to_value, to_value_with_example, Value, ValueOptional, ValueTz};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::time::{Duration, UNIX_EPOCH};
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+struct Info {
+    text: String,
+    rating: Option<f64>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+enum Status {
+    Active,
+    Closed,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+struct Series {
+    id: u64,
+    title: String,
+    info: Info,
+    tags: Vec<String>,
+    status: Status,
+    #[serde(default)]
+    release_year: Option<i32>,
+}
+
+// serialized optional use own value as example of type
+fn some(value: Value) -> Value {
+    Value::Optional(Box::new(ValueOptional {
+        t: value.clone(),
+        value: Some(value),
+    }))
+}
+
+#[test]
+fn serialize() -> YdbResult<()> {
+    assert_eq!(Value::Int32(1), to_value(&1_i32)?);
+    assert_eq!(Value::Text("a".to_string()), to_value("a")?);
+    assert_eq!(Value::Void, to_value(&())?);
+    assert!(matches!(to_value(&None::<i32>), Err(YdbError::Convert(_))));
+    assert_eq!(some(Value::Int32(1)), to_value(&Some(1_i32))?);
+    assert_eq!(
+        Value::Text("Active".to_string()),
+        to_value(&Status::Active)?
+    );
+    assert_eq!(
+        Value::Tuple(vec![Value::Int32(1), Value::Text("a".to_string())]),
+        to_value(&(1_i32, "a"))?
+    );
+    assert!(to_value(&Some(Ok::<i32, i32>(1))).is_err());
+    assert_eq!(Value::EmptyList, to_value(&Vec::<i32>::new())?);
+    assert_eq!(
+        Value::list_from(Value::Int32(1), vec![Value::Int32(1), Value::Int32(2)])?,
+        to_value(&vec![1_i32, 2])?
+    );
+    assert_eq!(Value::EmptyDict, to_value(&BTreeMap::<String, i32>::new())?);
+    assert_eq!(
+        Value::dict_from(
+            "a".into(),
+            Value::Int32(1),
+            vec![("a".into(), Value::Int32(1))]
+        )?,
+        to_value(&BTreeMap::from([("a".to_string(), 1_i32)]))?
+    );
+    Ok(())
+}
+
+#[test]
+fn serialize_struct() -> YdbResult<()> {
+    let series = Series {
+        id: 1,
+        title: "first".to_string(),
+        info: Info {
+            text: "text".to_string(),
+            rating: Some(9.5),
+        },
+        tags: vec!["drama".to_string()],
+        status: Status::Closed,
+        release_year: Some(2010),
+    };
+
+    assert_eq!(
+        Value::struct_from_fields(vec![
+            ("id".to_string(), Value::Uint64(1)),
+            ("title".to_string(), "first".into()),
+            (
+                "info".to_string(),
+                Value::struct_from_fields(vec![
+                    ("text".to_string(), "text".into()),
+                    ("rating".to_string(), some(Value::Double(9.5))),
+                ])
+            ),
+            (
+                "tags".to_string(),
+                Value::list_from("drama".into(), vec!["drama".into()])?
+            ),
+            ("status".to_string(), "Closed".into()),
+            ("release_year".to_string(), some(Value::Int32(2010))),
+        ]),
+        to_value(&series)?
+    );
+    Ok(())
+}
+
+#[test]
+fn serialize_list_with_nulls() -> YdbResult<()> {
+    let infos = vec![
+        Info {
+            text: "first".to_string(),
+            rating: None,
+        },
+        Info {
+            text: "second".to_string(),
+            rating: Some(1.0),
+        },
+    ];
+
+    // null in list item replaced by empty optional with type from other items
+    let first = Value::struct_from_fields(vec![
+        ("text".to_string(), "first".into()),
+        (
+            "rating".to_string(),
+            Value::Optional(Box::new(ValueOptional {
+                t: Value::Double(1.0),
+                value: None,
+            })),
+        ),
+    ]);
+    // example of list items type merged from all items
+    let example = Value::struct_from_fields(vec![
+        ("text".to_string(), "first".into()),
+        ("rating".to_string(), some(Value::Double(1.0))),
+    ]);
+    let second = Value::struct_from_fields(vec![
+        ("text".to_string(), "second".into()),
+        ("rating".to_string(), some(Value::Double(1.0))),
+    ]);
+    assert_eq!(
+        Value::list_from(example, vec![first, second])?,
+        to_value(&infos)?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn serialize_struct_with_none_field() -> YdbResult<()> {
+    let info = Info {
+        text: "first".to_string(),
+        rating: None,
+    };
+
+    // serde doesn't know type of none
+    assert!(matches!(to_value(&info), Err(YdbError::Convert(_))));
+    assert!(matches!(
+        to_value(&vec![info.clone()]),
+        Err(YdbError::Convert(_))
+    ));
+
+    let example = Value::struct_from_fields(vec![
+        ("text".to_string(), "".into()),
+        ("rating".to_string(), Option::<f64>::None.into()),
+    ]);
+    let expected = Value::struct_from_fields(vec![
+        ("text".to_string(), "first".into()),
+        (
+            "rating".to_string(),
+            Value::Optional(Box::new(ValueOptional {
+                t: Value::Double(0.0),
+                value: None,
+            })),
+        ),
+    ]);
+    assert_eq!(expected, to_value_with_example(&info, &example)?);
+
+    // list with none in all items
+    let list_example = Value::list_from(example, Vec::new())?;
+    assert_eq!(
+        Value::list_from(expected.clone(), vec![expected])?,
+        to_value_with_example(&vec![info], &list_example)?
+    );
+    Ok(())
+}
+
+#[test]
+fn deserialize() -> YdbResult<()> {
+    assert_eq!(1_i32, from_value::<i32>(Value::Int32(1))?);
+    assert_eq!(1_i64, from_value::<i64>(Value::Int32(1))?);
+    assert_eq!(
+        "a".to_string(),
+        from_value::<String>(Value::Text("a".to_string()))?
+    );
+    assert_eq!(Some(1_i32), from_value(Value::from(Some(1_i32)))?);
+    assert_eq!(None::<i32>, from_value(Value::from(None::<i32>))?);
+    assert_eq!(None::<i32>, from_value(Value::Null)?);
+    assert_eq!(
+        Status::Active,
+        from_value(Value::Text("Active".to_string()))?
+    );
+    assert_eq!(
+        vec![1_i32, 2],
+        from_value::<Vec<i32>>(Value::list_from(
+            Value::Int32(0),
+            vec![Value::Int32(1), Value::Int32(2)]
+        )?)?
+    );
+    assert_eq!(Vec::<i32>::new(), from_value::<Vec<i32>>(Value::EmptyList)?);
+    assert_eq!(
+        (1_i32, "a".to_string()),
+        from_value(Value::Tuple(vec![
+            Value::Int32(1),
+            Value::Text("a".to_string())
+        ]))?
+    );
+    assert_eq!(
+        BTreeMap::from([("a".to_string(), 1_i32)]),
+        from_value(Value::dict_from(
+            Value::Text(String::new()),
+            Value::Int32(0),
+            vec![("a".into(), Value::Int32(1))]
+        )?)?
+    );
+
+    assert!(from_value::<i32>(Value::Text("1".to_string())).is_err());
+    assert!(from_value::<u8>(Value::Int32(1000)).is_err());
+    Ok(())
+}
+
+#[test]
+fn deserialize_special_types() -> YdbResult<()> {
+    let t = UNIX_EPOCH + Duration::from_secs(86400 + 3661);
+    assert_eq!("1970-01-02", from_value::<String>(Value::Date(t))?);
+    assert_eq!(
+        "1970-01-02T01:01:01Z",
+        from_value::<String>(Value::Timestamp(t))?
+    );
+    assert_eq!(
+        -5,
+        from_value::<i64>(Value::Interval(SignedInterval::from_micros(-5)))?
+    );
+    assert_eq!(
+        "2024-01-01,Europe/Moscow",
+        from_value::<String>(Value::TzDate(ValueTz::new("2024-01-01", "Europe/Moscow")))?
+    );
+    assert_eq!(
+        "1.5",
+        from_value::<String>(Value::Decimal("1.5".parse().unwrap()))?
+    );
+    assert_eq!(3, from_value::<i32>(Value::tagged("tag", Value::Int32(3)))?);
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    enum Alternative {
+        Num(i32),
+        Text(String),
+    }
+    let variant = Value::variant_from(
+        Value::struct_from_fields(vec![
+            ("Num".to_string(), Value::Int32(0)),
+            ("Text".to_string(), Value::Text(String::new())),
+        ]),
+        1,
+        Value::Text("a".to_string()),
+    )?;
+    assert_eq!(Alternative::Text("a".to_string()), from_value(variant)?);
+    Ok(())
+}
+
+#[test]
+fn roundtrip_struct() -> YdbResult<()> {
+    let info = Info {
+        text: "text".to_string(),
+        rating: Some(9.5),
+    };
+    let series = Series {
+        id: 1,
+        title: "first".to_string(),
+        info: info.clone(),
+        tags: vec![],
+        status: Status::Active,
+        release_year: Some(2020),
+    };
+    assert_eq!(series, from_value(to_value(&series)?)?);
+
+    // absent optional field
+    let value = Value::struct_from_fields(vec![
+        ("id".to_string(), Value::Uint64(1)),
+        ("title".to_string(), "first".into()),
+        ("info".to_string(), to_value(&info)?),
+        ("tags".to_string(), Value::EmptyList),
+        ("status".to_string(), "Active".into()),
+    ]);
+    assert_eq!(
+        Series {
+            release_year: None,
+            ..series
+        },
+        from_value(value)?
+    );
+    Ok(())
+}
+
+#[test]
+fn deserialize_rows() -> YdbResult<()> {
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Row {
+        id: u64,
+        title: Option<String>,
+    }
+
+    let result_set = make_result_set(
+        vec![
+            ("id", Value::Uint64(0)),
+            ("title", Value::from(None::<String>)),
+        ],
+        vec![
+            vec![Value::Uint64(1), Value::from(Some("first".to_string()))],
+            vec![Value::Uint64(2), Value::from(None::<String>)],
+        ],
+    )?;
+
+    let rows: Vec<Row> = result_set.into_deserialized().collect::<YdbResult<_>>()?;
+    assert_eq!(
+        vec![
+            Row {
+                id: 1,
+                title: Some("first".to_string())
+            },
+            Row { id: 2, title: None }
+        ],
+        rows
+    );
+    Ok(())
+}
@@ -1,5 +1,10 @@
 use crate::client::Client;
-use crate::{test_helpers::test_client_builder, ydb_params, Query, Value, YdbResult};
+use crate::{
+    test_helpers::test_client_builder, ydb_params, Bytes, DyNumber, Query, Value, ValuePg, ValueTz,
+    YdbResult,
+};
+use std::collections::{BTreeMap, HashMap};
+use std::time::{Duration, SystemTime};
 use uuid::Uuid;
 
 #[test]
@@ -10,6 +15,216 @@ fn test_is_optional() -> YdbResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_nested_optional_conversions() -> YdbResult<()> {
+    for v in [None, Some(None), Some(Some(1_i32))] {
+        let value: Value = v.into();
+        assert_eq!(v, Option::<Option<i32>>::try_from(value.clone())?);
+    }
+
+    let just_null: Value = Some(None::<i32>).into();
+    let inner = just_null.to_option().unwrap();
+    assert!(inner.is_optional());
+    assert_eq!(None, inner.to_option());
+
+    let not_nested: Value = Some(1_i32).into();
+    assert_eq!(Some(Some(1)), Option::<Option<i32>>::try_from(not_nested)?);
+    Ok(())
+}
+
+#[tokio::test]
+#[ignore] // need YDB access
+async fn test_nested_optional() -> YdbResult<()> {
+    let client = test_client_builder().client()?;
+    client.wait().await?;
+
+    let mut row = client
+        .table_client()
+        .retry_transaction(|mut t| async move {
+            let res = t
+                .query(Query::new(
+                    "
+SELECT
+    Just(CAST(NULL AS Int32?)) AS just_null,
+    Just(Just(1)) AS just_value,
+    CAST(NULL AS Int32??) AS null
+",
+                ))
+                .await?;
+            Ok(res.into_only_row()?)
+        })
+        .await?;
+
+    let just_null: Option<Option<i32>> = row.remove_field_by_name("just_null")?.try_into()?;
+    assert_eq!(Some(None), just_null);
+    let just_value: Option<Option<i32>> = row.remove_field_by_name("just_value")?.try_into()?;
+    assert_eq!(Some(Some(1)), just_value);
+    let null: Option<Option<i32>> = row.remove_field_by_name("null")?.try_into()?;
+    assert_eq!(None, null);
+    Ok(())
+}
+
+#[test]
+fn test_container_conversions() -> YdbResult<()> {
+    let tuple: Value = (1_i32, "a", Some(2_i64)).into();
+    let (a, b, c): (i32, String, Option<i64>) = tuple.clone().try_into()?;
+    assert_eq!((1, "a".to_string(), Some(2)), (a, b, c));
+    assert!(<(i32, String)>::try_from(tuple).is_err());
+
+    let dict = Value::dict_from(
+        Value::Text("".into()),
+        Value::Int32(0),
+        vec![("a".into(), 1_i32.into()), ("b".into(), 2_i32.into())],
+    )?;
+    let hash_map: HashMap<String, i64> = dict.clone().try_into()?;
+    assert_eq!(HashMap::from([("a".into(), 1), ("b".into(), 2)]), hash_map);
+    let btree_map: BTreeMap<String, i32> = dict.try_into()?;
+    assert_eq!(
+        BTreeMap::from([("a".into(), 1), ("b".into(), 2)]),
+        btree_map
+    );
+    assert_eq!(
+        Value::dict_from(
+            Value::Text("".into()),
+            Value::Int32(0),
+            vec![("a".into(), 1_i32.into()), ("b".into(), 2_i32.into())],
+        )?,
+        Value::from(btree_map)
+    );
+
+    let struct_map: HashMap<String, Value> =
+        Value::struct_from_fields(vec![("a".to_string(), 1_i32.into())]).try_into()?;
+    assert_eq!(Some(&Value::Int32(1)), struct_map.get("a"));
+
+    let empty: HashMap<i32, i32> = Value::EmptyDict.try_into()?;
+    assert!(empty.is_empty());
+    let empty: Vec<i32> = Value::EmptyList.try_into()?;
+    assert!(empty.is_empty());
+
+    let variant = Value::variant_from(
+        Value::struct_from_fields(vec![
+            ("id".to_string(), Value::Int64(0)),
+            ("name".to_string(), Value::Text("".into())),
+        ]),
+        1,
+        "test".into(),
+    )?;
+    let Value::Variant(variant) = variant else {
+        panic!("unexpected value: {variant:?}")
+    };
+    assert_eq!(Some("name"), variant.name());
+    assert_eq!(1, variant.index());
+    assert_eq!(Value::Text("test".into()), variant.into_value());
+    assert!(Value::variant_from(Value::Tuple(vec![Value::Int64(0)]), 1, 1_i64.into()).is_err());
+
+    let Value::Tagged(tagged) = Value::tagged("tag", 1_i32.into()) else {
+        panic!("unexpected tagged value")
+    };
+    assert_eq!("tag", tagged.tag());
+    assert_eq!(&Value::Int32(1), tagged.value());
+    Ok(())
+}
+
+#[tokio::test]
+#[ignore] // need YDB access
+async fn test_containers() -> YdbResult<()> {
+    let client = test_client_builder().client()?;
+    client.wait().await?;
+
+    let mut row = client
+        .table_client()
+        .retry_transaction(|mut t| async move {
+            let res = t
+                .query(
+                    Query::new(
+                        "
+DECLARE $tuple AS Tuple<Int32, Utf8>;
+SELECT
+    AsDict(AsTuple(1, 'a'), AsTuple(2, 'b')) AS dict,
+    $tuple AS tuple,
+    Variant(1, '1', Variant<Int32, String>) AS variant,
+    AsTagged(1, 'tag') AS tagged,
+    [] AS empty_list,
+    {} AS empty_dict
+",
+                    )
+                    .with_params(ydb_params!("$tuple" => (1_i32, "a"))),
+                )
+                .await?;
+            Ok(res.into_only_row()?)
+        })
+        .await?;
+
+    let dict: BTreeMap<i32, Bytes> = row.remove_field_by_name("dict")?.try_into()?;
+    assert_eq!(BTreeMap::from([(1, "a".into()), (2, "b".into())]), dict);
+
+    let tuple: (i32, String) = row.remove_field_by_name("tuple")?.try_into()?;
+    assert_eq!((1, "a".to_string()), tuple);
+
+    let Value::Variant(variant) = row.remove_field_by_name("variant")? else {
+        panic!("expected variant")
+    };
+    assert_eq!(1, variant.index());
+
+    let Value::Tagged(tagged) = row.remove_field_by_name("tagged")? else {
+        panic!("expected tagged")
+    };
+    assert_eq!("tag", tagged.tag());
+
+    assert_eq!(Value::EmptyList, row.remove_field_by_name("empty_list")?);
+    assert_eq!(Value::EmptyDict, row.remove_field_by_name("empty_dict")?);
+    Ok(())
+}
+
+#[tokio::test]
+#[ignore] // need YDB access
+async fn test_dates() -> YdbResult<()> {
+    let client = test_client_builder().client()?;
+    client.wait().await?;
+
+    let before_epoch = SystemTime::UNIX_EPOCH - Duration::from_secs(86400 * 365);
+    let mut row = client
+        .table_client()
+        .retry_transaction(|mut t| async move {
+            let res = t
+                .query(
+                    Query::new(
+                        "
+DECLARE $date32 AS Date32;
+DECLARE $timestamp64 AS Timestamp64;
+DECLARE $tz_datetime AS TzDatetime;
+SELECT
+    $date32 AS date32,
+    $timestamp64 AS timestamp64,
+    $tz_datetime AS tz_datetime
+",
+                    )
+                    .with_params(ydb_params!(
+                        "$date32" => Value::Date32(before_epoch),
+                        "$timestamp64" => Value::Timestamp64(before_epoch),
+                        "$tz_datetime" => Value::TzDatetime(ValueTz::new("2024-03-01T12:00:00", "Europe/Moscow")),
+                    )),
+                )
+                .await?;
+            Ok(res.into_only_row()?)
+        })
+        .await?;
+
+    assert_eq!(
+        Value::Date32(before_epoch),
+        row.remove_field_by_name("date32")?
+    );
+    assert_eq!(
+        Value::Timestamp64(before_epoch),
+        row.remove_field_by_name("timestamp64")?
+    );
+    assert_eq!(
+        Value::TzDatetime(ValueTz::new("2024-03-01T12:00:00", "Europe/Moscow")),
+        row.remove_field_by_name("tz_datetime")?
+    );
+    Ok(())
+}
+
 #[tokio::test]
 #[ignore] // need YDB access
 async fn test_decimal() -> YdbResult<()> {
@@ -40,6 +255,75 @@ async fn test_decimal() -> YdbResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_dynumber_conversions() -> YdbResult<()> {
+    let v: i64 = Value::DyNumber(DyNumber::new(".123e3")).try_into()?;
+    assert_eq!(123, v);
+
+    let v: decimal_rs::Decimal = Value::DyNumber(DyNumber::new("-1.5e-100")).try_into()?;
+    assert_eq!("-1.5e-100".parse::<decimal_rs::Decimal>().unwrap(), v);
+
+    let v: Option<u64> = Value::from(Some(DyNumber::from(u64::MAX))).try_into()?;
+    assert_eq!(Some(u64::MAX), v);
+
+    // conversions are exact
+    assert!(i64::try_from(Value::DyNumber(DyNumber::new("1.5"))).is_err());
+    assert!(u64::try_from(Value::DyNumber(DyNumber::new("-1"))).is_err());
+    assert!(i64::try_from(Value::DyNumber(DyNumber::new("1e20"))).is_err());
+    assert!(i64::try_from(Value::DyNumber(DyNumber::new("abc"))).is_err());
+    Ok(())
+}
+
+#[tokio::test]
+#[ignore] // need YDB access
+async fn test_dynumber_and_pg() -> YdbResult<()> {
+    let client = test_client_builder().client()?;
+    client.wait().await?;
+
+    let mut row = client
+        .table_client()
+        .retry_transaction(|mut t| async move {
+            let res = t
+                .query(
+                    Query::new(
+                        "
+DECLARE $dynumber AS DyNumber;
+DECLARE $pg_int8 AS PgInt8;
+SELECT
+    $dynumber AS dynumber,
+    DyNumber(\"-12.5\") AS dynumber_literal,
+    $pg_int8 AS pg_int8,
+    PgConst('text', PgText) AS pg_text,
+    PgCast(NULL, PgInt4) AS pg_null
+",
+                    )
+                    .with_params(ydb_params!(
+                        "$dynumber" => Value::DyNumber(DyNumber::from(12345_i64)),
+                        "$pg_int8" => Value::Pg(ValuePg::new(ValuePg::INT8, "-1")),
+                    )),
+                )
+                .await?;
+            Ok(res.into_only_row()?)
+        })
+        .await?;
+
+    let dynumber: i64 = row.remove_field_by_name("dynumber")?.try_into()?;
+    assert_eq!(12345, dynumber);
+    let dynumber_literal: decimal_rs::Decimal =
+        row.remove_field_by_name("dynumber_literal")?.try_into()?;
+    assert_eq!(
+        "-12.5".parse::<decimal_rs::Decimal>().unwrap(),
+        dynumber_literal
+    );
+    let pg_int8: i64 = row.remove_field_by_name("pg_int8")?.try_into()?;
+    assert_eq!(-1, pg_int8);
+    let pg_text: String = row.remove_field_by_name("pg_text")?.try_into()?;
+    assert_eq!("text", pg_text);
+    let pg_null: Option<i32> = row.remove_field_by_name("pg_null")?.try_into()?;
+    assert_eq!(None, pg_null);
+    Ok(())
+}
+
 #[tokio::test]
 #[ignore = "needs YDB access"]
 async fn test_uuid_serialization() -> YdbResult<()> {
@@ -0,0 +1,212 @@
+#[cfg(test)]
+#[path = "types_time_test.rs"]
+mod types_time_test;
+
+use crate::errors::{YdbError, YdbResult};
+use crate::types::{Sign, SignedInterval, Value};
+use std::any::type_name;
+use std::time::SystemTime;
+use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time};
+
+fn to_offset_date_time(t: SystemTime) -> YdbResult<OffsetDateTime> {
+    let interval = SignedInterval::since_unix_epoch(t);
+    let nanos = interval.duration.as_nanos() as i128;
+    let nanos = match interval.sign {
+        Sign::Plus => nanos,
+        Sign::Minus => -nanos,
+    };
+    OffsetDateTime::from_unix_timestamp_nanos(nanos)
+        .map_err(|err| YdbError::Convert(format!("time out of range: {t:?}: {err}")))
+}
+
+fn to_system_time(t: OffsetDateTime) -> SystemTime {
+    let nanos = t.unix_timestamp_nanos();
+    let duration = std::time::Duration::from_nanos(nanos.unsigned_abs() as u64);
+    if nanos >= 0 {
+        SystemTime::UNIX_EPOCH + duration
+    } else {
+        SystemTime::UNIX_EPOCH - duration
+    }
+}
+
+fn convert_error<T>(value: &Value) -> YdbError {
+    YdbError::Convert(format!(
+        "failed to convert from {} to {}",
+        value.kind_static(),
+        type_name::<T>(),
+    ))
+}
+
+// parse local time of ValueTz in formats YYYY-MM-DD, YYYY-MM-DDThh:mm:ss and YYYY-MM-DDThh:mm:ss.ffffff
+fn parse_local(value: &str) -> YdbResult<PrimitiveDateTime> {
+    fn bad_format(value: &str) -> YdbError {
+        YdbError::Convert(format!("failed to parse local time '{value}'"))
+    }
+
+    let (date, time) = value.split_once('T').unwrap_or((value, "00:00:00"));
+
+    let mut date_parts = date.rsplitn(3, '-');
+    let (Some(day), Some(month), Some(year)) =
+        (date_parts.next(), date_parts.next(), date_parts.next())
+    else {
+        return Err(bad_format(value));
+    };
+    let month: u8 = month.parse().map_err(|_| bad_format(value))?;
+    let date = Date::from_calendar_date(
+        year.parse().map_err(|_| bad_format(value))?,
+        Month::try_from(month).map_err(|_| bad_format(value))?,
+        day.parse().map_err(|_| bad_format(value))?,
+    )
+    .map_err(|_| bad_format(value))?;
+
+    let (time, fraction) = time.split_once('.').unwrap_or((time, ""));
+    let mut time_parts = time.splitn(3, ':');
+    let (Some(hour), Some(minute), Some(second)) =
+        (time_parts.next(), time_parts.next(), time_parts.next())
+    else {
+        return Err(bad_format(value));
+    };
+    let micros: u32 = if fraction.is_empty() {
+        0
+    } else if fraction.len() <= 6 {
+        format!("{fraction:0<6}")
+            .parse()
+            .map_err(|_| bad_format(value))?
+    } else {
+        return Err(bad_format(value));
+    };
+    let time = Time::from_hms_micro(
+        hour.parse().map_err(|_| bad_format(value))?,
+        minute.parse().map_err(|_| bad_format(value))?,
+        second.parse().map_err(|_| bad_format(value))?,
+        micros,
+    )
+    .map_err(|_| bad_format(value))?;
+
+    Ok(PrimitiveDateTime::new(date, time))
+}
+
+impl From<Date> for Value {
+    fn from(value: Date) -> Self {
+        Value::Date32(to_system_time(value.midnight().assume_utc()))
+    }
+}
+
+impl From<PrimitiveDateTime> for Value {
+    fn from(value: PrimitiveDateTime) -> Self {
+        Value::Timestamp64(to_system_time(value.assume_utc()))
+    }
+}
+
+impl From<OffsetDateTime> for Value {
+    fn from(value: OffsetDateTime) -> Self {
+        Value::Timestamp64(to_system_time(value))
+    }
+}
+
+impl From<time::Duration> for Value {
+    fn from(value: time::Duration) -> Self {
+        let sign = if value.is_negative() {
+            Sign::Minus
+        } else {
+            Sign::Plus
+        };
+        let duration = std::time::Duration::new(
+            value.whole_seconds().unsigned_abs(),
+            value.subsec_nanoseconds().unsigned_abs(),
+        );
+        Value::Interval64(SignedInterval { sign, duration })
+    }
+}
+
+impl TryFrom<Value> for Date {
+    type Error = YdbError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Date(t) | Value::Date32(t) => Ok(to_offset_date_time(t)?.date()),
+            Value::TzDate(v) => Ok(parse_local(&v.value)?.date()),
+            value => Err(convert_error::<Self>(&value)),
+        }
+    }
+}
+
+impl TryFrom<Value> for PrimitiveDateTime {
+    type Error = YdbError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Date(t)
+            | Value::DateTime(t)
+            | Value::Timestamp(t)
+            | Value::Date32(t)
+            | Value::Datetime64(t)
+            | Value::Timestamp64(t) => {
+                let t = to_offset_date_time(t)?;
+                Ok(PrimitiveDateTime::new(t.date(), t.time()))
+            }
+            Value::TzDate(v) | Value::TzDatetime(v) | Value::TzTimestamp(v) => {
+                parse_local(&v.value)
+            }
+            value => Err(convert_error::<Self>(&value)),
+        }
+    }
+}
+
+// time crate can't resolve timezone names, values with timezone can be converted to PrimitiveDateTime
+impl TryFrom<Value> for OffsetDateTime {
+    type Error = YdbError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Date(t)
+            | Value::DateTime(t)
+            | Value::Timestamp(t)
+            | Value::Date32(t)
+            | Value::Datetime64(t)
+            | Value::Timestamp64(t) => to_offset_date_time(t),
+            value => Err(convert_error::<Self>(&value)),
+        }
+    }
+}
+
+impl TryFrom<Value> for time::Duration {
+    type Error = YdbError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Interval(v) | Value::Interval64(v) => {
+                let duration = time::Duration::try_from(v.duration)
+                    .map_err(|err| YdbError::Convert(format!("interval out of range: {err}")))?;
+                match v.sign {
+                    Sign::Plus => Ok(duration),
+                    Sign::Minus => Ok(-duration),
+                }
+            }
+            value => Err(convert_error::<Self>(&value)),
+        }
+    }
+}
+
+macro_rules! optional_convert {
+    ($native_type:ty) => {
+        impl TryFrom<Value> for Option<$native_type> {
+            type Error = YdbError;
+
+            fn try_from(value: Value) -> Result<Self, Self::Error> {
+                match value {
+                    Value::Optional(opt_val) => {
+                        <$native_type as TryFrom<Value>>::try_from(opt_val.t)?;
+                        opt_val.value.map(<$native_type>::try_from).transpose()
+                    }
+                    value => Ok(Some(value.try_into()?)),
+                }
+            }
+        }
+    };
+}
+
+optional_convert!(Date);
+optional_convert!(PrimitiveDateTime);
+optional_convert!(OffsetDateTime);
+optional_convert!(time::Duration);
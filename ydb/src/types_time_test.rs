@@ -0,0 +1,52 @@
+use crate::errors::YdbResult;
+use crate::types::{Value, ValueTz};
+use std::time::{Duration, SystemTime};
+use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time};
+
+fn date(year: i32, month: Month, day: u8) -> Date {
+    Date::from_calendar_date(year, month, day).unwrap()
+}
+
+#[test]
+fn convert_pre_epoch() -> YdbResult<()> {
+    let value = Value::from(date(1812, Month::September, 7));
+    assert!(matches!(value, Value::Date32(_)));
+    assert_eq!(date(1812, Month::September, 7), Date::try_from(value)?);
+
+    let value = Value::from(
+        date(1969, Month::December, 31)
+            .with_time(Time::from_hms_milli(23, 59, 59, 500).unwrap())
+            .assume_utc(),
+    );
+    assert_eq!(
+        Value::Timestamp64(SystemTime::UNIX_EPOCH - Duration::from_millis(500)),
+        value
+    );
+    assert_eq!(
+        date(1969, Month::December, 31)
+            .with_time(Time::from_hms_milli(23, 59, 59, 500).unwrap())
+            .assume_utc(),
+        OffsetDateTime::try_from(value)?
+    );
+
+    let interval = time::Duration::seconds(-90);
+    assert_eq!(interval, time::Duration::try_from(Value::from(interval))?);
+    Ok(())
+}
+
+#[test]
+fn convert_with_timezone() -> YdbResult<()> {
+    let value = Value::TzTimestamp(ValueTz::new("2024-03-01T12:00:00.5", "Europe/Moscow"));
+    assert_eq!(
+        date(2024, Month::March, 1).with_time(Time::from_hms_milli(12, 0, 0, 500).unwrap()),
+        PrimitiveDateTime::try_from(value.clone())?
+    );
+    assert!(OffsetDateTime::try_from(value).is_err());
+
+    let value = Value::TzDate(ValueTz::new("2024-03-01", "Europe/Moscow"));
+    assert_eq!(date(2024, Month::March, 1), Date::try_from(value)?);
+
+    let null = Value::optional_from(Value::TzDate(ValueTz::new("1970-01-01", "GMT")), None)?;
+    assert_eq!(None, Option::<Date>::try_from(null)?);
+    Ok(())
+}